@@ -23,6 +23,10 @@ fn process_instruction(
         ProgramInstruction::InitThing(data) => processor::init_thing(accounts, data),
         ProgramInstruction::UpdateThing(data) => processor::update_thing(accounts, data),
         ProgramInstruction::CloseThing => processor::close_thing(accounts),
+        ProgramInstruction::InitForeignAccount(space) => {
+            processor::init_foreign_account(accounts, space)
+        }
+        ProgramInstruction::GetOrInitThing(data) => processor::get_or_init_thing(accounts, data),
     }
 }
 
@@ -8,12 +8,18 @@ pub enum ProgramInstruction {
     InitThing(u64),
     UpdateThing(u64),
     CloseThing,
+    InitForeignAccount(u64),
+    GetOrInitThing(u64),
 }
 
 impl ProgramInstruction {
     pub const INIT_THING: Selector = Discriminator::Sha2(b"ix::init_thing").to_bytes();
     pub const UPDATE_THING: Selector = Discriminator::Sha2(b"ix::update_thing").to_bytes();
     pub const CLOSE_THING: Selector = Discriminator::Sha2(b"ix::close_thing").to_bytes();
+    pub const INIT_FOREIGN_ACCOUNT: Selector =
+        Discriminator::Sha2(b"ix::init_foreign_account").to_bytes();
+    pub const GET_OR_INIT_THING: Selector =
+        Discriminator::Sha2(b"ix::get_or_init_thing").to_bytes();
 }
 
 impl BorshDeserialize for ProgramInstruction {
@@ -27,6 +33,12 @@ impl BorshDeserialize for ProgramInstruction {
                 reader,
             )?)),
             Self::CLOSE_THING => Ok(Self::CloseThing),
+            Self::INIT_FOREIGN_ACCOUNT => Ok(Self::InitForeignAccount(
+                BorshDeserialize::deserialize_reader(reader)?,
+            )),
+            Self::GET_OR_INIT_THING => Ok(Self::GetOrInitThing(
+                BorshDeserialize::deserialize_reader(reader)?,
+            )),
             _ => Err(std::io::Error::new(
                 std::io::ErrorKind::InvalidData,
                 "Invalid discriminator",
@@ -48,6 +60,14 @@ impl BorshSerialize for ProgramInstruction {
                 value.serialize(writer)
             }
             Self::CloseThing => Self::CLOSE_THING.serialize(writer),
+            Self::InitForeignAccount(space) => {
+                Self::INIT_FOREIGN_ACCOUNT.serialize(writer)?;
+                space.serialize(writer)
+            }
+            Self::GetOrInitThing(value) => {
+                Self::GET_OR_INIT_THING.serialize(writer)?;
+                value.serialize(writer)
+            }
         }
     }
 }
@@ -1,8 +1,8 @@
 use sealevel_tools::{
     account::BorshAccountSchema,
     account_info::{
-        try_next_enumerated_account, AccountInfoConstraints, MatchDataSlice, Payer,
-        WritableAccount, WritableSystemAccount,
+        try_next_enumerated_account, try_next_enumerated_account_info, AccountInfoConstraints,
+        MatchDataSlice, Payer, Signer, WritableAccount, WritableSystemAccount,
     },
     cpi::system_program::CreateAccount,
     discriminator::Discriminate,
@@ -107,3 +107,60 @@ pub fn close_thing(accounts: &[NoStdAccountInfo]) -> ProgramResult {
 
     Ok(())
 }
+
+#[inline(always)]
+pub fn init_foreign_account(accounts: &[NoStdAccountInfo], space: u64) -> ProgramResult {
+    let mut accounts_iter = accounts.iter().enumerate();
+
+    // First account will be paying the rent.
+    let (_, payer) = try_next_enumerated_account::<Payer>(&mut accounts_iter, Default::default())?;
+
+    // Second account is the new account, which is a random keypair signing for its creation.
+    let (_, new_account) =
+        try_next_enumerated_account::<Signer<true>>(&mut accounts_iter, Default::default())?;
+
+    // Third account is the program that will own the new account. This program will not be able
+    // to write to or reassign the new account afterward.
+    let (_, owner_program) =
+        try_next_enumerated_account_info(&mut accounts_iter, Default::default())?;
+
+    CreateAccount {
+        payer: payer.as_cpi_authority(),
+        to: new_account.as_cpi_authority(),
+        program_id: owner_program.key(),
+        space: Some(space as usize),
+        lamports: None,
+    }
+    .try_into_invoke()?;
+
+    Ok(())
+}
+
+#[inline(always)]
+pub fn get_or_init_thing(accounts: &[NoStdAccountInfo], value: u64) -> ProgramResult {
+    let mut accounts_iter = accounts.iter().enumerate();
+
+    // First account will be paying the rent if the Thing does not exist yet.
+    let (_, payer) = try_next_enumerated_account::<Payer>(&mut accounts_iter, Default::default())?;
+
+    let (thing_addr, thing_bump) = Thing::find_program_address(());
+
+    // Second account is the Thing, which may or may not exist.
+    let (_, thing_account) = try_next_enumerated_account::<WritableAccount>(
+        &mut accounts_iter,
+        AccountInfoConstraints {
+            key: Some(&thing_addr),
+            ..Default::default()
+        },
+    )?;
+
+    // If the Thing already exists, its value is left unchanged.
+    WritableThingAccount::try_get_or_init(
+        thing_account.as_cpi_authority(Some(&[Thing::SEED, &[thing_bump]])),
+        payer.as_cpi_authority(),
+        &ID,
+        BorshAccountSchema(Thing { value }),
+    )?;
+
+    Ok(())
+}
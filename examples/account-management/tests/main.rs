@@ -4,11 +4,15 @@ use example_account_management::{
     ID,
 };
 use examples_common::{is_compute_units_within, is_program_failure};
-use sealevel_tools::account::AccountSerde;
+use sealevel_tools::{
+    account::AccountSerde,
+    test_utils::{adjust_for_bump_iterations, REDUCED_BUMP_ITERATION_CU},
+};
 use solana_program_test::{tokio, ProgramTest};
 use solana_sdk::{
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
+    signature::Keypair,
     signer::Signer,
     system_instruction, system_program,
     transaction::Transaction,
@@ -51,7 +55,8 @@ async fn test_thing() {
 
     // NOTE: Thing bump is 255, which requires 1 iteration to find the thing key. Each bump
     // iteration costs 1,200 CU. The total adjustment is 1,200 CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 1_200;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 1, REDUCED_BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         2_530,
@@ -66,10 +71,7 @@ async fn test_thing() {
         .unwrap()
         .data;
     let thing_data = ThingSchema::try_deserialize_data(&mut &account_data[..]).unwrap();
-    assert_eq!(
-        account_data.len(),
-        thing_data.try_account_space().unwrap() as usize
-    );
+    assert_eq!(account_data.len(), thing_data.try_account_space().unwrap());
     assert_eq!(thing_data.0, Thing { value });
 
     // Update.
@@ -106,10 +108,7 @@ async fn test_thing() {
         .unwrap()
         .data;
     let thing_data = ThingSchema::try_deserialize_data(&mut &account_data[..]).unwrap();
-    assert_eq!(
-        account_data.len(),
-        thing_data.try_account_space().unwrap() as usize
-    );
+    assert_eq!(account_data.len(), thing_data.try_account_space().unwrap());
     assert_eq!(thing_data.0, Thing { value: new_value });
 
     // Close.
@@ -196,7 +195,8 @@ async fn test_init_thing_already_having_lamports() {
 
     // NOTE: Thing bump is 255, which requires 1 iteration to find the thing key. Each bump
     // iteration costs 1,200 CU. The total adjustment is 1,200 CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 1_200;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 1, REDUCED_BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         5_200,
@@ -211,10 +211,129 @@ async fn test_init_thing_already_having_lamports() {
         .unwrap()
         .data;
     let thing_data = ThingSchema::try_deserialize_data(&mut &account_data[..]).unwrap();
+    assert_eq!(account_data.len(), thing_data.try_account_space().unwrap());
+    assert_eq!(thing_data.0, Thing { value });
+}
+
+#[tokio::test]
+async fn test_init_foreign_account() {
+    let space = 165;
+
+    let (banks_client, payer, recent_blockhash) =
+        ProgramTest::new("example_account_management", ID, None)
+            .start()
+            .await;
+
+    // The new account will be owned by a program other than the executing program.
+    let owner_program_id = Pubkey::new_unique();
+    assert_ne!(owner_program_id, ID);
+
+    let new_account = Keypair::new();
+
+    let mut transaction = Transaction::new_with_payer(
+        &[InitForeignAccount {
+            payer: AccountMeta::new(payer.pubkey(), true),
+            new_account: AccountMeta::new(new_account.pubkey(), true),
+            owner_program: AccountMeta::new_readonly(owner_program_id, false),
+            system_program: AccountMeta::new_readonly(system_program::ID, false),
+        }
+        .into_instruction(space as u64)],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer, &new_account], recent_blockhash);
+
+    let tx_meta = banks_client
+        .process_transaction_with_metadata(transaction)
+        .await
+        .unwrap()
+        .metadata
+        .unwrap();
+    assert!(!is_program_failure(&ID, &tx_meta.log_messages));
+
+    // Check the new account.
+    let account = banks_client
+        .get_account(new_account.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.owner, owner_program_id);
+    assert_eq!(account.data, vec![0; space]);
+
+    let rent = banks_client.get_rent().await.unwrap();
+    assert_eq!(account.lamports, rent.minimum_balance(space));
+}
+
+#[tokio::test]
+async fn test_get_or_init_thing() {
+    let value = 69;
+
+    let (banks_client, payer, recent_blockhash) =
+        ProgramTest::new("example_account_management", ID, None)
+            .start()
+            .await;
+
+    let (thing_addr, _) = Pubkey::find_program_address(&[b"thing"], &ID);
+
+    // Thing does not exist yet, so it is created with the given value.
+    let mut transaction = Transaction::new_with_payer(
+        &[GetOrInitThing {
+            payer: AccountMeta::new(payer.pubkey(), true),
+            thing: AccountMeta::new(thing_addr, false),
+            system_program: AccountMeta::new_readonly(system_program::ID, false),
+        }
+        .into_instruction(value)],
+        Some(&payer.pubkey()),
+    );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    let tx_meta = banks_client
+        .process_transaction_with_metadata(transaction)
+        .await
+        .unwrap()
+        .metadata
+        .unwrap();
+    assert!(!is_program_failure(&ID, &tx_meta.log_messages));
+
+    let thing_account = banks_client.get_account(thing_addr).await.unwrap().unwrap();
+    assert_eq!(thing_account.owner, ID);
+
+    let thing_data = ThingSchema::try_deserialize_data(&mut &thing_account.data[..]).unwrap();
     assert_eq!(
-        account_data.len(),
-        thing_data.try_account_space().unwrap() as usize
+        thing_account.data.len(),
+        thing_data.try_account_space().unwrap()
+    );
+    assert_eq!(thing_data.0, Thing { value });
+
+    // Thing already exists, so its value is unchanged.
+    let new_value = 420;
+    assert_ne!(value, new_value);
+
+    let mut transaction = Transaction::new_with_payer(
+        &[GetOrInitThing {
+            payer: AccountMeta::new(payer.pubkey(), true),
+            thing: AccountMeta::new(thing_addr, false),
+            system_program: AccountMeta::new_readonly(system_program::ID, false),
+        }
+        .into_instruction(new_value)],
+        Some(&payer.pubkey()),
     );
+    transaction.sign(&[&payer], recent_blockhash);
+
+    let tx_meta = banks_client
+        .process_transaction_with_metadata(transaction)
+        .await
+        .unwrap()
+        .metadata
+        .unwrap();
+    assert!(!is_program_failure(&ID, &tx_meta.log_messages));
+
+    let account_data = banks_client
+        .get_account(thing_addr)
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let thing_data = ThingSchema::try_deserialize_data(&mut &account_data[..]).unwrap();
     assert_eq!(thing_data.0, Thing { value });
 }
 
@@ -272,3 +391,49 @@ impl CloseThing {
         }
     }
 }
+
+struct InitForeignAccount {
+    payer: AccountMeta,
+    new_account: AccountMeta,
+    owner_program: AccountMeta,
+    system_program: AccountMeta,
+}
+
+impl InitForeignAccount {
+    fn into_instruction(self, space: u64) -> Instruction {
+        let InitForeignAccount {
+            payer,
+            new_account,
+            owner_program,
+            system_program,
+        } = self;
+
+        Instruction {
+            program_id: example_account_management::ID,
+            accounts: vec![payer, new_account, owner_program, system_program],
+            data: borsh::to_vec(&ProgramInstruction::InitForeignAccount(space)).unwrap(),
+        }
+    }
+}
+
+struct GetOrInitThing {
+    payer: AccountMeta,
+    thing: AccountMeta,
+    system_program: AccountMeta,
+}
+
+impl GetOrInitThing {
+    fn into_instruction(self, value: u64) -> Instruction {
+        let GetOrInitThing {
+            payer,
+            thing,
+            system_program,
+        } = self;
+
+        Instruction {
+            program_id: example_account_management::ID,
+            accounts: vec![payer, thing, system_program],
+            data: borsh::to_vec(&ProgramInstruction::GetOrInitThing(value)).unwrap(),
+        }
+    }
+}
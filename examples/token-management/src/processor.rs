@@ -112,7 +112,9 @@ pub fn init_mint_with_extensions(
 
     let extensions = token_program_cpi::InitializeMintExtensions {
         close_authority: if close_authority {
-            Some(&mint_authority_addr)
+            Some(token_program_cpi::AuthoritySpec::Pubkey(
+                &mint_authority_addr,
+            ))
         } else {
             None
         },
@@ -193,7 +195,9 @@ pub fn init_mint_with_extensions(
         mint: new_mint_account.as_cpi_authority(Some(&[state::MINT_SEED, &[new_mint_bump]])),
         mint_authority: &mint_authority_addr,
         decimals,
-        freeze_authority: freeze_authority.as_ref(),
+        freeze_authority: freeze_authority
+            .as_ref()
+            .map(token_program_cpi::AuthoritySpec::Pubkey),
         extensions,
     }
     .try_into_invoke()?;
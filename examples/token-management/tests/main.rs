@@ -3,7 +3,10 @@ use example_token_management::{
     state, ID,
 };
 use examples_common::{is_compute_units_within, is_program_failure, TestResult, TestSuccess};
-use sealevel_tools::account::{legacy_token, token_extensions, AssociatedTokenAccountSeeds};
+use sealevel_tools::{
+    account::{legacy_token, token_extensions, AssociatedTokenAccountSeeds},
+    test_utils::{adjust_for_bump_iterations, BUMP_ITERATION_CU},
+};
 use solana_program_test::{tokio, BanksClient, ProgramTest};
 use solana_sdk::{
     hash::Hash,
@@ -42,7 +45,8 @@ async fn test_init_mint_token_program() {
     // NOTE: Mint bump is 252, which requires 4 iterations to find the mint key. Authority bump is
     // 255, which requires 1 iteration to find the authority key. Each bump iteration costs 1,500
     // CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 5 * 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 5, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         6_350,
@@ -69,7 +73,8 @@ async fn test_init_token_account_token_program() {
     // NOTE: Mint bump is 252, which requires 4 iterations to find the mint key. Token account bump
     // is 252, which requires 4 iterations to find the token account key. Each bump iteration costs
     // 1,500 CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 8 * 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 8, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         7_950,
@@ -156,7 +161,8 @@ async fn test_mint_to_token_program() {
             .unwrap();
     // NOTE: Mint authority bump is 255, which requires 1 iteration to find the mint authority key.
     // Each bump iteration costs 1,500 CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 1, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         6_090,
@@ -179,7 +185,8 @@ async fn test_suboptimal_mint_to_token_program() {
             .unwrap();
     // NOTE: Mint authority bump is 255, which requires 1 iteration to find the mint authority key.
     // Each bump iteration costs 1,500 CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 1, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         6_550,
@@ -305,7 +312,8 @@ async fn test_init_mint_token_2022_program() {
     // NOTE: Mint bump is 252, which requires 4 iterations to find the mint key. Authority bump is
     // 255, which requires 1 iteration to find the authority key. Each bump iteration costs 1,500
     // CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 5 * 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 5, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         4_900,
@@ -336,7 +344,8 @@ async fn test_init_mint_with_extensions() {
     // NOTE: Mint bump is 252, which requires 4 iterations to find the mint key. Authority bump is
     // 255, which requires 1 iteration to find the authority key. Each bump iteration costs 1,500
     // CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 5 * 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 5, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         7_850,
@@ -360,7 +369,8 @@ async fn test_init_mint_with_extensions() {
     // NOTE: Mint bump is 252, which requires 4 iterations to find the mint key. Authority bump is
     // 255, which requires 1 iteration to find the authority key. Each bump iteration costs 1,500
     // CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 5 * 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 5, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         7_550,
@@ -384,7 +394,8 @@ async fn test_init_mint_with_extensions() {
     // NOTE: Mint bump is 252, which requires 4 iterations to find the mint key. Authority bump is
     // 255, which requires 1 iteration to find the authority key. Each bump iteration costs 1,500
     // CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 5 * 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 5, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         7_550,
@@ -408,7 +419,8 @@ async fn test_init_mint_with_extensions() {
     // NOTE: Mint bump is 252, which requires 4 iterations to find the mint key. Authority bump is
     // 255, which requires 1 iteration to find the authority key. Each bump iteration costs 1,500
     // CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 5 * 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 5, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         7_550,
@@ -432,7 +444,8 @@ async fn test_init_mint_with_extensions() {
     // NOTE: Mint bump is 252, which requires 4 iterations to find the mint key. Authority bump is
     // 255, which requires 1 iteration to find the authority key. Each bump iteration costs 1,500
     // CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 5 * 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 5, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         7_400,
@@ -456,7 +469,8 @@ async fn test_init_mint_with_extensions() {
     // NOTE: Mint bump is 252, which requires 4 iterations to find the mint key. Authority bump is
     // 255, which requires 1 iteration to find the authority key. Each bump iteration costs 1,500
     // CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 5 * 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 5, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         7_500,
@@ -480,7 +494,8 @@ async fn test_init_mint_with_extensions() {
     // NOTE: Mint bump is 252, which requires 4 iterations to find the mint key. Authority bump is
     // 255, which requires 1 iteration to find the authority key. Each bump iteration costs 1,500
     // CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 5 * 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 5, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         8_550,
@@ -504,7 +519,8 @@ async fn test_init_mint_with_extensions() {
     // NOTE: Mint bump is 252, which requires 4 iterations to find the mint key. Authority bump is
     // 255, which requires 1 iteration to find the authority key. Each bump iteration costs 1,500
     // CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 5 * 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 5, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         7_550,
@@ -528,7 +544,8 @@ async fn test_init_mint_with_extensions() {
     // NOTE: Mint bump is 252, which requires 4 iterations to find the mint key. Authority bump is
     // 255, which requires 1 iteration to find the authority key. Each bump iteration costs 1,500
     // CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 5 * 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 5, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         7_550,
@@ -554,7 +571,8 @@ async fn test_init_mint_with_extensions() {
     // NOTE: Mint bump is 252, which requires 4 iterations to find the mint key. Authority bump is
     // 255, which requires 1 iteration to find the authority key. Each bump iteration costs 1,500
     // CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 5 * 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 5, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         13_000,
@@ -588,7 +606,8 @@ async fn test_init_mint_with_extensions() {
     // NOTE: Mint bump is 252, which requires 4 iterations to find the mint key. Authority bump is
     // 255, which requires 1 iteration to find the authority key. Each bump iteration costs 1,500
     // CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 5 * 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 5, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         31_150,
@@ -615,7 +634,8 @@ async fn test_init_token_account_token_2022_program() {
     // NOTE: Mint bump is 252, which requires 4 iterations to find the mint key. Token account bump
     // is 252, which requires 4 iterations to find the token account key. Each bump iteration costs
     // 1,500 CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 8 * 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 8, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         5_150,
@@ -685,7 +705,8 @@ async fn test_init_token_account_with_extensions() {
     // NOTE: Mint bump is 252, which requires 4 iterations to find the mint key. Token account bump
     // is 252, which requires 4 iterations to find the token account key. Each bump iteration costs
     // 1,500 CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 8 * 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 8, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         6_450,
@@ -709,7 +730,8 @@ async fn test_init_token_account_with_extensions() {
     // NOTE: Mint bump is 252, which requires 4 iterations to find the mint key. Token account bump
     // is 252, which requires 4 iterations to find the token account key. Each bump iteration costs
     // 1,500 CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 8 * 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 8, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         6_600,
@@ -733,7 +755,8 @@ async fn test_init_token_account_with_extensions() {
     // NOTE: Mint bump is 252, which requires 4 iterations to find the mint key. Token account bump
     // is 252, which requires 4 iterations to find the token account key. Each bump iteration costs
     // 1,500 CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 8 * 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 8, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         6_450,
@@ -759,7 +782,8 @@ async fn test_init_token_account_with_extensions() {
     // NOTE: Mint bump is 252, which requires 4 iterations to find the mint key. Token account bump
     // is 252, which requires 4 iterations to find the token account key. Each bump iteration costs
     // 1,500 CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 8 * 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 8, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         8_050,
@@ -783,7 +807,8 @@ async fn test_init_token_account_with_extensions() {
     // NOTE: Mint bump is 252, which requires 4 iterations to find the mint key. Token account bump
     // is 252, which requires 4 iterations to find the token account key. Each bump iteration costs
     // 1,500 CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 8 * 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 8, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         6_600,
@@ -807,7 +832,8 @@ async fn test_init_token_account_with_extensions() {
     // NOTE: Mint bump is 252, which requires 4 iterations to find the mint key. Token account bump
     // is 252, which requires 4 iterations to find the token account key. Each bump iteration costs
     // 1,500 CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 8 * 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 8, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         8_050,
@@ -835,7 +861,8 @@ async fn test_init_token_account_with_extensions() {
     // NOTE: Mint bump is 252, which requires 4 iterations to find the mint key. Token account bump
     // is 252, which requires 4 iterations to find the token account key. Each bump iteration costs
     // 1,500 CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 8 * 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 8, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         7_750,
@@ -888,7 +915,8 @@ async fn test_init_token_account_token_2022_program_immutable_owner() {
     // NOTE: Mint bump is 252, which requires 4 iterations to find the mint key. Token account bump
     // is 252, which requires 4 iterations to find the token account key. Each bump iteration costs
     // 1,500 CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 8 * 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 8, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         6_750,
@@ -911,7 +939,8 @@ async fn test_mint_to_token_2022_program() {
             .unwrap();
     // NOTE: Mint authority bump is 255, which requires 1 iteration to find the mint authority key.
     // Each bump iteration costs 1,500 CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 1, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         2_550,
@@ -934,7 +963,8 @@ async fn test_suboptimal_mint_to_token_2022_program() {
             .unwrap();
     // NOTE: Mint authority bump is 255, which requires 1 iteration to find the mint authority key.
     // Each bump iteration costs 1,500 CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 1, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         3_000,
@@ -1061,7 +1091,8 @@ async fn test_init_mint_token_program_and_freeze_authority() {
     // NOTE: Mint bump is 252, which requires 4 iterations to find the mint key. Authority bump is
     // 255, which requires 1 iteration to find the authority key. Each bump iteration costs 1,500
     // CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 5 * 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 5, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         6_450,
@@ -1088,7 +1119,8 @@ async fn test_init_mint_token_2022_program_and_freeze_authority() {
     // NOTE: Mint bump is 252, which requires 4 iterations to find the mint key. Authority bump is
     // 255, which requires 1 iteration to find the authority key. Each bump iteration costs 1,500
     // CU.
-    let adjusted_compute_units_consumed = tx_meta.compute_units_consumed - 5 * 1_500;
+    let adjusted_compute_units_consumed =
+        adjust_for_bump_iterations(tx_meta.compute_units_consumed, 5, BUMP_ITERATION_CU);
     assert!(is_compute_units_within(
         adjusted_compute_units_consumed,
         5_050,
@@ -0,0 +1,146 @@
+//! Derive macros for [sealevel-tools]. These macros are re-exported by [sealevel-tools] (with the
+//! "derive" feature enabled), so there is no need to add this crate as a dependency.
+//!
+//! [sealevel-tools]: https://docs.rs/sealevel-tools
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse_macro_input, DeriveInput, Ident, LitByteStr, LitInt, LitStr};
+
+const DEFAULT_PREFIX: &str = "account:";
+const DEFAULT_LEN: usize = 8;
+const MAX_LEN: usize = 32;
+
+/// Derive `Discriminate` for a type, where the discriminator is the hash of the type's name with a
+/// prefix. By default, the discriminator is the first 8 bytes of the Sha2 hash of
+/// `"account:{TypeName}"`, which matches [anchor-lang]'s account discriminators.
+///
+/// Use the `discriminator` attribute to override the prefix, the length and the hashing function
+/// (one of "sha2", "sha3" or "keccak"):
+/// ```ignore
+/// use sealevel_tools::discriminator::Discriminate;
+///
+/// #[derive(Discriminate)]
+/// #[discriminator(prefix = "event:", len = 4, hash = "keccak")]
+/// pub struct ThingCreated {
+///     pub value: u64,
+/// }
+/// ```
+///
+/// [anchor-lang]: https://docs.rs/anchor-lang
+#[proc_macro_derive(Discriminate, attributes(discriminator))]
+pub fn derive_discriminate(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    _derive_discriminate(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn _derive_discriminate(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let mut prefix = String::from(DEFAULT_PREFIX);
+    let mut len = DEFAULT_LEN;
+    let mut hash = Ident::new("Sha2", Span::call_site());
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("discriminator"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("prefix") {
+                prefix = meta.value()?.parse::<LitStr>()?.value();
+            } else if meta.path.is_ident("len") {
+                let lit = meta.value()?.parse::<LitInt>()?;
+                len = lit.base10_parse()?;
+
+                if len == 0 || len > MAX_LEN {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        format!("len must be between 1 and {MAX_LEN}"),
+                    ));
+                }
+            } else if meta.path.is_ident("hash") {
+                let lit = meta.value()?.parse::<LitStr>()?;
+                let variant = match lit.value().as_str() {
+                    "sha2" => "Sha2",
+                    "sha3" => "Sha3",
+                    "keccak" => "Keccak",
+                    _ => {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            "hash must be one of \"sha2\", \"sha3\" or \"keccak\"",
+                        ))
+                    }
+                };
+                hash = Ident::new(variant, lit.span());
+            } else {
+                return Err(meta.error("expected `prefix`, `len` or `hash`"));
+            }
+
+            Ok(())
+        })?;
+    }
+
+    let name = &input.ident;
+    let preimage = LitByteStr::new(format!("{prefix}{name}").as_bytes(), name.span());
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::sealevel_tools::discriminator::Discriminate<#len>
+            for #name #ty_generics #where_clause
+        {
+            const DISCRIMINATOR: [u8; #len] =
+                ::sealevel_tools::discriminator::Discriminator::#hash(#preimage).to_bytes();
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn expand(input: &str) -> String {
+        _derive_discriminate(syn::parse_str(input).unwrap())
+            .map(|tokens| tokens.to_string())
+            .unwrap_or_else(|err| err.to_string())
+    }
+
+    #[test]
+    fn test_default() {
+        let expanded = expand("pub struct Thing { value: u64 }");
+
+        assert!(expanded.contains("Discriminate < 8usize >"));
+        assert!(expanded.contains("Discriminator :: Sha2 (b\"account:Thing\")"));
+    }
+
+    #[test]
+    fn test_overrides() {
+        let expanded = expand(
+            r#"
+            #[discriminator(prefix = "event:", len = 4, hash = "keccak")]
+            pub struct ThingCreated { value: u64 }
+            "#,
+        );
+
+        assert!(expanded.contains("Discriminate < 4usize >"));
+        assert!(expanded.contains("Discriminator :: Keccak (b\"event:ThingCreated\")"));
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            expand("#[discriminator(len = 33)] pub struct Thing;"),
+            "len must be between 1 and 32"
+        );
+        assert_eq!(
+            expand("#[discriminator(hash = \"md5\")] pub struct Thing;"),
+            "hash must be one of \"sha2\", \"sha3\" or \"keccak\""
+        );
+        assert_eq!(
+            expand("#[discriminator(salt = \"a\")] pub struct Thing;"),
+            "expected `prefix`, `len` or `hash`"
+        );
+    }
+}
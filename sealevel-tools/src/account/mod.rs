@@ -18,6 +18,14 @@ pub mod bpf_loader_upgradeable {
     crate::declare_id!("BPFLoaderUpgradeab1e11111111111111111111111");
 }
 
+/// Namespace for the incinerator address. Lamports sent to this account are burned at the end of
+/// the slot.
+pub mod incinerator {
+    crate::declare_id!("1nc1nerator11111111111111111111111111111111");
+}
+
+#[cfg(feature = "alloc")]
+use alloc::format;
 use core::ops::{Deref, DerefMut};
 
 #[cfg(feature = "alloc")]
@@ -25,7 +33,14 @@ use core::borrow::Borrow;
 
 use solana_program_pack::{IsInitialized, Pack};
 
-use crate::{discriminator::Discriminate, program_error::ProgramError};
+use crate::{
+    discriminator::Discriminate,
+    entrypoint::{NoStdAccountInfo, ProgramResult},
+    error::SealevelToolsError,
+    pda::append_bump_seed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
 
 /// Trait used to define a serializable account schema, which includes a discriminator. If the
 /// account does not have a discriminator, use DISC_LEN == 0.
@@ -95,8 +110,15 @@ pub trait AccountSerde<const DISC_LEN: usize>: Sized + Discriminate<DISC_LEN> {
     /// Compute serialized length including its discriminator.
     fn try_account_schema_space(&self) -> Result<usize, ProgramError>;
 
+    /// Check the discriminator and deserialize the remaining data. If the data is shorter than the
+    /// discriminator, [ProgramError::AccountDataTooSmall] is returned.
     #[inline(always)]
     fn try_deserialize_data(data: &mut &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < DISC_LEN {
+            crate::log::sol_log("Account data too small for discriminator");
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+
         let _: [u8; DISC_LEN] = match data[..DISC_LEN].try_into() {
             Ok(discriminator) if discriminator == Self::DISCRIMINATOR => discriminator,
             _ => {
@@ -147,6 +169,250 @@ where
     }
 }
 
+/// Determine whether an account looks uninitialized, which is when it is owned by the System
+/// program and has no data. This heuristic is useful for deciding between create and update paths
+/// (e.g. whether to invoke [CreateAccount]).
+///
+/// ### Notes
+///
+/// Whether an account was created in the current transaction is not directly observable. This
+/// heuristic cannot distinguish an uninitialized account from a legitimately empty System account
+/// (like a wallet holding lamports). So do not use this check to authorize anything; verify the
+/// account's key (e.g. as a PDA) separately.
+///
+/// [CreateAccount]: crate::cpi::system_program::CreateAccount
+#[inline(always)]
+pub fn looks_uninitialized(account: &NoStdAccountInfo) -> bool {
+    account.data_len() == 0 && account.owner() == &system::ID
+}
+
+/// Read a bump seed stored in an account's data at the given offset. Storing the canonical bump in
+/// account data allows later instructions to use the cheaper [Pubkey::create_program_address]
+/// instead of [Pubkey::find_program_address].
+///
+/// Returns [ProgramError::AccountDataTooSmall] if the offset is out of bounds.
+#[inline(always)]
+pub fn read_stored_bump(account: &NoStdAccountInfo, offset: usize) -> Result<u8, ProgramError> {
+    account
+        .try_borrow_data()?
+        .get(offset)
+        .copied()
+        .ok_or(ProgramError::AccountDataTooSmall)
+}
+
+/// Verify that an account's key is the PDA derived from the given seeds and the bump seed stored in
+/// its data at `bump_offset` (see [read_stored_bump]).
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account::verify_pda_with_stored_bump,
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+///     pubkey::Pubkey,
+/// };
+///
+/// // Thing's bump is stored after its 8-byte discriminator.
+/// const THING_BUMP_OFFSET: usize = 8;
+///
+/// fn check_thing(program_id: &Pubkey, thing_account: &NoStdAccountInfo) -> ProgramResult {
+///     verify_pda_with_stored_bump(thing_account, &[b"thing"], program_id, THING_BUMP_OFFSET)
+/// }
+/// ```
+#[inline(always)]
+pub fn verify_pda_with_stored_bump(
+    account: &NoStdAccountInfo,
+    seeds: &[&[u8]],
+    program_id: &Pubkey,
+    bump_offset: usize,
+) -> ProgramResult {
+    _verify_pda_with_bump(
+        account,
+        seeds,
+        program_id,
+        read_stored_bump(account, bump_offset)?,
+    )
+}
+
+/// Read a bump seed stored as the last byte of an account's data. Storing the canonical bump at
+/// the end of the data is a space-efficient convention, which does not require knowing the offset
+/// of the bump within the account's schema.
+///
+/// Returns [ProgramError::AccountDataTooSmall] if the account's data is empty.
+#[inline(always)]
+pub fn read_trailing_bump(account: &NoStdAccountInfo) -> Result<u8, ProgramError> {
+    _read_trailing_bump(&account.try_borrow_data()?)
+}
+
+/// Verify that an account's key is the PDA derived from the given seeds and the bump seed stored as
+/// the last byte of its data (see [read_trailing_bump]).
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account::verify_pda_trailing_bump,
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+///     pubkey::Pubkey,
+/// };
+///
+/// fn check_thing(program_id: &Pubkey, thing_account: &NoStdAccountInfo) -> ProgramResult {
+///     verify_pda_trailing_bump(thing_account, &[b"thing"], program_id)
+/// }
+/// ```
+#[inline(always)]
+pub fn verify_pda_trailing_bump(
+    account: &NoStdAccountInfo,
+    seeds: &[&[u8]],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    _verify_pda_with_bump(account, seeds, program_id, read_trailing_bump(account)?)
+}
+
+#[inline(always)]
+fn _read_trailing_bump(data: &[u8]) -> Result<u8, ProgramError> {
+    data.last()
+        .copied()
+        .ok_or(ProgramError::AccountDataTooSmall)
+}
+
+#[inline(always)]
+fn _verify_pda_with_bump(
+    account: &NoStdAccountInfo,
+    seeds: &[&[u8]],
+    program_id: &Pubkey,
+    bump: u8,
+) -> ProgramResult {
+    let bump_seed = [bump];
+    let (seeds_with_bump, len) = append_bump_seed(seeds, &bump_seed)?;
+
+    match Pubkey::create_program_address(&seeds_with_bump[..len], program_id) {
+        Ok(expected_key) if account.key() == &expected_key => Ok(()),
+        _ => Err(SealevelToolsError::AccountInfo(&[
+            "Account does not match PDA derived from stored bump",
+        ])
+        .into()),
+    }
+}
+
+/// Read a nonce stored in an account's data at the given offset. The nonce is stored as a
+/// little-endian u64 (see [check_and_bump_nonce]).
+///
+/// Returns [ProgramError::AccountDataTooSmall] if the nonce does not fit in the data at the offset.
+#[inline(always)]
+pub fn read_nonce(account: &NoStdAccountInfo, offset: usize) -> Result<u64, ProgramError> {
+    _read_nonce(&account.try_borrow_data()?, offset)
+}
+
+/// Check that the provided nonce equals the nonce stored in an account's data at the given offset,
+/// then increment the stored nonce. This pattern protects against replaying signed off-chain
+/// messages, where each message must commit to the current nonce.
+///
+/// ### Notes
+///
+/// The nonce is assumed to be stored as a little-endian u64 (which is how [borsh] serializes u64)
+/// at `offset`, which should account for any discriminator preceding it. The account must be
+/// writable.
+///
+/// If the provided nonce does not match, a custom program error code with
+/// [SealevelToolsError::ACCOUNT_INFO] is returned. If the stored nonce is [u64::MAX],
+/// [ProgramError::ArithmeticOverflow] is returned.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account::check_and_bump_nonce,
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+/// };
+///
+/// // Nonce is stored after its 8-byte discriminator.
+/// const NONCE_OFFSET: usize = 8;
+///
+/// fn consume_message(nonce_account: &NoStdAccountInfo, message_nonce: u64) -> ProgramResult {
+///     check_and_bump_nonce(nonce_account, NONCE_OFFSET, message_nonce)?;
+///
+///     // Process the message.
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [borsh]: https://docs.rs/borsh/latest/borsh/
+#[inline(always)]
+pub fn check_and_bump_nonce(
+    account: &NoStdAccountInfo,
+    offset: usize,
+    provided_nonce: u64,
+) -> ProgramResult {
+    if !account.is_writable() {
+        return Err(SealevelToolsError::AccountInfo(&["Expected writable nonce account"]).into());
+    }
+
+    _check_and_bump_nonce(&mut account.try_borrow_mut_data()?, offset, provided_nonce)
+}
+
+/// Assert that an account's data length equals [Pack::LEN] before unpacking it. [Pack::unpack]
+/// fails with [ProgramError::InvalidAccountData] if the length is wrong, so this check gives a
+/// more diagnosable error naming the expected and actual lengths.
+///
+/// ### Notes
+///
+/// This check is only meant for base-state accounts (like legacy Token program mints and token
+/// accounts). Token Extensions program accounts with extensions have data longer than
+/// [Pack::LEN], so this check intentionally fails for them.
+///
+/// If the length does not match, a custom program error code with
+/// [SealevelToolsError::ACCOUNT_INFO] is returned.
+#[inline(always)]
+pub fn assert_pack_len<T: Pack>(account: &NoStdAccountInfo) -> ProgramResult {
+    _assert_pack_len(account.data_len(), T::LEN)
+}
+
+#[inline(always)]
+fn _read_nonce(data: &[u8], offset: usize) -> Result<u64, ProgramError> {
+    data.get(offset..)
+        .and_then(|data| data.first_chunk::<8>())
+        .map(|nonce| u64::from_le_bytes(*nonce))
+        .ok_or(ProgramError::AccountDataTooSmall)
+}
+
+#[inline(always)]
+fn _check_and_bump_nonce(data: &mut [u8], offset: usize, provided_nonce: u64) -> ProgramResult {
+    let stored_nonce = _read_nonce(data, offset)?;
+
+    if provided_nonce != stored_nonce {
+        return Err(SealevelToolsError::AccountInfo(&["Nonce does not match stored nonce"]).into());
+    }
+
+    let next_nonce = stored_nonce
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    data[offset..(offset + 8)].copy_from_slice(&next_nonce.to_le_bytes());
+
+    Ok(())
+}
+
+#[inline(always)]
+fn _assert_pack_len(data_len: usize, expected_len: usize) -> ProgramResult {
+    if data_len == expected_len {
+        return Ok(());
+    }
+
+    #[cfg(feature = "alloc")]
+    return Err(SealevelToolsError::AccountInfo(&[
+        "Account data length does not match packed length...",
+        format!("  Found: {}", data_len).as_str(),
+        format!("  Expected: {}", expected_len).as_str(),
+    ])
+    .into());
+    #[cfg(not(feature = "alloc"))]
+    return Err(SealevelToolsError::AccountInfo(&[
+        "Account data length does not match packed length",
+    ])
+    .into());
+}
+
 /// Wrapper around a type implementing [Pack] and [IsInitialized].
 #[derive(Clone, PartialEq, Eq)]
 pub struct PackAccountSchema<T: Pack + IsInitialized>(pub T);
@@ -187,3 +453,350 @@ impl<T: Pack + IsInitialized> AccountSerde<0> for PackAccountSchema<T> {
         Ok(T::LEN)
     }
 }
+
+/// Wrapper around a type implementing [Pack] and [IsInitialized], whose packed data is prefixed
+/// with a discriminator. This schema is useful for storing SPL-style packed structs alongside
+/// discriminated (e.g. borsh) accounts in the same program.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account::{AccountSerde, DiscriminatedPackSchema},
+///     discriminator::{Discriminate, Discriminator},
+///     program_error::ProgramError,
+/// };
+/// use solana_program_pack::{IsInitialized, Pack, Sealed};
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// struct Counter {
+///     is_initialized: bool,
+///     count: u8,
+/// }
+///
+/// impl Discriminate<8> for Counter {
+///     const DISCRIMINATOR: [u8; 8] = Discriminator::Sha2(b"account:Counter").to_bytes();
+/// }
+///
+/// impl Sealed for Counter {}
+///
+/// impl IsInitialized for Counter {
+///     fn is_initialized(&self) -> bool {
+///         self.is_initialized
+///     }
+/// }
+///
+/// impl Pack for Counter {
+///     const LEN: usize = 2;
+///
+///     fn pack_into_slice(&self, dst: &mut [u8]) {
+///         dst[0] = self.is_initialized.into();
+///         dst[1] = self.count;
+///     }
+///
+///     fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+///         Ok(Self {
+///             is_initialized: src[0] == 1,
+///             count: src[1],
+///         })
+///     }
+/// }
+///
+/// let counter = DiscriminatedPackSchema(Counter {
+///     is_initialized: true,
+///     count: 69,
+/// });
+/// assert_eq!(counter.try_account_space(), Ok(10));
+///
+/// let mut data = [0; 10];
+/// counter.try_serialize_data(&mut data).unwrap();
+/// assert_eq!(&data[..8], &Counter::DISCRIMINATOR);
+///
+/// let decoded = DiscriminatedPackSchema::<8, Counter>::try_deserialize_data(&mut &data[..]);
+/// assert_eq!(decoded.map(|counter| counter.count), Ok(69));
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct DiscriminatedPackSchema<
+    const DISC_LEN: usize,
+    T: Discriminate<DISC_LEN> + Pack + IsInitialized,
+>(pub T);
+
+impl<const DISC_LEN: usize, T: Discriminate<DISC_LEN> + Pack + IsInitialized> Discriminate<DISC_LEN>
+    for DiscriminatedPackSchema<DISC_LEN, T>
+{
+    const DISCRIMINATOR: [u8; DISC_LEN] = T::DISCRIMINATOR;
+}
+
+impl<const DISC_LEN: usize, T: Discriminate<DISC_LEN> + Pack + IsInitialized> Deref
+    for DiscriminatedPackSchema<DISC_LEN, T>
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const DISC_LEN: usize, T: Discriminate<DISC_LEN> + Pack + IsInitialized> DerefMut
+    for DiscriminatedPackSchema<DISC_LEN, T>
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<const DISC_LEN: usize, T: Discriminate<DISC_LEN> + Pack + IsInitialized> AccountSerde<DISC_LEN>
+    for DiscriminatedPackSchema<DISC_LEN, T>
+{
+    #[inline(always)]
+    fn try_deserialize_schema(data: &mut &[u8]) -> Result<Self, ProgramError> {
+        T::unpack(data).map(Self)
+    }
+
+    #[inline(always)]
+    fn try_serialize_schema(&self, buf: &mut [u8]) -> Result<(), ProgramError> {
+        if buf.len() < T::LEN {
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+
+        self.0.pack_into_slice(buf);
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn try_account_schema_space(&self) -> Result<usize, ProgramError> {
+        Ok(T::LEN)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Thing {
+        value: u8,
+    }
+
+    impl Discriminate<8> for Thing {
+        const DISCRIMINATOR: [u8; 8] =
+            crate::discriminator::Discriminator::Sha2(b"state::Thing").to_bytes();
+    }
+
+    impl AccountSerde<8> for Thing {
+        fn try_deserialize_schema(data: &mut &[u8]) -> Result<Self, ProgramError> {
+            data.first()
+                .map(|&value| Thing { value })
+                .ok_or(ProgramError::InvalidAccountData)
+        }
+
+        fn try_serialize_schema(&self, buf: &mut [u8]) -> Result<(), ProgramError> {
+            buf[0] = self.value;
+            Ok(())
+        }
+
+        fn try_account_schema_space(&self) -> Result<usize, ProgramError> {
+            Ok(1)
+        }
+    }
+
+    #[test]
+    fn test_incinerator_id() {
+        assert_eq!(incinerator::ID, solana_sdk::incinerator::ID);
+    }
+
+    #[test]
+    fn test_looks_uninitialized() {
+        use crate::test_fixture::{TestAccount, TestAccountInfos};
+
+        let mut discriminated = [0; 9];
+        discriminated[..8].copy_from_slice(&Thing::DISCRIMINATOR);
+
+        let program_id = Pubkey::new_unique();
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(Pubkey::new_unique(), system::ID),
+            TestAccount::new(Pubkey::new_unique(), system::ID).data(&[0; 9]),
+            TestAccount::new(Pubkey::new_unique(), program_id).data(&[0; 9]),
+            TestAccount::new(Pubkey::new_unique(), program_id).data(&discriminated),
+            TestAccount::new(Pubkey::new_unique(), program_id),
+        ]);
+
+        // Zero-length data owned by the System program.
+        assert!(looks_uninitialized(&infos[0]));
+
+        // All-zero data, whether owned by the System program or another program.
+        assert!(!looks_uninitialized(&infos[1]));
+        assert!(!looks_uninitialized(&infos[2]));
+
+        // Data with a discriminator.
+        assert!(!looks_uninitialized(&infos[3]));
+
+        // Zero-length data owned by another program.
+        assert!(!looks_uninitialized(&infos[4]));
+    }
+
+    #[test]
+    fn test_try_deserialize_data_too_small() {
+        let data = [1, 2, 3];
+
+        assert_eq!(
+            Thing::try_deserialize_data(&mut &data[..]),
+            Err(ProgramError::AccountDataTooSmall)
+        );
+    }
+
+    #[test]
+    fn test_try_deserialize_data() {
+        let mut data = [0; 9];
+        data[..8].copy_from_slice(&Thing::DISCRIMINATOR);
+        data[8] = 69;
+
+        assert_eq!(
+            Thing::try_deserialize_data(&mut &data[..]),
+            Ok(Thing { value: 69 })
+        );
+
+        data[0] = data[0].wrapping_add(1);
+        assert_eq!(
+            Thing::try_deserialize_data(&mut &data[..]),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct PackedThing {
+        is_initialized: bool,
+        value: u8,
+    }
+
+    impl Discriminate<4> for PackedThing {
+        const DISCRIMINATOR: [u8; 4] = [1, 2, 3, 4];
+    }
+
+    impl solana_program_pack::Sealed for PackedThing {}
+
+    impl IsInitialized for PackedThing {
+        fn is_initialized(&self) -> bool {
+            self.is_initialized
+        }
+    }
+
+    impl Pack for PackedThing {
+        const LEN: usize = 2;
+
+        fn pack_into_slice(&self, dst: &mut [u8]) {
+            dst[0] = self.is_initialized.into();
+            dst[1] = self.value;
+        }
+
+        fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+            Ok(Self {
+                is_initialized: src[0] == 1,
+                value: src[1],
+            })
+        }
+    }
+
+    #[test]
+    fn test_discriminated_pack_schema() {
+        let thing = DiscriminatedPackSchema(PackedThing {
+            is_initialized: true,
+            value: 69,
+        });
+        assert_eq!(thing.try_account_space(), Ok(6));
+
+        let mut data = [0; 6];
+        assert_eq!(thing.try_serialize_data(&mut data), Ok(()));
+        assert_eq!(data, [1, 2, 3, 4, 1, 69]);
+
+        assert_eq!(
+            DiscriminatedPackSchema::try_deserialize_data(&mut &data[..]).map(|thing| thing.0),
+            Ok(PackedThing {
+                is_initialized: true,
+                value: 69,
+            })
+        );
+
+        // Buffer too small for packed data.
+        assert_eq!(
+            thing.try_serialize_data(&mut data[..5]),
+            Err(ProgramError::AccountDataTooSmall)
+        );
+
+        // Uninitialized.
+        data[4] = 0;
+        assert_eq!(
+            DiscriminatedPackSchema::<4, PackedThing>::try_deserialize_data(&mut &data[..])
+                .map(|thing| thing.0),
+            Err(ProgramError::UninitializedAccount)
+        );
+
+        // Invalid discriminator.
+        data[4] = 1;
+        data[0] = 0;
+        assert_eq!(
+            DiscriminatedPackSchema::<4, PackedThing>::try_deserialize_data(&mut &data[..])
+                .map(|thing| thing.0),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_assert_pack_len() {
+        assert_eq!(_assert_pack_len(82, 82), Ok(()));
+        assert_eq!(
+            _assert_pack_len(165, 82),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+        assert_eq!(
+            _assert_pack_len(0, 82),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+    }
+
+    #[test]
+    fn test_check_and_bump_nonce() {
+        let mut data = [0; 16];
+        data[8..].copy_from_slice(&69_u64.to_le_bytes());
+
+        assert_eq!(_read_nonce(&data, 8), Ok(69));
+        assert_eq!(_check_and_bump_nonce(&mut data, 8, 69), Ok(()));
+        assert_eq!(_read_nonce(&data, 8), Ok(70));
+
+        // Replaying the same nonce fails and leaves the stored nonce unchanged.
+        assert_eq!(
+            _check_and_bump_nonce(&mut data, 8, 69),
+            Err(SealevelToolsError::AccountInfo(&["Nonce does not match stored nonce"]).into())
+        );
+        assert_eq!(_read_nonce(&data, 8), Ok(70));
+
+        // Out of bounds.
+        assert_eq!(
+            _check_and_bump_nonce(&mut data, 9, 70),
+            Err(ProgramError::AccountDataTooSmall)
+        );
+        assert_eq!(
+            _check_and_bump_nonce(&mut data, usize::MAX, 70),
+            Err(ProgramError::AccountDataTooSmall)
+        );
+
+        // Overflow.
+        data[8..].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert_eq!(
+            _check_and_bump_nonce(&mut data, 8, u64::MAX),
+            Err(ProgramError::ArithmeticOverflow)
+        );
+        assert_eq!(_read_nonce(&data, 8), Ok(u64::MAX));
+    }
+
+    #[test]
+    fn test_read_trailing_bump() {
+        assert_eq!(_read_trailing_bump(&[1, 2, 255]), Ok(255));
+        assert_eq!(_read_trailing_bump(&[254]), Ok(254));
+        assert_eq!(
+            _read_trailing_bump(&[]),
+            Err(ProgramError::AccountDataTooSmall)
+        );
+    }
+}
@@ -13,12 +13,16 @@ pub mod legacy_token {
     crate::declare_id!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
 }
 
+#[cfg(feature = "alloc")]
+use alloc::format;
 use core::ops::{Deref, DerefMut};
 
 use solana_program_pack::Pack;
 
 use crate::{
     discriminator::Discriminate,
+    entrypoint::ProgramResult,
+    error::SealevelToolsError,
     program_error::ProgramError,
     pubkey::Pubkey,
     spl_token_2022::extension::{BaseState, StateWithExtensions},
@@ -85,7 +89,184 @@ impl<'a> AssociatedTokenAccountSeeds<'a> {
             program_id.unwrap_or(&ata::ID),
         )
     }
+
+    /// Create the Associated Token Account address with a known bump, which is much cheaper than
+    /// [Self::find_program_address]. If the program ID is not provided, the official ATA program ID
+    /// will be used.
+    pub fn create_program_address(
+        &self,
+        bump_seed: u8,
+        program_id: Option<&Pubkey>,
+    ) -> Option<Pubkey> {
+        Pubkey::create_program_address(
+            &[
+                &self.owner.to_bytes(),
+                &self.token_program_id.to_bytes(),
+                &self.mint.to_bytes(),
+                &[bump_seed],
+            ],
+            program_id.unwrap_or(&ata::ID),
+        )
+        .ok()
+    }
+}
+
+/// Find the canonical Associated Token Account address (and its bump) for the given owner, mint and
+/// Token program ID using the official ATA program ID. This method mirrors
+/// `get_associated_token_address_with_program_id` found in the [spl_associated_token_account]
+/// crate, but also returns the bump.
+///
+/// [spl_associated_token_account]: https://docs.rs/spl-associated-token-account/latest/spl_associated_token_account/
+#[inline(always)]
+pub fn find_associated_token_address(
+    owner: &Pubkey,
+    mint: &Pubkey,
+    token_program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    AssociatedTokenAccountSeeds {
+        owner,
+        token_program_id,
+        mint,
+    }
+    .find_program_address(None)
+}
+
+/// Assert that the token account key is the canonical Associated Token Account for the given owner,
+/// mint and Token program ID (using the official ATA program ID). This check is useful where
+/// protocol invariants assume the ATA instead of an arbitrary token account owned by `owner`.
+///
+/// If the bump is known (e.g. stored in an account), providing it uses the cheaper
+/// [AssociatedTokenAccountSeeds::create_program_address] instead of finding the address.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account::{assert_is_ata, find_associated_token_address, legacy_token},
+///     pubkey::Pubkey,
+/// };
+///
+/// let owner = Pubkey::new_unique();
+/// let mint = Pubkey::new_unique();
+/// let (ata_key, bump) = find_associated_token_address(&owner, &mint, &legacy_token::ID);
+///
+/// assert!(assert_is_ata(&ata_key, &owner, &mint, &legacy_token::ID, None).is_ok());
+/// assert!(assert_is_ata(&ata_key, &owner, &mint, &legacy_token::ID, Some(bump)).is_ok());
+/// ```
+#[inline(always)]
+pub fn assert_is_ata(
+    token_account_key: &Pubkey,
+    owner: &Pubkey,
+    mint: &Pubkey,
+    token_program_id: &Pubkey,
+    bump: Option<u8>,
+) -> ProgramResult {
+    let seeds = AssociatedTokenAccountSeeds {
+        owner,
+        token_program_id,
+        mint,
+    };
+
+    let derived = match bump {
+        Some(bump) => seeds.create_program_address(bump, None),
+        None => Some(seeds.find_program_address(None).0),
+    };
+
+    match derived {
+        Some(derived) if &derived == token_account_key => Ok(()),
+        Some(derived) => {
+            #[cfg(feature = "alloc")]
+            return Err(SealevelToolsError::AccountInfo(&[
+                "Token account is not the Associated Token Account...",
+                format!("  Found: {}", token_account_key).as_str(),
+                format!("  Expected: {}", derived).as_str(),
+            ])
+            .into());
+            #[cfg(not(feature = "alloc"))]
+            {
+                let _ = derived;
+                Err(SealevelToolsError::AccountInfo(&[
+                    "Token account is not the Associated Token Account",
+                ])
+                .into())
+            }
+        }
+        None => {
+            Err(SealevelToolsError::AccountInfo(&["Invalid Associated Token Account bump"]).into())
+        }
+    }
 }
 
 #[deprecated(note = "Please use the `AssociatedTokenAccountSeeds` type instead.")]
 pub type AtaSeeds<'a> = AssociatedTokenAccountSeeds<'a>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ata_program_id() {
+        assert_eq!(ata::ID, spl_associated_token_account::ID);
+    }
+
+    #[test]
+    fn test_find_associated_token_address() {
+        let owner = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+
+        for token_program_id in [&legacy_token::ID, &token_extensions::ID] {
+            let (addr, _) = find_associated_token_address(&owner, &mint, token_program_id);
+            assert_eq!(
+                addr,
+                spl_associated_token_account::get_associated_token_address_with_program_id(
+                    &owner,
+                    &mint,
+                    token_program_id
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_assert_is_ata() {
+        let owner = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+
+        for token_program_id in [&legacy_token::ID, &token_extensions::ID] {
+            let ata_key =
+                spl_associated_token_account::get_associated_token_address_with_program_id(
+                    &owner,
+                    &mint,
+                    token_program_id,
+                );
+            let (_, bump) = find_associated_token_address(&owner, &mint, token_program_id);
+
+            assert_eq!(
+                assert_is_ata(&ata_key, &owner, &mint, token_program_id, None),
+                Ok(())
+            );
+            assert_eq!(
+                assert_is_ata(&ata_key, &owner, &mint, token_program_id, Some(bump)),
+                Ok(())
+            );
+
+            // Arbitrary token account.
+            assert_eq!(
+                assert_is_ata(&Pubkey::new_unique(), &owner, &mint, token_program_id, None),
+                Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+            );
+
+            // Wrong mint.
+            assert_eq!(
+                assert_is_ata(
+                    &ata_key,
+                    &owner,
+                    &Pubkey::new_unique(),
+                    token_program_id,
+                    Some(bump)
+                ),
+                Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+            );
+        }
+    }
+}
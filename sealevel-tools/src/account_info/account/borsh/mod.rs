@@ -1,4 +1,18 @@
-use crate::account::BorshAccountSchema;
+#[cfg(feature = "alloc")]
+use alloc::{format, vec::Vec};
+
+#[cfg(feature = "alloc")]
+use crate::{
+    account::AccountSerde, account_info::MAX_PERMITTED_DATA_INCREASE, cpi::CpiAuthority,
+    error::SealevelToolsError,
+};
+use crate::{
+    account::BorshAccountSchema,
+    borsh::{BorshDeserialize, BorshSerialize},
+    discriminator::Discriminate,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+};
 
 use super::DataAccount;
 
@@ -11,3 +25,419 @@ pub type ReadonlyBorshAccount<'a, const DISC_LEN: usize, T> = BorshAccount<'a, f
 
 /// Writable account whose data is serialized as [BorshAccountSchema].
 pub type WritableBorshAccount<'a, const DISC_LEN: usize, T> = BorshAccount<'a, true, DISC_LEN, T>;
+
+impl<'a, const DISC_LEN: usize, T> WritableBorshAccount<'a, DISC_LEN, T>
+where
+    T: Discriminate<DISC_LEN> + BorshDeserialize + BorshSerialize,
+{
+    /// Write raw bytes to the account's data at the given offset, which is relative to the end of
+    /// the discriminator. This method is cheaper than [DataAccount::try_write_data] because the
+    /// whole account schema is not serialized, which is useful for hot update paths (like a
+    /// counter embedded in a larger struct).
+    ///
+    /// Returns [ProgramError::AccountDataTooSmall] if the bytes do not fit in the account's data.
+    ///
+    /// ### Notes
+    ///
+    /// This method does not check that the bytes correspond to a field of the account schema. The
+    /// caller must know the field's borsh offset, which is only fixed for fields that are preceded
+    /// by fixed-size fields only (e.g. integers, [Pubkey] or arrays, but not [Vec], [String] or
+    /// [Option]). The bytes must be the borsh encoding of the field (e.g. `u64::to_le_bytes`).
+    /// Writing at a wrong offset will corrupt the account's data.
+    ///
+    /// [DataAccount::data] is not updated, so it will be stale after writing.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use sealevel_tools::{
+    ///     account_info::WritableBorshAccount,
+    ///     borsh::{BorshDeserialize, BorshSerialize},
+    ///     discriminator::{Discriminate, Discriminator},
+    ///     entrypoint::ProgramResult,
+    ///     pubkey::Pubkey,
+    /// };
+    ///
+    /// #[derive(BorshDeserialize, BorshSerialize)]
+    /// pub struct Thing {
+    ///     pub authority: Pubkey,
+    ///     pub counter: u64,
+    ///     pub name: String,
+    /// }
+    ///
+    /// impl Discriminate<8> for Thing {
+    ///     const DISCRIMINATOR: [u8; 8] = Discriminator::Sha2(b"Thing").to_bytes();
+    /// }
+    ///
+    /// // Counter follows the 32-byte authority.
+    /// const COUNTER_OFFSET: usize = 32;
+    ///
+    /// fn increment_counter(thing: &WritableBorshAccount<8, Thing>) -> ProgramResult {
+    ///     let counter = thing.data.counter.saturating_add(1);
+    ///
+    ///     // The counter is preceded by fixed-size fields only.
+    ///     thing.try_write_raw_bytes_at(COUNTER_OFFSET, &counter.to_le_bytes())
+    /// }
+    /// ```
+    ///
+    /// [Pubkey]: crate::pubkey::Pubkey
+    /// [String]: https://doc.rust-lang.org/std/string/struct.String.html
+    /// [Vec]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+    #[inline(always)]
+    pub fn try_write_raw_bytes_at(&self, offset: usize, bytes: &[u8]) -> ProgramResult {
+        _write_raw_bytes_at(
+            &mut self.try_borrow_mut_data()?,
+            DISC_LEN.saturating_add(offset),
+            bytes,
+        )
+    }
+}
+
+/// Push an item to a [Vec] field of a borsh account's data and write the data to the account. The
+/// field is selected with the `vec_field` closure (e.g. `|list| &mut list.items`). If the
+/// serialized data no longer fits, the account is reallocated to its new size and the payer tops
+/// up the account's lamports so it remains rent-exempt (via the System program's transfer
+/// instruction).
+///
+/// ### Notes
+///
+/// The runtime limits how much an account's data can grow in a single instruction (see
+/// [MAX_PERMITTED_DATA_INCREASE]). If the new size exceeds the account's current data length by
+/// more than this limit, a custom program error code with [SealevelToolsError::ACCOUNT_INFO] is
+/// returned. This check only accounts for growth in this call, so growing the same account more
+/// than once in an instruction can still exceed the runtime's limit. The new size is checked before
+/// the item is pushed, so the account's deserialized data is unchanged if this check fails.
+///
+/// This method requires loading [Rent] via syscall if the account grows.
+///
+/// [Rent]: solana_rent::Rent
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account_info::{try_push_to_vec_account, WritableBorshAccount},
+///     borsh::{BorshDeserialize, BorshSerialize},
+///     cpi::CpiAuthority,
+///     discriminator::{Discriminate, Discriminator},
+///     entrypoint::ProgramResult,
+///     pubkey::Pubkey,
+/// };
+///
+/// #[derive(BorshDeserialize, BorshSerialize)]
+/// pub struct Allowlist {
+///     pub authority: Pubkey,
+///     pub members: Vec<Pubkey>,
+/// }
+///
+/// impl Discriminate<8> for Allowlist {
+///     const DISCRIMINATOR: [u8; 8] = Discriminator::Sha2(b"Allowlist").to_bytes();
+/// }
+///
+/// fn add_member(
+///     allowlist: &mut WritableBorshAccount<8, Allowlist>,
+///     payer: CpiAuthority,
+///     member: Pubkey,
+/// ) -> ProgramResult {
+///     try_push_to_vec_account(allowlist, payer, |allowlist| &mut allowlist.members, member)
+/// }
+/// ```
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn try_push_to_vec_account<const DISC_LEN: usize, T, U>(
+    account: &mut WritableBorshAccount<'_, DISC_LEN, T>,
+    payer: CpiAuthority,
+    vec_field: impl FnOnce(&mut T) -> &mut Vec<U>,
+    item: U,
+) -> ProgramResult
+where
+    T: Discriminate<DISC_LEN> + BorshDeserialize + BorshSerialize,
+    U: BorshSerialize,
+{
+    // Validate the new size before pushing the item, so the account's data is left untouched if
+    // the account cannot grow. Pushing to a vector grows its borsh encoding by the item's length.
+    let current_len = account.data_len();
+    let new_len = account
+        .data
+        .try_account_space()?
+        .saturating_add(crate::borsh::object_length(&item)?);
+
+    if new_len > current_len {
+        _check_data_increase(current_len, new_len)?;
+
+        let lamports = *account.try_borrow_lamports()?;
+        let top_up = _top_up_lamports(lamports, crate::sysvar::get_rent_minimum_balance(new_len));
+
+        if top_up != 0 {
+            crate::cpi::system_program::Transfer {
+                from: payer,
+                to: account,
+                lamports: top_up,
+            }
+            .into_invoke();
+        }
+
+        account.realloc(new_len, false)?;
+    }
+
+    vec_field(&mut account.data.0).push(item);
+
+    account.try_write_data()
+}
+
+#[cfg(feature = "alloc")]
+#[inline(always)]
+fn _top_up_lamports(lamports: u64, minimum_balance: u64) -> u64 {
+    minimum_balance.saturating_sub(lamports)
+}
+
+#[cfg(feature = "alloc")]
+#[inline(always)]
+fn _check_data_increase(current_len: usize, new_len: usize) -> ProgramResult {
+    let increase = new_len.saturating_sub(current_len);
+
+    if increase <= MAX_PERMITTED_DATA_INCREASE {
+        return Ok(());
+    }
+
+    Err(SealevelToolsError::AccountInfo(&[
+        "Account data increase exceeds realloc limit...",
+        format!("  Found: {}", increase).as_str(),
+        format!("  Max: {}", MAX_PERMITTED_DATA_INCREASE).as_str(),
+    ])
+    .into())
+}
+
+#[inline(always)]
+fn _write_raw_bytes_at(data: &mut [u8], offset: usize, bytes: &[u8]) -> ProgramResult {
+    offset
+        .checked_add(bytes.len())
+        .and_then(|end| data.get_mut(offset..end))
+        .ok_or(ProgramError::AccountDataTooSmall)?
+        .copy_from_slice(bytes);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_write_raw_bytes_at() {
+        let mut data = [0; 16];
+
+        assert_eq!(
+            _write_raw_bytes_at(&mut data, 8, &69_u64.to_le_bytes()),
+            Ok(())
+        );
+        assert_eq!(data[8..], 69_u64.to_le_bytes());
+        assert_eq!(data[..8], [0; 8]);
+
+        // Out of bounds.
+        assert_eq!(
+            _write_raw_bytes_at(&mut data, 9, &69_u64.to_le_bytes()),
+            Err(ProgramError::AccountDataTooSmall)
+        );
+        assert_eq!(
+            _write_raw_bytes_at(&mut data, usize::MAX, &[1]),
+            Err(ProgramError::AccountDataTooSmall)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_top_up_lamports() {
+        assert_eq!(_top_up_lamports(1_000, 1_500), 500);
+        assert_eq!(_top_up_lamports(1_500, 1_500), 0);
+        assert_eq!(_top_up_lamports(2_000, 1_500), 0);
+    }
+
+    #[test]
+    fn test_max_permitted_data_increase() {
+        assert_eq!(
+            crate::account_info::MAX_PERMITTED_DATA_INCREASE,
+            solana_sdk::entrypoint::MAX_PERMITTED_DATA_INCREASE
+        );
+    }
+
+    #[test]
+    fn test_try_get_or_init_existing_account() {
+        use crate::{
+            cpi::CpiAuthority,
+            discriminator::Discriminator,
+            error::SealevelToolsError,
+            pubkey::Pubkey,
+            test_fixture::{TestAccount, TestAccountInfos},
+        };
+
+        #[derive(Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+        struct Thing {
+            value: u64,
+        }
+
+        impl Discriminate<8> for Thing {
+            const DISCRIMINATOR: [u8; 8] = Discriminator::Sha2(b"Thing").to_bytes();
+        }
+
+        let program_id = Pubkey::new_unique();
+
+        let mut data = [0; 16];
+        data[..8].copy_from_slice(&Thing::DISCRIMINATOR);
+        data[8..].copy_from_slice(&69_u64.to_le_bytes());
+
+        let mut wrong_discriminator_data = data;
+        wrong_discriminator_data[0] ^= 1;
+
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(Pubkey::new_unique(), program_id)
+                .data(&data)
+                .writable(),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique())
+                .data(&data)
+                .writable(),
+            TestAccount::new(Pubkey::new_unique(), program_id)
+                .data(&wrong_discriminator_data)
+                .writable(),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::default())
+                .signer()
+                .writable(),
+        ]);
+        let [owned, foreign, wrong_discriminator, payer] = &infos[..] else {
+            unreachable!()
+        };
+
+        let try_get_or_init = |account| {
+            WritableBorshAccount::<8, Thing>::try_get_or_init(
+                CpiAuthority::from_signer(account),
+                CpiAuthority::from_signer(payer),
+                &program_id,
+                BorshAccountSchema(Thing { value: 420 }),
+            )
+        };
+
+        // Existing data is deserialized instead of being replaced with the default.
+        let thing = try_get_or_init(owned).unwrap();
+        assert_eq!(thing.key(), owned.key());
+        assert_eq!(thing.data.0, Thing { value: 69 });
+        assert_eq!(&owned.try_borrow_data().unwrap()[..], &data);
+
+        // Existing account owned by another program.
+        assert_eq!(
+            try_get_or_init(foreign).err(),
+            Some(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+
+        // Existing account owned by the program, but with another account type's data.
+        assert!(try_get_or_init(wrong_discriminator).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_check_data_increase() {
+        assert_eq!(_check_data_increase(100, 100), Ok(()));
+        assert_eq!(
+            _check_data_increase(100, 100 + MAX_PERMITTED_DATA_INCREASE),
+            Ok(())
+        );
+        assert_eq!(
+            _check_data_increase(100, 101 + MAX_PERMITTED_DATA_INCREASE),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[derive(BorshDeserialize, BorshSerialize)]
+    struct Allowlist {
+        members: Vec<Vec<u8>>,
+    }
+
+    #[cfg(feature = "alloc")]
+    impl Discriminate<0> for Allowlist {
+        const DISCRIMINATOR: [u8; 0] = [];
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_try_push_to_vec_account_without_growth() {
+        use alloc::vec;
+
+        use crate::{
+            account_info::Account,
+            pubkey::Pubkey,
+            test_fixture::{TestAccount, TestAccountInfos},
+        };
+
+        // One member with 2 bytes, followed by unused space that fits another member.
+        let mut data = [0; 16];
+        data[..10].copy_from_slice(&[1, 0, 0, 0, 2, 0, 0, 0, 4, 5]);
+
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique())
+                .data(&data)
+                .writable(),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::default())
+                .signer()
+                .writable(),
+        ]);
+
+        let mut allowlist = WritableBorshAccount::<0, Allowlist>::try_from(&infos[0]).unwrap();
+        let payer = Account::<true>::try_from(&infos[1]).unwrap();
+
+        assert_eq!(
+            try_push_to_vec_account(
+                &mut allowlist,
+                payer.as_cpi_authority(None),
+                |allowlist| &mut allowlist.members,
+                vec![6, 7],
+            ),
+            Ok(())
+        );
+        assert_eq!(infos[0].data_len(), 16);
+        assert_eq!(
+            &infos[0].try_borrow_data().unwrap()[..],
+            &[2, 0, 0, 0, 2, 0, 0, 0, 4, 5, 2, 0, 0, 0, 6, 7]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_try_push_to_vec_account_exceeds_data_increase() {
+        use alloc::vec;
+
+        use crate::{
+            account_info::Account,
+            pubkey::Pubkey,
+            test_fixture::{TestAccount, TestAccountInfos},
+        };
+
+        let data = [0; 4];
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique())
+                .data(&data)
+                .writable(),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::default())
+                .signer()
+                .writable(),
+        ]);
+
+        let mut allowlist = WritableBorshAccount::<0, Allowlist>::try_from(&infos[0]).unwrap();
+        let payer = Account::<true>::try_from(&infos[1]).unwrap();
+
+        // New size is 4 + 4 + MAX_PERMITTED_DATA_INCREASE bytes.
+        assert_eq!(
+            try_push_to_vec_account(
+                &mut allowlist,
+                payer.as_cpi_authority(None),
+                |allowlist| &mut allowlist.members,
+                vec![0; MAX_PERMITTED_DATA_INCREASE],
+            ),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+
+        // Neither the deserialized data nor the account's data changed.
+        assert!(allowlist.data.members.is_empty());
+        assert_eq!(infos[0].data_len(), 4);
+        assert_eq!(&infos[0].try_borrow_data().unwrap()[..], &[0; 4]);
+    }
+}
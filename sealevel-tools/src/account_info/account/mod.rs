@@ -2,7 +2,9 @@
 mod borsh;
 mod bpf_loader_upgradeable;
 mod pack;
+mod pod;
 mod system;
+mod sysvar;
 #[cfg(feature = "token")]
 mod token;
 
@@ -11,9 +13,12 @@ pub use borsh::*;
 pub use bpf_loader_upgradeable::*;
 pub use pack::*;
 pub use system::*;
+pub use sysvar::*;
 #[cfg(feature = "token")]
 pub use token::*;
 
+#[cfg(feature = "alloc")]
+use alloc::format;
 use core::ops::Deref;
 
 use crate::{
@@ -25,14 +30,18 @@ use crate::{
     pubkey::Pubkey,
 };
 
-use super::{try_close_account, try_next_enumerated_account_info, AccountInfoConstraints};
+use super::{
+    try_close_account, try_next_enumerated_account_info, try_prev_enumerated_account_info,
+    AccountInfoConstraints,
+};
 
 /// Generic wrapper for a data account that can be read from or written to (specified by `WRITE`
 /// const parameter).
 #[derive(Clone, PartialEq, Eq)]
 pub struct Account<'a, const WRITE: bool>(pub(crate) &'a NoStdAccountInfo);
 
-/// Wrapper for a read-only account.
+/// Wrapper for a read-only account. An account marked writable is rejected, because write-locking
+/// an account that is only read from unnecessarily affects the transaction's fees and scheduling.
 pub type ReadonlyAccount<'a> = Account<'a, false>;
 
 /// Wrapper for a writable account.
@@ -49,17 +58,24 @@ impl<'a, const WRITE: bool> TryFrom<&'a NoStdAccountInfo> for Account<'a, WRITE>
 
     #[inline(always)]
     fn try_from(account: &'a NoStdAccountInfo) -> Result<Self, Self::Error> {
-        if account.is_writable() == WRITE {
-            Ok(Self(account))
-        } else if WRITE {
-            Err(SealevelToolsError::AccountInfo(&[
-                "Cannot process account as writable",
-            ]))
-        } else {
-            Err(SealevelToolsError::AccountInfo(&[
-                "Cannot process account as read-only",
-            ]))
-        }
+        _check_writable::<WRITE>(account.is_writable()).map(|_| Self(account))
+    }
+}
+
+#[inline(always)]
+fn _check_writable<const WRITE: bool>(
+    is_writable: bool,
+) -> Result<(), SealevelToolsError<'static>> {
+    if is_writable == WRITE {
+        Ok(())
+    } else if WRITE {
+        Err(SealevelToolsError::AccountInfo(&[
+            "Cannot process account as writable",
+        ]))
+    } else {
+        Err(SealevelToolsError::AccountInfo(&[
+            "Cannot process account as read-only",
+        ]))
     }
 }
 
@@ -86,6 +102,59 @@ impl<'b, const WRITE: bool> Account<'b, WRITE> {
     }
 }
 
+/// Wrapper for [Account] that must be rent-exempt for its current data length.
+///
+/// ### Notes
+///
+/// Processing this account loads [Rent] via syscall, so it costs slightly more compute units than
+/// processing a plain [Account].
+///
+/// [Rent]: solana_rent::Rent
+#[derive(Clone, PartialEq, Eq)]
+pub struct RentExemptAccount<'a, const WRITE: bool>(pub(crate) Account<'a, WRITE>);
+
+/// Wrapper for a read-only rent-exempt account.
+pub type ReadonlyRentExemptAccount<'a> = RentExemptAccount<'a, false>;
+
+/// Wrapper for a writable rent-exempt account.
+pub type WritableRentExemptAccount<'a> = RentExemptAccount<'a, true>;
+
+impl<'a, const WRITE: bool> TryFrom<&'a NoStdAccountInfo> for RentExemptAccount<'a, WRITE> {
+    type Error = SealevelToolsError<'static>;
+
+    #[inline(always)]
+    fn try_from(account: &'a NoStdAccountInfo) -> Result<Self, Self::Error> {
+        let account = Account::try_from(account)?;
+        let lamports = *account.try_borrow_lamports()?;
+
+        _check_rent_exempt(&crate::sysvar::get_rent(), lamports, account.data_len())
+            .map(|_| Self(account))
+    }
+}
+
+#[inline(always)]
+fn _check_rent_exempt(
+    rent: &solana_rent::Rent,
+    lamports: u64,
+    data_len: usize,
+) -> Result<(), SealevelToolsError<'static>> {
+    if rent.is_exempt(lamports, data_len) {
+        Ok(())
+    } else {
+        Err(SealevelToolsError::AccountInfo(&[
+            "Expected rent-exempt account",
+        ]))
+    }
+}
+
+impl<'a, const WRITE: bool> Deref for RentExemptAccount<'a, WRITE> {
+    type Target = Account<'a, WRITE>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
 /// Generic wrapper for a program (executable) account.
 #[derive(Clone, PartialEq, Eq)]
 pub struct Program<'a>(pub(crate) &'a NoStdAccountInfo);
@@ -157,10 +226,7 @@ impl<'b, const WRITE: bool> Signer<'b, WRITE> {
     where
         'b: 'a,
     {
-        CpiAuthority {
-            account: self.deref(),
-            signer_seeds: None,
-        }
+        CpiAuthority::from_signer(self.deref())
     }
 }
 
@@ -208,6 +274,85 @@ impl<'a, const DISC_LEN: usize, T: AccountSerde<DISC_LEN>> DataAccount<'a, true,
         let mut info_data = account.try_borrow_mut_data()?;
         data.try_serialize_data(&mut info_data)
     }
+
+    /// Like [Self::try_write_data], but first asserts that the account is owned by the given
+    /// program ID. The runtime rejects writes to accounts not owned by the executing program, but
+    /// only after the instruction finishes with an opaque error. This check fails before writing
+    /// with a custom program error code with [SealevelToolsError::ACCOUNT_INFO].
+    ///
+    /// Use [Self::try_write_data] if ownership has already been validated.
+    #[inline(always)]
+    pub fn try_write_data_checked(&self, program_id: &Pubkey) -> ProgramResult {
+        _assert_owned_by(self.owner(), program_id)?;
+
+        self.try_write_data()
+    }
+
+    /// Either deserialize the existing account's data or, if the account has no data, create it
+    /// with the given default data using [CreateAccount::try_invoke_and_serialize]. This method is
+    /// useful for instructions that are idempotent with respect to account initialization.
+    ///
+    /// ### Notes
+    ///
+    /// If the account already has data, it must be owned by the given program ID and its data must
+    /// deserialize with [AccountSerde] (so its discriminator must match). Otherwise an error is
+    /// returned.
+    ///
+    /// [CreateAccount::try_invoke_and_serialize]: crate::cpi::system_program::CreateAccount::try_invoke_and_serialize
+    #[inline(always)]
+    pub fn try_get_or_init<'b>(
+        account: CpiAuthority<'b, 'a>,
+        payer: CpiAuthority<'b, 'a>,
+        program_id: &'b Pubkey,
+        default: T,
+    ) -> Result<Self, ProgramError>
+    where
+        'a: 'b,
+    {
+        let info = account.account;
+
+        if info.data_len() == 0 {
+            crate::cpi::system_program::CreateAccount {
+                payer,
+                to: account,
+                program_id,
+                space: None,
+                lamports: None,
+            }
+            .try_invoke_and_serialize(&default)?;
+
+            // We know that this account was writable, so we are safe to instantiate it like this.
+            Ok(Self {
+                account: Account(info),
+                data: default,
+            })
+        } else if info.owner() != program_id {
+            Err(SealevelToolsError::AccountInfo(&["Existing account not owned by program"]).into())
+        } else {
+            let account = Account::<true>::try_from(info)?;
+
+            Ok(account.try_into()?)
+        }
+    }
+}
+
+#[inline(always)]
+fn _assert_owned_by(owner: &Pubkey, program_id: &Pubkey) -> ProgramResult {
+    if owner == program_id {
+        return Ok(());
+    }
+
+    #[cfg(feature = "alloc")]
+    return Err(SealevelToolsError::AccountInfo(&[
+        "Cannot write to account not owned by program...",
+        format!("  Found: {}", owner).as_str(),
+        format!("  Expected: {}", program_id).as_str(),
+    ])
+    .into());
+    #[cfg(not(feature = "alloc"))]
+    return Err(
+        SealevelToolsError::AccountInfo(&["Cannot write to account not owned by program"]).into(),
+    );
 }
 
 impl<'a, const WRITE: bool, const DISC_LEN: usize, T: AccountSerde<DISC_LEN>> Deref
@@ -273,6 +418,100 @@ where
     Ok((index, processed))
 }
 
+/// Like [try_prev_enumerated_account_info], but processes the account as a specific type. The
+/// account is taken from the back of the iterator, and its original (forward) index is returned.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account_info::{
+///         try_next_enumerated_account, try_prev_enumerated_account, Payer, SystemProgram,
+///     },
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+/// };
+///
+/// fn process_instruction(accounts: &[NoStdAccountInfo]) -> ProgramResult {
+///     let mut accounts_iter = accounts.iter().enumerate();
+///
+///     // First account must be writable signer (A.K.A. our payer).
+///     let (_, payer) =
+///         try_next_enumerated_account::<Payer>(&mut accounts_iter, Default::default())?;
+///
+///     // Last account must be the System program.
+///     let (_, system_program) =
+///         try_prev_enumerated_account::<SystemProgram>(&mut accounts_iter, Default::default())?;
+///
+///     Ok(())
+/// }
+/// ```
+#[inline(always)]
+pub fn try_prev_enumerated_account<'a, T: TryFrom<&'a NoStdAccountInfo>>(
+    iter: &mut impl DoubleEndedIterator<Item = (usize, &'a NoStdAccountInfo)>,
+    constraints: AccountInfoConstraints,
+) -> Result<(usize, T), ProgramError>
+where
+    ProgramError: From<<T as TryFrom<&'a NoStdAccountInfo>>::Error>,
+{
+    let (index, account) = try_prev_enumerated_account_info(iter, constraints)?;
+    let processed = T::try_from(account)?;
+    Ok((index, processed))
+}
+
+/// Like [try_next_enumerated_account], but also requires the account's key to be strictly greater
+/// than the previous account's key. This method is useful for instructions requiring a sorted list
+/// of accounts (e.g. for deterministic ordering), which also guarantees that there are no duplicate
+/// accounts.
+///
+/// The `prev_key` argument tracks the previous account's key across calls. Start with [None] and
+/// this method will update it with the key of each account taken.
+///
+/// ### Notes
+///
+/// If the account's key is out of order, a custom program error code with
+/// [SealevelToolsError::ACCOUNT_INFO] is returned, as well as a program log indicating the account
+/// index.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account_info::{try_next_enumerated_account_sorted, ReadonlyAccount},
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+/// };
+///
+/// fn process_instruction(accounts: &[NoStdAccountInfo]) -> ProgramResult {
+///     let mut accounts_iter = accounts.iter().enumerate();
+///     let mut prev_key = None;
+///
+///     // All accounts must be sorted by key.
+///     while accounts_iter.len() != 0 {
+///         let (_, leaf) = try_next_enumerated_account_sorted::<ReadonlyAccount>(
+///             &mut accounts_iter,
+///             Default::default(),
+///             &mut prev_key,
+///         )?;
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[inline(always)]
+pub fn try_next_enumerated_account_sorted<'a, T: TryFrom<&'a NoStdAccountInfo>>(
+    iter: &mut impl Iterator<Item = (usize, &'a NoStdAccountInfo)>,
+    constraints: AccountInfoConstraints,
+    prev_key: &mut Option<Pubkey>,
+) -> Result<(usize, T), ProgramError>
+where
+    ProgramError: From<<T as TryFrom<&'a NoStdAccountInfo>>::Error>,
+{
+    let (index, account) = try_next_enumerated_account_info(iter, constraints)?;
+    _check_sorted_key(index, prev_key, account.key())?;
+
+    let processed = T::try_from(account)?;
+    Ok((index, processed))
+}
+
 /// Like [try_next_enumerated_account], but will return [None] if the account's pubkey equals the
 /// `none_pubkey` argument. This method can be useful for instructions where an account is not
 /// required (indicated by an account pubkey already passed into the instruction, usually the
@@ -341,6 +580,54 @@ where
     }
 }
 
+/// Take the next two accounts as [Payer] and [SystemProgram], which is the most common account
+/// prefix for instructions that create accounts. The payer must be immediately followed by the
+/// System program.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account_info::{
+///         try_next_enumerated_account, try_next_payer_and_system_program, WritableAccount,
+///     },
+///     cpi::system_program::CreateAccount,
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+///     pubkey::Pubkey,
+/// };
+///
+/// fn process_instruction(program_id: &Pubkey, accounts: &[NoStdAccountInfo]) -> ProgramResult {
+///     let mut accounts_iter = accounts.iter().enumerate();
+///
+///     let ((_, payer), (_, _system_program)) =
+///         try_next_payer_and_system_program(&mut accounts_iter)?;
+///
+///     let (_, new_account) =
+///         try_next_enumerated_account::<WritableAccount>(&mut accounts_iter, Default::default())?;
+///
+///     CreateAccount {
+///         payer: payer.as_cpi_authority(),
+///         to: new_account.as_cpi_authority(None),
+///         program_id,
+///         space: Some(8),
+///         lamports: None,
+///     }
+///     .try_into_invoke()?;
+///
+///     Ok(())
+/// }
+/// ```
+#[allow(clippy::type_complexity)]
+#[inline(always)]
+pub fn try_next_payer_and_system_program<'a>(
+    iter: &mut impl Iterator<Item = (usize, &'a NoStdAccountInfo)>,
+) -> Result<((usize, Payer<'a>), (usize, SystemProgram<'a>)), ProgramError> {
+    let payer = try_next_enumerated_account(iter, Default::default())?;
+    let system_program = try_next_enumerated_account(iter, Default::default())?;
+
+    Ok((payer, system_program))
+}
+
 /// Trait for composable account structs. This trait is meant to leverage the
 /// [try_next_enumerated_account] and [try_next_enumerated_account_info] functions to process an
 /// enumerated [NoStdAccountInfo] iterator.
@@ -398,3 +685,349 @@ pub trait TakeAccounts<'a>: Sized {
         iter: &mut impl Iterator<Item = (usize, &'a NoStdAccountInfo)>,
     ) -> Result<Self, ProgramError>;
 }
+
+/// Take the next accounts from an enumerated iterator of [NoStdAccountInfo] in order, binding each
+/// account to a variable of the specified type. Each account is taken with
+/// [try_next_enumerated_account] using default constraints unless a constraints expression follows
+/// the type (e.g. `mint: ReadonlyMintAccount = constraints`).
+///
+/// ### Notes
+///
+/// The account indices are discarded. If an account fails to be taken, its error is returned with
+/// `?`, so this macro must be used in a function returning a result compatible with
+/// [ProgramError].
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account_info::{AccountInfoConstraints, Payer, ReadonlyAccount, SystemProgram},
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+///     pubkey::Pubkey,
+///     take_accounts,
+/// };
+///
+/// fn process_instruction(program_id: &Pubkey, accounts: &[NoStdAccountInfo]) -> ProgramResult {
+///     let mut accounts_iter = accounts.iter().enumerate();
+///
+///     take_accounts!(accounts_iter =>
+///         payer: Payer,
+///         config: ReadonlyAccount = AccountInfoConstraints {
+///             owner: Some(program_id),
+///             ..Default::default()
+///         },
+///         system_program: SystemProgram,
+///     );
+///
+///     Ok(())
+/// }
+/// ```
+#[macro_export]
+macro_rules! take_accounts {
+    ($iter:expr => $($name:ident: $account:ty $(= $constraints:expr)?),+ $(,)?) => {
+        $(
+            let (_, $name) = $crate::account_info::try_next_enumerated_account::<$account>(
+                &mut $iter,
+                $crate::__take_accounts_constraints!($($constraints)?),
+            )?;
+        )+
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __take_accounts_constraints {
+    () => {
+        ::core::default::Default::default()
+    };
+    ($constraints:expr) => {
+        $constraints
+    };
+}
+
+#[inline(always)]
+fn _check_sorted_key(index: usize, prev_key: &mut Option<Pubkey>, key: &Pubkey) -> ProgramResult {
+    match prev_key {
+        Some(prev_key) if key <= prev_key => {
+            #[cfg(feature = "alloc")]
+            return Err(SealevelToolsError::AccountInfo(&[
+                format!("Account index {}: Key out of order...", index).as_str(),
+                format!("  Found: {}", key).as_str(),
+                format!("  Expected greater than: {}", prev_key).as_str(),
+            ])
+            .into());
+            #[cfg(not(feature = "alloc"))]
+            {
+                let _ = index;
+                Err(
+                    SealevelToolsError::AccountInfo(&["Account key not greater than previous key"])
+                        .into(),
+                )
+            }
+        }
+        _ => {
+            prev_key.replace(*key);
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_fixture::{TestAccount, TestAccountInfos};
+
+    use super::*;
+
+    #[test]
+    fn test_check_writable() {
+        assert!(_check_writable::<false>(false).is_ok());
+        assert!(_check_writable::<true>(true).is_ok());
+
+        // Writable account rejected as read-only.
+        assert!(matches!(
+            _check_writable::<false>(true),
+            Err(SealevelToolsError::AccountInfo(&[
+                "Cannot process account as read-only"
+            ]))
+        ));
+
+        // Read-only account rejected as writable.
+        assert!(matches!(
+            _check_writable::<true>(false),
+            Err(SealevelToolsError::AccountInfo(&[
+                "Cannot process account as writable"
+            ]))
+        ));
+    }
+
+    #[test]
+    fn test_account_writability() {
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique()).writable(),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique()),
+        ]);
+
+        assert!(Account::<true>::try_from(&infos[0]).is_ok());
+        assert!(matches!(
+            Account::<false>::try_from(&infos[0]),
+            Err(SealevelToolsError::AccountInfo(&[
+                "Cannot process account as read-only"
+            ]))
+        ));
+
+        assert!(Account::<false>::try_from(&infos[1]).is_ok());
+        assert!(matches!(
+            Account::<true>::try_from(&infos[1]),
+            Err(SealevelToolsError::AccountInfo(&[
+                "Cannot process account as writable"
+            ]))
+        ));
+
+        // Same check when taking accounts from an iterator.
+        let mut iter = infos.iter().enumerate();
+        assert_eq!(
+            try_next_enumerated_account::<ReadonlyAccount>(&mut iter, Default::default())
+                .map(|(index, _)| index)
+                .err(),
+            Some(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+        assert_eq!(
+            try_next_enumerated_account::<ReadonlyAccount>(&mut iter, Default::default())
+                .map(|(index, _)| index),
+            Ok(1)
+        );
+    }
+
+    #[test]
+    fn test_check_rent_exempt() {
+        let rent = solana_rent::Rent::default();
+        let minimum_balance = rent.minimum_balance(165);
+
+        assert!(_check_rent_exempt(&rent, minimum_balance, 165).is_ok());
+        assert!(_check_rent_exempt(&rent, minimum_balance + 1, 165).is_ok());
+        assert!(_check_rent_exempt(&rent, rent.minimum_balance(0), 0).is_ok());
+
+        // One lamport short of the minimum balance.
+        assert!(matches!(
+            _check_rent_exempt(&rent, minimum_balance - 1, 165),
+            Err(SealevelToolsError::AccountInfo(&[
+                "Expected rent-exempt account"
+            ]))
+        ));
+        assert!(matches!(
+            _check_rent_exempt(&rent, 0, 0),
+            Err(SealevelToolsError::AccountInfo(&[
+                "Expected rent-exempt account"
+            ]))
+        ));
+    }
+
+    #[test]
+    fn test_rent_exempt_account_writability() {
+        let infos = TestAccountInfos::new(&[TestAccount::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        )
+        .lamports(u64::MAX)
+        .writable()]);
+
+        // Writability is checked before rent is loaded.
+        assert!(matches!(
+            ReadonlyRentExemptAccount::try_from(&infos[0]),
+            Err(SealevelToolsError::AccountInfo(&[
+                "Cannot process account as read-only"
+            ]))
+        ));
+    }
+
+    #[test]
+    fn test_assert_owned_by() {
+        let program_id = Pubkey::new_unique();
+
+        assert_eq!(_assert_owned_by(&program_id, &program_id), Ok(()));
+        assert_eq!(
+            _assert_owned_by(&Pubkey::new_unique(), &program_id),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+    }
+
+    #[test]
+    fn test_check_sorted_key() {
+        let mut keys = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        keys.sort();
+
+        let mut prev_key = None;
+        for (index, key) in keys.iter().enumerate() {
+            assert_eq!(_check_sorted_key(index, &mut prev_key, key), Ok(()));
+            assert_eq!(prev_key, Some(*key));
+        }
+
+        // Duplicate key.
+        assert_eq!(
+            _check_sorted_key(3, &mut prev_key, &keys[2]),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+
+        // Smaller key. Previous key is unchanged.
+        assert_eq!(
+            _check_sorted_key(3, &mut prev_key, &keys[0]),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+        assert_eq!(prev_key, Some(keys[2]));
+    }
+
+    #[test]
+    fn test_try_next_payer_and_system_program() {
+        let system_program_id = crate::account::system::ID;
+        let native_loader_id = Pubkey::new_unique();
+
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(Pubkey::new_unique(), system_program_id)
+                .signer()
+                .writable(),
+            TestAccount::new(system_program_id, native_loader_id).executable(),
+        ]);
+        let take = |infos: &TestAccountInfos| {
+            try_next_payer_and_system_program(&mut infos.iter().enumerate()).map(
+                |((payer_index, _), (system_program_index, _))| (payer_index, system_program_index),
+            )
+        };
+        assert_eq!(take(&infos), Ok((0, 1)));
+
+        // Payer is not a writable signer.
+        for payer in [
+            TestAccount::new(Pubkey::new_unique(), system_program_id).signer(),
+            TestAccount::new(Pubkey::new_unique(), system_program_id).writable(),
+        ] {
+            let infos = TestAccountInfos::new(&[
+                payer,
+                TestAccount::new(system_program_id, native_loader_id).executable(),
+            ]);
+            assert_eq!(
+                take(&infos),
+                Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+            );
+        }
+
+        // Wrong System program key.
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(Pubkey::new_unique(), system_program_id)
+                .signer()
+                .writable(),
+            TestAccount::new(Pubkey::new_unique(), native_loader_id).executable(),
+        ]);
+        assert_eq!(
+            take(&infos),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+
+        // Missing System program.
+        let infos =
+            TestAccountInfos::new(&[TestAccount::new(Pubkey::new_unique(), system_program_id)
+                .signer()
+                .writable()]);
+        assert_eq!(take(&infos), Err(ProgramError::NotEnoughAccountKeys));
+    }
+
+    #[test]
+    fn test_take_accounts() {
+        use crate::account_info::SystemProgram;
+
+        let program_id = Pubkey::new_unique();
+        let system_program_id = crate::account::system::ID;
+        let native_loader_id = Pubkey::new_unique();
+
+        let take = |accounts: &[NoStdAccountInfo]| -> Result<[Pubkey; 3], ProgramError> {
+            let mut accounts_iter = accounts.iter().enumerate();
+
+            take_accounts!(accounts_iter =>
+                payer: Payer,
+                config: ReadonlyAccount = AccountInfoConstraints {
+                    owner: Some(&program_id),
+                    ..Default::default()
+                },
+                system_program: SystemProgram,
+            );
+
+            Ok([*payer.key(), *config.key(), *system_program.key()])
+        };
+
+        let payer_key = Pubkey::new_unique();
+        let config_key = Pubkey::new_unique();
+
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(payer_key, system_program_id)
+                .signer()
+                .writable(),
+            TestAccount::new(config_key, program_id),
+            TestAccount::new(system_program_id, native_loader_id).executable(),
+        ]);
+        assert_eq!(take(&infos), Ok([payer_key, config_key, system_program_id]));
+
+        // Config account owned by another program.
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(payer_key, system_program_id)
+                .signer()
+                .writable(),
+            TestAccount::new(config_key, Pubkey::new_unique()),
+            TestAccount::new(system_program_id, native_loader_id).executable(),
+        ]);
+        assert_eq!(
+            take(&infos),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+
+        // Missing System program.
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(payer_key, system_program_id)
+                .signer()
+                .writable(),
+            TestAccount::new(config_key, program_id),
+        ]);
+        assert_eq!(take(&infos), Err(ProgramError::NotEnoughAccountKeys));
+    }
+}
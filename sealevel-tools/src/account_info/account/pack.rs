@@ -1,4 +1,4 @@
-use crate::account::PackAccountSchema;
+use crate::account::{DiscriminatedPackSchema, PackAccountSchema};
 
 use super::DataAccount;
 
@@ -10,3 +10,15 @@ pub type ReadonlyPackAccount<'a, T> = PackAccount<'a, false, T>;
 
 /// Writable account whose data is serialized as [PackAccountSchema].
 pub type WritablePackAccount<'a, T> = PackAccount<'a, true, T>;
+
+/// Account whose data is serialized as [DiscriminatedPackSchema].
+pub type DiscriminatedPackAccount<'a, const WRITE: bool, const DISC_LEN: usize, T> =
+    DataAccount<'a, WRITE, DISC_LEN, DiscriminatedPackSchema<DISC_LEN, T>>;
+
+/// Read-only account whose data is serialized as [DiscriminatedPackSchema].
+pub type ReadonlyDiscriminatedPackAccount<'a, const DISC_LEN: usize, T> =
+    DiscriminatedPackAccount<'a, false, DISC_LEN, T>;
+
+/// Writable account whose data is serialized as [DiscriminatedPackSchema].
+pub type WritableDiscriminatedPackAccount<'a, const DISC_LEN: usize, T> =
+    DiscriminatedPackAccount<'a, true, DISC_LEN, T>;
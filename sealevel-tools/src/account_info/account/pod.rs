@@ -0,0 +1,200 @@
+use core::mem::size_of;
+
+use bytemuck::{Pod, PodCastError};
+
+use crate::{error::SealevelToolsError, program_error::ProgramError};
+
+use super::Account;
+
+impl<'a, const WRITE: bool> Account<'a, WRITE> {
+    /// Borrow the account data after skipping `offset` bytes and pass it to the closure as a slice
+    /// of [Pod] elements without deserializing (zero-copy). This method is useful for accounts
+    /// holding arrays (e.g. a list of pubkeys).
+    ///
+    /// ### Notes
+    ///
+    /// A closure is used because the slice cannot outlive the data borrow.
+    ///
+    /// If `offset` is out of bounds, [ProgramError::AccountDataTooSmall] is returned. If the
+    /// remaining data is not a multiple of the element size or is not aligned for the element
+    /// type, a custom program error code with [SealevelToolsError::ACCOUNT_INFO] is returned.
+    /// Account data starts at an 8-byte aligned address, so `offset` should be a multiple of the
+    /// element type's alignment.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use sealevel_tools::{
+    ///     account_info::{try_next_enumerated_account, ReadonlyAccount},
+    ///     entrypoint::{NoStdAccountInfo, ProgramResult},
+    ///     program_error::ProgramError,
+    ///     pubkey::Pubkey,
+    /// };
+    ///
+    /// // Allowlist of pubkeys is stored after its 8-byte discriminator.
+    /// const ALLOWLIST_OFFSET: usize = 8;
+    ///
+    /// fn check_allowlisted(accounts: &[NoStdAccountInfo], user: &Pubkey) -> ProgramResult {
+    ///     let mut accounts_iter = accounts.iter().enumerate();
+    ///
+    ///     let (_, allowlist) =
+    ///         try_next_enumerated_account::<ReadonlyAccount>(&mut accounts_iter, Default::default())?;
+    ///
+    ///     let is_allowlisted = allowlist
+    ///         .try_with_pod_slice(ALLOWLIST_OFFSET, |keys: &[[u8; 32]]| {
+    ///             keys.contains(&user.to_bytes())
+    ///         })?;
+    ///
+    ///     if is_allowlisted {
+    ///         Ok(())
+    ///     } else {
+    ///         Err(ProgramError::InvalidArgument)
+    ///     }
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn try_with_pod_slice<T: Pod, R>(
+        &self,
+        offset: usize,
+        f: impl FnOnce(&[T]) -> R,
+    ) -> Result<R, ProgramError> {
+        let data = self.try_borrow_data()?;
+        _try_cast_pod_slice(&data, offset).map(f)
+    }
+
+    /// Copy `size_of::<T>()` bytes of the account data starting at `offset` and read them as a
+    /// [Pod] value. This method is useful for peeking at a fixed header (e.g. a version or account
+    /// type) of an account with a variable-length tail without deserializing the whole account.
+    ///
+    /// ### Notes
+    ///
+    /// Because the bytes are copied, the value does not borrow the account data and there are no
+    /// alignment requirements for `offset`.
+    ///
+    /// If the value does not fit in the data at `offset`, [ProgramError::AccountDataTooSmall] is
+    /// returned.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use sealevel_tools::{account_info::ReadonlyAccount, program_error::ProgramError};
+    ///
+    /// // Header (version, account type) is stored after its 8-byte discriminator.
+    /// const HEADER_OFFSET: usize = 8;
+    ///
+    /// fn read_version(account: &ReadonlyAccount) -> Result<u8, ProgramError> {
+    ///     let [version, _account_type] = account.try_read_header::<[u8; 2]>(HEADER_OFFSET)?;
+    ///
+    ///     Ok(version)
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn try_read_header<T: Pod>(&self, offset: usize) -> Result<T, ProgramError> {
+        let data = self.try_borrow_data()?;
+        _try_read_pod(&data, offset)
+    }
+}
+
+impl<'a> Account<'a, true> {
+    /// Like [Account::try_with_pod_slice], but borrows the account data mutably so the elements can
+    /// be modified in place.
+    #[inline(always)]
+    pub fn try_with_pod_slice_mut<T: Pod, R>(
+        &self,
+        offset: usize,
+        f: impl FnOnce(&mut [T]) -> R,
+    ) -> Result<R, ProgramError> {
+        let mut data = self.try_borrow_mut_data()?;
+        _try_cast_pod_slice_mut(&mut data, offset).map(f)
+    }
+}
+
+#[inline(always)]
+fn _try_cast_pod_slice<T: Pod>(data: &[u8], offset: usize) -> Result<&[T], ProgramError> {
+    data.get(offset..)
+        .ok_or(ProgramError::AccountDataTooSmall)
+        .and_then(|data| bytemuck::try_cast_slice(data).map_err(_pod_cast_error))
+}
+
+#[inline(always)]
+fn _try_cast_pod_slice_mut<T: Pod>(
+    data: &mut [u8],
+    offset: usize,
+) -> Result<&mut [T], ProgramError> {
+    data.get_mut(offset..)
+        .ok_or(ProgramError::AccountDataTooSmall)
+        .and_then(|data| bytemuck::try_cast_slice_mut(data).map_err(_pod_cast_error))
+}
+
+#[inline(always)]
+fn _try_read_pod<T: Pod>(data: &[u8], offset: usize) -> Result<T, ProgramError> {
+    offset
+        .checked_add(size_of::<T>())
+        .and_then(|end| data.get(offset..end))
+        .map(bytemuck::pod_read_unaligned::<T>)
+        .ok_or(ProgramError::AccountDataTooSmall)
+}
+
+#[inline(always)]
+fn _pod_cast_error(err: PodCastError) -> ProgramError {
+    match err {
+        PodCastError::OutputSliceWouldHaveSlop | PodCastError::SizeMismatch => {
+            SealevelToolsError::AccountInfo(&["Account data is not a multiple of element size"])
+                .into()
+        }
+        _ => SealevelToolsError::AccountInfo(&["Account data is misaligned for element type"])
+            .into(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_try_cast_pod_slice() {
+        let mut elements = [1_u64, 2, 3, 4];
+        let data = bytemuck::cast_slice_mut::<_, u8>(&mut elements);
+
+        assert_eq!(_try_cast_pod_slice::<u64>(data, 0), Ok([1, 2, 3, 4].as_slice()));
+        assert_eq!(_try_cast_pod_slice::<u64>(data, 8), Ok([2, 3, 4].as_slice()));
+        assert_eq!(_try_cast_pod_slice::<u64>(data, 32), Ok([].as_slice()));
+        assert_eq!(
+            _try_cast_pod_slice::<u64>(data, 33),
+            Err(ProgramError::AccountDataTooSmall)
+        );
+        assert_eq!(
+            _try_cast_pod_slice::<u64>(&data[..31], 0),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+        assert_eq!(
+            _try_cast_pod_slice::<u64>(&data[..25], 1),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+
+        _try_cast_pod_slice_mut::<u64>(data, 8).unwrap()[0] = 69;
+        assert_eq!(elements, [1, 69, 3, 4]);
+    }
+
+    #[test]
+    fn test_try_read_pod() {
+        let mut data = [0; 13];
+        data[1..9].copy_from_slice(&69_u64.to_le_bytes());
+        data[9..].copy_from_slice(&420_u32.to_le_bytes());
+
+        // Unaligned offsets are fine.
+        assert_eq!(_try_read_pod::<u64>(&data, 1), Ok(69));
+        assert_eq!(_try_read_pod::<u32>(&data, 9), Ok(420));
+        assert_eq!(_try_read_pod::<[u8; 0]>(&data, 13), Ok([]));
+
+        // Out of bounds.
+        assert_eq!(
+            _try_read_pod::<u64>(&data, 6),
+            Err(ProgramError::AccountDataTooSmall)
+        );
+        assert_eq!(
+            _try_read_pod::<u8>(&data, usize::MAX),
+            Err(ProgramError::AccountDataTooSmall)
+        );
+    }
+}
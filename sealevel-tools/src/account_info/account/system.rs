@@ -4,7 +4,27 @@ use crate::{account::system::ID, entrypoint::NoStdAccountInfo, error::SealevelTo
 
 use super::{Account, Program};
 
-/// Wrapper for [Program] for the System program.
+/// Wrapper for [Program] for the System program. The account's key must equal the System program
+/// ID, and the account must be executable and not a signer.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account_info::{try_next_enumerated_account, SystemProgram},
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+/// };
+///
+/// fn process_instruction(accounts: &[NoStdAccountInfo]) -> ProgramResult {
+///     let mut accounts_iter = accounts.iter().enumerate();
+///
+///     // First account must be the System program, which is required for create account CPI.
+///     let (_, system_program) =
+///         try_next_enumerated_account::<SystemProgram>(&mut accounts_iter, Default::default())?;
+///
+///     Ok(())
+/// }
+/// ```
 #[derive(Clone, PartialEq, Eq)]
 pub struct SystemProgram<'a>(pub(crate) Program<'a>);
 
@@ -63,3 +83,90 @@ impl<'a, const WRITE: bool> Deref for SystemAccount<'a, WRITE> {
         &self.0
     }
 }
+
+/// Writable account owned by the System program with no data, which is expected for accounts that
+/// will be created (e.g. via [CreateAccount]).
+///
+/// ### Notes
+///
+/// Lamports are not checked. An account that was pre-funded with lamports but has no data still
+/// qualifies because [CreateAccount] tops up its lamports before allocating and assigning it.
+///
+/// [CreateAccount]: crate::cpi::system_program::CreateAccount
+#[derive(Clone, PartialEq, Eq)]
+pub struct UninitializedAccount<'a>(pub(crate) Account<'a, true>);
+
+impl<'a> TryFrom<&'a NoStdAccountInfo> for UninitializedAccount<'a> {
+    type Error = SealevelToolsError<'static>;
+
+    #[inline(always)]
+    fn try_from(account: &'a NoStdAccountInfo) -> Result<Self, Self::Error> {
+        if account.owner() != &ID {
+            Err(SealevelToolsError::AccountInfo(&[
+                "Expected System program account",
+            ]))
+        } else if account.data_len() != 0 {
+            Err(SealevelToolsError::AccountInfo(&[
+                "Expected uninitialized account",
+            ]))
+        } else {
+            Account::try_from(account).map(Self)
+        }
+    }
+}
+
+impl<'a> Deref for UninitializedAccount<'a> {
+    type Target = Account<'a, true>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        pubkey::Pubkey,
+        test_fixture::{TestAccount, TestAccountInfos},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_uninitialized_account() {
+        let infos = TestAccountInfos::new(&[
+            // Pre-funded account with no data.
+            TestAccount::new(Pubkey::new_unique(), ID)
+                .lamports(1_000)
+                .writable(),
+            // Account with data.
+            TestAccount::new(Pubkey::new_unique(), ID)
+                .data(&[0; 8])
+                .writable(),
+            // Account owned by another program.
+            TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique()).writable(),
+            // Read-only account.
+            TestAccount::new(Pubkey::new_unique(), ID),
+        ]);
+
+        assert!(UninitializedAccount::try_from(&infos[0]).is_ok());
+        assert!(matches!(
+            UninitializedAccount::try_from(&infos[1]),
+            Err(SealevelToolsError::AccountInfo(&[
+                "Expected uninitialized account"
+            ]))
+        ));
+        assert!(matches!(
+            UninitializedAccount::try_from(&infos[2]),
+            Err(SealevelToolsError::AccountInfo(&[
+                "Expected System program account"
+            ]))
+        ));
+        assert!(matches!(
+            UninitializedAccount::try_from(&infos[3]),
+            Err(SealevelToolsError::AccountInfo(&[
+                "Cannot process account as writable"
+            ]))
+        ));
+    }
+}
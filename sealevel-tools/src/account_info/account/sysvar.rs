@@ -0,0 +1,113 @@
+use core::ops::Deref;
+
+use solana_rent::Rent;
+
+use crate::{
+    entrypoint::NoStdAccountInfo, error::SealevelToolsError, program_error::ProgramError,
+    sysvar::rent::ID,
+};
+
+use super::ReadonlyAccount;
+
+const RENT_DATA_LEN: usize = {
+    8 // lamports_per_byte_year
+    + 8 // exemption_threshold
+    + 1 // burn_percent
+};
+
+/// Wrapper for the Rent sysvar account, whose data is deserialized as [Rent]. Loading [Rent] via
+/// syscall (see [get_rent]) is preferred, but some instructions (like the Token program's legacy
+/// initialize instructions) require the Rent sysvar account to be passed in. The account's key must
+/// equal the Rent sysvar ID.
+///
+/// [get_rent]: crate::sysvar::get_rent
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account_info::{try_next_enumerated_account, RentSysvar},
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+/// };
+///
+/// fn process_instruction(accounts: &[NoStdAccountInfo]) -> ProgramResult {
+///     let mut accounts_iter = accounts.iter().enumerate();
+///
+///     let (_, rent_sysvar) =
+///         try_next_enumerated_account::<RentSysvar>(&mut accounts_iter, Default::default())?;
+///     let minimum_balance = rent_sysvar.minimum_balance(165);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct RentSysvar<'a> {
+    pub(crate) account: ReadonlyAccount<'a>,
+    pub data: Rent,
+}
+
+impl<'a> RentSysvar<'a> {
+    /// Calculate minimum balance due for rent-exemption of a given account data size.
+    #[inline(always)]
+    pub fn minimum_balance(&self, size: usize) -> u64 {
+        self.data.minimum_balance(size)
+    }
+}
+
+impl<'a> TryFrom<&'a NoStdAccountInfo> for RentSysvar<'a> {
+    type Error = SealevelToolsError<'static>;
+
+    #[inline(always)]
+    fn try_from(account: &'a NoStdAccountInfo) -> Result<Self, Self::Error> {
+        if account.key() != &ID {
+            return Err(SealevelToolsError::AccountInfo(&["Expected Rent sysvar"]));
+        }
+
+        let account = ReadonlyAccount::try_from(account)?;
+        let data = _try_deserialize_rent(&account.try_borrow_data()?)?;
+
+        Ok(Self { account, data })
+    }
+}
+
+impl<'a> Deref for RentSysvar<'a> {
+    type Target = ReadonlyAccount<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.account
+    }
+}
+
+#[inline(always)]
+fn _try_deserialize_rent(data: &[u8]) -> Result<Rent, ProgramError> {
+    match data.first_chunk::<RENT_DATA_LEN>() {
+        Some(data) => Ok(Rent {
+            lamports_per_byte_year: u64::from_le_bytes(data[..8].try_into().unwrap()),
+            exemption_threshold: f64::from_le_bytes(data[8..16].try_into().unwrap()),
+            burn_percent: data[16],
+        }),
+        None => Err(ProgramError::InvalidAccountData),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_try_deserialize_rent() {
+        let rent = Rent {
+            lamports_per_byte_year: 420,
+            exemption_threshold: 1.5,
+            burn_percent: 69,
+        };
+        let data = bincode::serialize(&rent).unwrap();
+        assert_eq!(data.len(), RENT_DATA_LEN);
+
+        assert_eq!(_try_deserialize_rent(&data), Ok(rent));
+        assert_eq!(
+            _try_deserialize_rent(&data[..(RENT_DATA_LEN - 1)]),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+}
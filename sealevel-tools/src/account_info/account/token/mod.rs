@@ -1,19 +1,26 @@
+#[cfg(feature = "alloc")]
+use alloc::format;
 use core::ops::Deref;
 
 use solana_program_pack::{IsInitialized, Pack};
 
 use crate::{
-    account::{legacy_token, token_extensions, StateWithExtensionsBaseSchema},
-    entrypoint::NoStdAccountInfo,
+    account::{ata, legacy_token, token_extensions, StateWithExtensionsBaseSchema},
+    entrypoint::{NoStdAccountInfo, ProgramResult},
     error::SealevelToolsError,
+    program_error::ProgramError,
     pubkey::Pubkey,
     spl_token_2022::{
-        extension::BaseState,
-        state::{Account as BaseTokenAccountState, Mint as BaseMintState},
+        extension::{BaseState, BaseStateWithExtensions, ExtensionType, PodStateWithExtensions},
+        pod::PodMint,
+        state::{Account as BaseTokenAccountState, AccountState, Mint as BaseMintState},
     },
 };
 
-use super::{Account, DataAccount, PackAccount, Program};
+use super::{
+    try_next_enumerated_account, Account, AccountInfoConstraints, DataAccount, PackAccount,
+    Program, Signer,
+};
 
 pub const TOKEN_PROGRAM_IDS: [&Pubkey; 2] = [&legacy_token::ID, &token_extensions::ID];
 
@@ -23,6 +30,238 @@ pub fn is_any_token_program_id(program_id: &Pubkey) -> bool {
     TOKEN_PROGRAM_IDS.iter().any(|&id| id == program_id)
 }
 
+/// Token program variant, which is useful for deciding whether extension-specific logic applies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenVariant {
+    /// SPL Token program.
+    Legacy,
+
+    /// SPL Token Extensions program.
+    Extensions,
+}
+
+impl TokenVariant {
+    /// Determine the variant from a program ID. Returns [None] if the program ID is neither SPL
+    /// Token nor SPL Token Extensions program ID.
+    #[inline(always)]
+    pub fn from_program_id(program_id: &Pubkey) -> Option<Self> {
+        if program_id == &legacy_token::ID {
+            Some(Self::Legacy)
+        } else if program_id == &token_extensions::ID {
+            Some(Self::Extensions)
+        } else {
+            None
+        }
+    }
+
+    /// Program ID of this variant.
+    #[inline(always)]
+    pub fn program_id(&self) -> &'static Pubkey {
+        match self {
+            Self::Legacy => &legacy_token::ID,
+            Self::Extensions => &token_extensions::ID,
+        }
+    }
+}
+
+/// Detect which Token program owns the account (e.g. a mint or token account). If the account is
+/// owned by neither SPL Token nor SPL Token Extensions program, a custom program error code with
+/// [SealevelToolsError::ACCOUNT_INFO] is returned.
+#[inline(always)]
+pub fn detect_program(account: &NoStdAccountInfo) -> Result<TokenVariant, ProgramError> {
+    TokenVariant::from_program_id(account.owner()).ok_or_else(|| {
+        SealevelToolsError::AccountInfo(&[
+            "Expected legacy SPL Token or Token Extensions program account",
+        ])
+        .into()
+    })
+}
+
+/// Assert that the account is a mint for either SPL Token or SPL Token Extensions program. The
+/// account must be owned by one of these programs and its base state must unpack as an initialized
+/// mint. Only the base state is read, so this check works for mints with or without extensions.
+///
+/// This check prevents passing in a token account where a mint is expected. If it fails, a custom
+/// program error code with [SealevelToolsError::ACCOUNT_INFO] is returned.
+#[inline(always)]
+pub fn try_assert_mint(account: &NoStdAccountInfo) -> Result<(), ProgramError> {
+    if !is_any_token_program_id(account.owner()) {
+        return Err(SealevelToolsError::AccountInfo(&[
+            "Expected legacy SPL Token or Token Extensions program account",
+        ])
+        .into());
+    }
+
+    if _is_initialized_mint_data(&account.try_borrow_data()?) {
+        Ok(())
+    } else {
+        Err(SealevelToolsError::AccountInfo(&["Expected initialized mint"]).into())
+    }
+}
+
+/// Get the extension types present in a mint's TLV data (for either SPL Token or SPL Token
+/// Extensions program). A legacy SPL Token mint has no extensions, so its list is empty.
+///
+/// ### Notes
+///
+/// If the account is not owned by either Token program, a custom program error code with
+/// [SealevelToolsError::ACCOUNT_INFO] is returned. If the account data does not unpack as an
+/// initialized mint, the error from unpacking is returned. To check for a specific extension
+/// without allocating, use [mint_has_extension].
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn get_mint_extension_types(
+    account: &NoStdAccountInfo,
+) -> Result<alloc::vec::Vec<ExtensionType>, ProgramError> {
+    detect_program(account)?;
+
+    let data = account.try_borrow_data()?;
+    PodStateWithExtensions::<PodMint>::unpack(&data)?.get_extension_types()
+}
+
+/// Determine whether a mint (for either SPL Token or SPL Token Extensions program) has the given
+/// extension by scanning its TLV data. A legacy SPL Token mint has no extensions.
+///
+/// ### Notes
+///
+/// If the account is not owned by either Token program, a custom program error code with
+/// [SealevelToolsError::ACCOUNT_INFO] is returned. If the account data does not unpack as an
+/// initialized mint, the error from unpacking is returned. If its TLV data is malformed,
+/// [ProgramError::InvalidAccountData] is returned.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account_info::mint_has_extension,
+///     cpi::token_program::ExtensionType,
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+///     program_error::ProgramError,
+/// };
+///
+/// fn check_mint(mint: &NoStdAccountInfo) -> ProgramResult {
+///     if mint_has_extension(mint, ExtensionType::TransferFeeConfig)? {
+///         return Err(ProgramError::InvalidAccountData);
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[inline(always)]
+pub fn mint_has_extension(
+    account: &NoStdAccountInfo,
+    extension_type: ExtensionType,
+) -> Result<bool, ProgramError> {
+    detect_program(account)?;
+
+    let data = account.try_borrow_data()?;
+    let state = PodStateWithExtensions::<PodMint>::unpack(&data)?;
+
+    _find_extension_type(state.get_tlv_data(), |found| found == extension_type)
+        .map(|found| found.is_some())
+}
+
+/// Assert that a mint (for either SPL Token or SPL Token Extensions program) has none of the
+/// forbidden extensions. This check is useful for protocols that cannot safely handle certain
+/// tokens (e.g. mints with transfer fees or transfer hooks). The mint's TLV data is scanned
+/// directly, so no extension types are collected.
+///
+/// If a forbidden extension is present, a custom program error code with
+/// [SealevelToolsError::ACCOUNT_INFO] is returned, as well as a program log indicating the
+/// extension found.
+///
+/// ### Notes
+///
+/// If the account is not owned by either Token program, a custom program error code with
+/// [SealevelToolsError::ACCOUNT_INFO] is returned. If the account data does not unpack as an
+/// initialized mint, the error from unpacking is returned. If its TLV data is malformed,
+/// [ProgramError::InvalidAccountData] is returned.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account_info::assert_mint_lacks_extensions,
+///     cpi::token_program::ExtensionType,
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+/// };
+///
+/// const FORBIDDEN_EXTENSIONS: [ExtensionType; 2] =
+///     [ExtensionType::TransferFeeConfig, ExtensionType::TransferHook];
+///
+/// fn check_mint(mint: &NoStdAccountInfo) -> ProgramResult {
+///     assert_mint_lacks_extensions(mint, &FORBIDDEN_EXTENSIONS)
+/// }
+/// ```
+#[inline(always)]
+pub fn assert_mint_lacks_extensions(
+    account: &NoStdAccountInfo,
+    forbidden: &[ExtensionType],
+) -> ProgramResult {
+    detect_program(account)?;
+
+    let data = account.try_borrow_data()?;
+    let state = PodStateWithExtensions::<PodMint>::unpack(&data)?;
+
+    match _find_extension_type(state.get_tlv_data(), |found| forbidden.contains(&found))? {
+        None => Ok(()),
+        Some(extension_type) => {
+            #[cfg(feature = "alloc")]
+            return Err(SealevelToolsError::AccountInfo(&[
+                "Mint has forbidden extension...",
+                format!("  Found: {:?}", extension_type).as_str(),
+            ])
+            .into());
+            #[cfg(not(feature = "alloc"))]
+            {
+                let _ = extension_type;
+                Err(SealevelToolsError::AccountInfo(&["Mint has forbidden extension"]).into())
+            }
+        }
+    }
+}
+
+/// Scan TLV data for the first extension type matching the predicate. Each TLV entry is serialized
+/// as its extension type (u16), the length of its value (u16) and the value itself. An
+/// uninitialized extension type (or not enough bytes left for a type) marks the end of the entries.
+#[inline(always)]
+fn _find_extension_type(
+    tlv_data: &[u8],
+    mut predicate: impl FnMut(ExtensionType) -> bool,
+) -> Result<Option<ExtensionType>, ProgramError> {
+    let mut offset = 0;
+
+    while let Some(type_bytes) = tlv_data.get(offset..(offset + 2)) {
+        let extension_type = ExtensionType::try_from(type_bytes)?;
+
+        if extension_type == ExtensionType::Uninitialized {
+            break;
+        }
+
+        if predicate(extension_type) {
+            return Ok(Some(extension_type));
+        }
+
+        let length = tlv_data
+            .get((offset + 2)..(offset + 4))
+            .map(|length| u16::from_le_bytes([length[0], length[1]]))
+            .ok_or(ProgramError::InvalidAccountData)?;
+
+        offset += 4 + usize::from(length);
+
+        if offset > tlv_data.len() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+    }
+
+    Ok(None)
+}
+
+#[inline(always)]
+fn _is_initialized_mint_data(data: &[u8]) -> bool {
+    PodStateWithExtensions::<PodMint>::unpack(data).is_ok_and(|state| state.base.is_initialized())
+}
+
 type StateWithExtensionsBaseAccount<'a, const WRITE: bool, T> =
     DataAccount<'a, WRITE, 0, StateWithExtensionsBaseSchema<T>>;
 
@@ -53,6 +292,49 @@ impl<'a> Deref for TokenProgram<'a> {
     }
 }
 
+/// Wrapper for [Program] for the Associated Token Account program.
+///
+/// ### Notes
+///
+/// [TryFrom] checks against the canonical ATA program ID. If your program interacts with another
+/// program that uses the same ATA program interface, use [Self::try_from_program_id].
+#[derive(Clone, PartialEq, Eq)]
+pub struct AtaProgram<'a>(pub(crate) Program<'a>);
+
+impl<'a> AtaProgram<'a> {
+    /// Check that the account is an executable program with the specified ATA program ID.
+    #[inline(always)]
+    pub fn try_from_program_id(
+        account: &'a NoStdAccountInfo,
+        ata_program_id: &Pubkey,
+    ) -> Result<Self, SealevelToolsError<'static>> {
+        if account.key() == ata_program_id {
+            Program::try_from(account).map(Self)
+        } else {
+            Err(SealevelToolsError::AccountInfo(&[
+                "Expected Associated Token Account program",
+            ]))
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a NoStdAccountInfo> for AtaProgram<'a> {
+    type Error = SealevelToolsError<'static>;
+
+    #[inline(always)]
+    fn try_from(account: &'a NoStdAccountInfo) -> Result<Self, Self::Error> {
+        Self::try_from_program_id(account, &ata::ID)
+    }
+}
+
+impl<'a> Deref for AtaProgram<'a> {
+    type Target = Program<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
 /// Account must be owned by either the SPL Token or SPL Token Extensions program.
 #[derive(Clone, PartialEq, Eq)]
 pub struct TokenProgramAccount<'a, const WRITE: bool>(pub(crate) Account<'a, WRITE>);
@@ -217,6 +499,44 @@ impl<'a, const WRITE: bool, T: BaseState + Pack> Deref for TokenProgramDataAccou
     }
 }
 
+impl<'a, const WRITE: bool> TokenAccount<'a, WRITE> {
+    /// Whether the token account is frozen (based on its deserialized base state).
+    #[inline(always)]
+    pub fn is_frozen(&self) -> bool {
+        self.data.state == AccountState::Frozen
+    }
+
+    /// Delegate approved to transfer or burn tokens from this token account, if any (based on its
+    /// deserialized base state).
+    #[inline(always)]
+    pub fn delegate(&self) -> Option<Pubkey> {
+        Option::from(self.data.delegate)
+    }
+
+    /// Amount of tokens the delegate is approved to transfer or burn (based on its
+    /// deserialized base state). This amount is zero if there is no delegate.
+    #[inline(always)]
+    pub fn delegated_amount(&self) -> u64 {
+        self.data.delegated_amount
+    }
+
+    /// Assert that the token account is not frozen. The Token program enforces this when moving
+    /// tokens, but checking beforehand gives a clearer error and avoids a failed CPI call.
+    #[inline(always)]
+    pub fn assert_not_frozen(&self) -> ProgramResult {
+        _assert_not_frozen(self.is_frozen())
+    }
+}
+
+impl<'a, const WRITE: bool> MintAccount<'a, WRITE> {
+    /// Assert that no tokens have been minted (based on its deserialized base state). This check is
+    /// useful for lifecycle operations requiring an untouched mint (e.g. before closing it).
+    #[inline(always)]
+    pub fn assert_supply_zero(&self) -> ProgramResult {
+        _assert_supply_zero(self.data.supply)
+    }
+}
+
 /// Wrapper for [Program] for the SPL Token program.
 #[derive(Clone, PartialEq, Eq)]
 pub struct LegacyTokenProgram<'a>(pub(crate) Program<'a>);
@@ -341,6 +661,56 @@ impl<'a, const WRITE: bool, T: Pack + IsInitialized> Deref
     }
 }
 
+impl<'a, const WRITE: bool, T: Pack + IsInitialized> LegacyTokenProgramDataAccount<'a, WRITE, T> {
+    /// Borrow the account data, unpack it with [Pack] and pass a reference of the unpacked state to
+    /// the closure. The data borrow is released before the closure is called, so the closure is
+    /// free to borrow this account's data again.
+    ///
+    /// ### Notes
+    ///
+    /// The deserialized data found in this wrapper reflects the account data at the time this
+    /// wrapper was created. This method is useful to read the latest state (e.g. after a CPI call to
+    /// the Token program that modified this account).
+    #[inline(always)]
+    pub fn with_unpacked<R>(&self, f: impl FnOnce(&T) -> R) -> Result<R, ProgramError> {
+        let state = {
+            let data = self.try_borrow_data()?;
+            T::unpack(&data)?
+        };
+
+        Ok(f(&state))
+    }
+}
+
+impl<'a, const WRITE: bool> LegacyTokenAccount<'a, WRITE> {
+    /// Whether the token account is frozen (based on its deserialized state).
+    #[inline(always)]
+    pub fn is_frozen(&self) -> bool {
+        self.data.state == AccountState::Frozen
+    }
+
+    /// Delegate approved to transfer or burn tokens from this token account, if any (based on its
+    /// deserialized state).
+    #[inline(always)]
+    pub fn delegate(&self) -> Option<Pubkey> {
+        Option::from(self.data.delegate)
+    }
+
+    /// Amount of tokens the delegate is approved to transfer or burn (based on its
+    /// deserialized state). This amount is zero if there is no delegate.
+    #[inline(always)]
+    pub fn delegated_amount(&self) -> u64 {
+        self.data.delegated_amount
+    }
+
+    /// Assert that the token account is not frozen. The Token program enforces this when moving
+    /// tokens, but checking beforehand gives a clearer error and avoids a failed CPI call.
+    #[inline(always)]
+    pub fn assert_not_frozen(&self) -> ProgramResult {
+        _assert_not_frozen(self.is_frozen())
+    }
+}
+
 /// Wrapper for [Program] for the SPL Token Extensions program.
 #[derive(Clone, PartialEq, Eq)]
 pub struct TokenExtensionsProgram<'a>(pub(crate) Program<'a>);
@@ -538,3 +908,854 @@ impl<'a, const WRITE: bool, T: BaseState + Pack> Deref
         &self.0
     }
 }
+
+impl<'a, const WRITE: bool> ExtensionsBaseTokenAccount<'a, WRITE> {
+    /// Whether the token account is frozen (based on its deserialized base state).
+    #[inline(always)]
+    pub fn is_frozen(&self) -> bool {
+        self.data.state == AccountState::Frozen
+    }
+
+    /// Assert that the token account is not frozen. The Token program enforces this when moving
+    /// tokens, but checking beforehand gives a clearer error and avoids a failed CPI call.
+    #[inline(always)]
+    pub fn assert_not_frozen(&self) -> ProgramResult {
+        _assert_not_frozen(self.is_frozen())
+    }
+}
+
+/// Assert that two token accounts (for either SPL Token or SPL Token Extensions program) share the
+/// same mint based on their deserialized base states. This check is common for swaps and transfers
+/// between pools.
+///
+/// If the mints differ, a custom program error code with [SealevelToolsError::ACCOUNT_INFO] is
+/// returned, as well as a program log indicating both mints.
+#[inline(always)]
+pub fn assert_same_mint<const WRITE_A: bool, const WRITE_B: bool>(
+    a: &TokenAccount<'_, WRITE_A>,
+    b: &TokenAccount<'_, WRITE_B>,
+) -> ProgramResult {
+    _assert_same_mint(&a.data.mint, &b.data.mint)
+}
+
+#[inline(always)]
+fn _assert_same_mint(a_mint: &Pubkey, b_mint: &Pubkey) -> ProgramResult {
+    if a_mint == b_mint {
+        return Ok(());
+    }
+
+    #[cfg(feature = "alloc")]
+    return Err(SealevelToolsError::AccountInfo(&[
+        "Token accounts have different mints...",
+        format!("  First: {}", a_mint).as_str(),
+        format!("  Second: {}", b_mint).as_str(),
+    ])
+    .into());
+    #[cfg(not(feature = "alloc"))]
+    return Err(SealevelToolsError::AccountInfo(&["Token accounts have different mints"]).into());
+}
+
+/// Assert that the signer is either the mint authority or the freeze authority of the mint (for
+/// either SPL Token or SPL Token Extensions program) based on its deserialized base state. This
+/// check is common for admin operations on a mint, which either authority may perform.
+///
+/// If the signer is neither authority, a custom program error code with
+/// [SealevelToolsError::ACCOUNT_INFO] is returned, as well as a program log indicating the signer
+/// and both accepted authorities.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account_info::{assert_mint_authority_or_freeze, Authority, ReadonlyMintAccount},
+///     entrypoint::ProgramResult,
+/// };
+///
+/// fn check_admin(mint: &ReadonlyMintAccount, admin: &Authority) -> ProgramResult {
+///     assert_mint_authority_or_freeze(mint, admin)
+/// }
+/// ```
+#[inline(always)]
+pub fn assert_mint_authority_or_freeze<const MINT_WRITE: bool, const SIGNER_WRITE: bool>(
+    mint: &MintAccount<'_, MINT_WRITE>,
+    signer: &Signer<'_, SIGNER_WRITE>,
+) -> ProgramResult {
+    _assert_mint_authority_or_freeze(
+        signer.key(),
+        Option::from(mint.data.mint_authority),
+        Option::from(mint.data.freeze_authority),
+    )
+}
+
+#[inline(always)]
+fn _assert_mint_authority_or_freeze(
+    key: &Pubkey,
+    mint_authority: Option<Pubkey>,
+    freeze_authority: Option<Pubkey>,
+) -> ProgramResult {
+    if mint_authority.as_ref() == Some(key) || freeze_authority.as_ref() == Some(key) {
+        return Ok(());
+    }
+
+    #[cfg(feature = "alloc")]
+    return Err(SealevelToolsError::AccountInfo(&[
+        "Signer is neither mint authority nor freeze authority...",
+        format!("  Found: {}", key).as_str(),
+        format!("  Mint authority: {:?}", mint_authority).as_str(),
+        format!("  Freeze authority: {:?}", freeze_authority).as_str(),
+    ])
+    .into());
+    #[cfg(not(feature = "alloc"))]
+    return Err(SealevelToolsError::AccountInfo(&[
+        "Signer is neither mint authority nor freeze authority",
+    ])
+    .into());
+}
+
+/// Assert that the token program owns the given account (e.g. a token account or mint). This check
+/// prevents passing in the SPL Token program alongside a Token Extensions account (or vice versa),
+/// which would otherwise fail in a CPI call to the wrong program.
+///
+/// If the account is not owned by the token program, a custom program error code with
+/// [SealevelToolsError::ACCOUNT_INFO] is returned, as well as a program log indicating both keys.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account_info::{assert_program_owns, TokenProgram, WritableTokenProgramAccount},
+///     entrypoint::ProgramResult,
+/// };
+///
+/// fn check_token_account(
+///     token_program: &TokenProgram,
+///     token_account: &WritableTokenProgramAccount,
+/// ) -> ProgramResult {
+///     assert_program_owns(token_program, token_account)
+/// }
+/// ```
+#[inline(always)]
+pub fn assert_program_owns(
+    token_program: &TokenProgram,
+    token_account: &NoStdAccountInfo,
+) -> ProgramResult {
+    _assert_program_owns(token_program.key(), token_account.owner())
+}
+
+#[inline(always)]
+fn _assert_program_owns(program_id: &Pubkey, owner: &Pubkey) -> ProgramResult {
+    if program_id == owner {
+        return Ok(());
+    }
+
+    #[cfg(feature = "alloc")]
+    return Err(SealevelToolsError::AccountInfo(&[
+        "Token program does not own account...",
+        format!("  Token program: {}", program_id).as_str(),
+        format!("  Account owner: {}", owner).as_str(),
+    ])
+    .into());
+    #[cfg(not(feature = "alloc"))]
+    return Err(SealevelToolsError::AccountInfo(&["Token program does not own account"]).into());
+}
+
+/// Like [try_next_enumerated_account], but processes the next two accounts as a mint and a token
+/// account for this mint (for either SPL Token or SPL Token Extensions program). The token
+/// account's mint (from its deserialized base state) must equal the mint's key.
+///
+/// If the token account's mint does not match, a custom program error code with
+/// [SealevelToolsError::ACCOUNT_INFO] is returned, as well as a program log indicating both account
+/// indices.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account_info::try_next_mint_and_token_account,
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+/// };
+///
+/// fn process(accounts: &[NoStdAccountInfo]) -> ProgramResult {
+///     let mut accounts_iter = accounts.iter().enumerate();
+///
+///     let ((_, mint), (_, token_account)) = try_next_mint_and_token_account::<false, true>(
+///         &mut accounts_iter,
+///         Default::default(),
+///         Default::default(),
+///     )?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [try_next_enumerated_account]: crate::account_info::try_next_enumerated_account
+#[allow(clippy::type_complexity)]
+#[inline(always)]
+pub fn try_next_mint_and_token_account<'a, const MINT_WRITE: bool, const TOKEN_WRITE: bool>(
+    iter: &mut impl Iterator<Item = (usize, &'a NoStdAccountInfo)>,
+    mint_constraints: AccountInfoConstraints,
+    token_account_constraints: AccountInfoConstraints,
+) -> Result<
+    (
+        (usize, MintAccount<'a, MINT_WRITE>),
+        (usize, TokenAccount<'a, TOKEN_WRITE>),
+    ),
+    ProgramError,
+> {
+    let (mint_index, mint) =
+        try_next_enumerated_account::<MintAccount<'a, MINT_WRITE>>(iter, mint_constraints)?;
+    let (token_account_index, token_account) = try_next_enumerated_account::<
+        TokenAccount<'a, TOKEN_WRITE>,
+    >(iter, token_account_constraints)?;
+
+    _assert_token_account_mint(
+        mint_index,
+        mint.key(),
+        token_account_index,
+        &token_account.data.mint,
+    )?;
+
+    Ok(((mint_index, mint), (token_account_index, token_account)))
+}
+
+#[inline(always)]
+fn _assert_token_account_mint(
+    mint_index: usize,
+    mint_key: &Pubkey,
+    token_account_index: usize,
+    token_account_mint: &Pubkey,
+) -> ProgramResult {
+    if mint_key == token_account_mint {
+        return Ok(());
+    }
+
+    #[cfg(feature = "alloc")]
+    return Err(SealevelToolsError::AccountInfo(&[
+        format!(
+            "Account index {}: Token account mint does not match mint at index {}...",
+            token_account_index, mint_index
+        )
+        .as_str(),
+        format!("  Found: {}", token_account_mint).as_str(),
+        format!("  Expected: {}", mint_key).as_str(),
+    ])
+    .into());
+    #[cfg(not(feature = "alloc"))]
+    {
+        let _ = (mint_index, token_account_index);
+        Err(SealevelToolsError::AccountInfo(&["Token account mint does not match mint"]).into())
+    }
+}
+
+#[inline(always)]
+fn _assert_not_frozen(is_frozen: bool) -> ProgramResult {
+    if is_frozen {
+        Err(SealevelToolsError::AccountInfo(&["Token account is frozen"]).into())
+    } else {
+        Ok(())
+    }
+}
+
+#[inline(always)]
+fn _assert_supply_zero(supply: u64) -> ProgramResult {
+    if supply == 0 {
+        return Ok(());
+    }
+
+    #[cfg(feature = "alloc")]
+    return Err(SealevelToolsError::AccountInfo(&[
+        "Mint supply is not zero...",
+        format!("  Found: {}", supply).as_str(),
+    ])
+    .into());
+    #[cfg(not(feature = "alloc"))]
+    return Err(SealevelToolsError::AccountInfo(&["Mint supply is not zero"]).into());
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        program_error::ProgramError,
+        test_fixture::{TestAccount, TestAccountInfos},
+    };
+
+    use super::*;
+
+    fn mint_data(
+        mint_authority: Option<Pubkey>,
+        freeze_authority: Option<Pubkey>,
+    ) -> [u8; BaseMintState::LEN] {
+        let mut data = [0; BaseMintState::LEN];
+        BaseMintState {
+            mint_authority: mint_authority.into(),
+            decimals: 9,
+            is_initialized: true,
+            freeze_authority: freeze_authority.into(),
+            ..Default::default()
+        }
+        .pack_into_slice(&mut data);
+
+        data
+    }
+
+    fn token_account_data(mint: Pubkey) -> [u8; BaseTokenAccountState::LEN] {
+        let mut data = [0; BaseTokenAccountState::LEN];
+        BaseTokenAccountState {
+            mint,
+            owner: Pubkey::new_unique(),
+            state: AccountState::Initialized,
+            ..Default::default()
+        }
+        .pack_into_slice(&mut data);
+
+        data
+    }
+
+    #[test]
+    fn test_token_variant() {
+        for variant in [TokenVariant::Legacy, TokenVariant::Extensions] {
+            assert_eq!(
+                TokenVariant::from_program_id(variant.program_id()),
+                Some(variant)
+            );
+        }
+        assert_eq!(
+            TokenVariant::from_program_id(&legacy_token::ID),
+            Some(TokenVariant::Legacy)
+        );
+        assert_eq!(
+            TokenVariant::from_program_id(&token_extensions::ID),
+            Some(TokenVariant::Extensions)
+        );
+        assert_eq!(TokenVariant::from_program_id(&Pubkey::new_unique()), None);
+    }
+
+    #[test]
+    fn test_is_initialized_mint_data() {
+        let mut data = [0; BaseMintState::LEN];
+        assert!(!_is_initialized_mint_data(&data));
+
+        BaseMintState {
+            decimals: 9,
+            is_initialized: true,
+            ..Default::default()
+        }
+        .pack_into_slice(&mut data);
+        assert!(_is_initialized_mint_data(&data));
+
+        assert!(!_is_initialized_mint_data(&data[..(BaseMintState::LEN - 1)]));
+        assert!(!_is_initialized_mint_data(&[]));
+    }
+
+    #[test]
+    fn test_assert_token_account_mint() {
+        let mint = Pubkey::new_unique();
+        assert_eq!(_assert_token_account_mint(0, &mint, 1, &mint), Ok(()));
+
+        let other_mint = Pubkey::new_unique();
+        assert_eq!(
+            _assert_token_account_mint(0, &mint, 1, &other_mint),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+    }
+
+    #[test]
+    fn test_assert_same_mint() {
+        let mint = Pubkey::new_unique();
+        assert_eq!(_assert_same_mint(&mint, &mint), Ok(()));
+
+        let other_mint = Pubkey::new_unique();
+        assert_eq!(
+            _assert_same_mint(&mint, &other_mint),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+    }
+
+    #[test]
+    fn test_assert_supply_zero() {
+        assert_eq!(_assert_supply_zero(0), Ok(()));
+        assert_eq!(
+            _assert_supply_zero(1),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+        assert_eq!(
+            _assert_supply_zero(u64::MAX),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+    }
+
+    #[test]
+    fn test_assert_program_owns() {
+        for program_id in TOKEN_PROGRAM_IDS {
+            assert_eq!(_assert_program_owns(program_id, program_id), Ok(()));
+        }
+
+        // Token Extensions account passed alongside the SPL Token program.
+        assert_eq!(
+            _assert_program_owns(&legacy_token::ID, &token_extensions::ID),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+        assert_eq!(
+            _assert_program_owns(&token_extensions::ID, &legacy_token::ID),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+    }
+
+    #[test]
+    fn test_assert_program_owns_account_info() {
+        let data = token_account_data(Pubkey::new_unique());
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(legacy_token::ID, Pubkey::new_unique()).executable(),
+            TestAccount::new(Pubkey::new_unique(), legacy_token::ID).data(&data),
+            TestAccount::new(Pubkey::new_unique(), token_extensions::ID).data(&data),
+        ]);
+
+        let token_program = TokenProgram::try_from(&infos[0]).unwrap();
+        assert_eq!(assert_program_owns(&token_program, &infos[1]), Ok(()));
+        assert_eq!(
+            assert_program_owns(&token_program, &infos[2]),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+    }
+
+    #[test]
+    fn test_assert_mint_authority_or_freeze() {
+        let mint_authority = Pubkey::new_unique();
+        let freeze_authority = Pubkey::new_unique();
+
+        assert_eq!(
+            _assert_mint_authority_or_freeze(
+                &mint_authority,
+                Some(mint_authority),
+                Some(freeze_authority)
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            _assert_mint_authority_or_freeze(
+                &freeze_authority,
+                Some(mint_authority),
+                Some(freeze_authority)
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            _assert_mint_authority_or_freeze(&freeze_authority, None, Some(freeze_authority)),
+            Ok(())
+        );
+
+        // Neither authority.
+        assert_eq!(
+            _assert_mint_authority_or_freeze(
+                &Pubkey::new_unique(),
+                Some(mint_authority),
+                Some(freeze_authority)
+            ),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+
+        // No authorities.
+        assert_eq!(
+            _assert_mint_authority_or_freeze(&mint_authority, None, None),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+    }
+
+    #[test]
+    fn test_assert_mint_authority_or_freeze_account_info() {
+        let mint_authority = Pubkey::new_unique();
+        let freeze_authority = Pubkey::new_unique();
+
+        let data = mint_data(Some(mint_authority), Some(freeze_authority));
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(Pubkey::new_unique(), token_extensions::ID).data(&data),
+            TestAccount::new(mint_authority, Pubkey::default()).signer(),
+            TestAccount::new(freeze_authority, Pubkey::default()).signer(),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::default()).signer(),
+        ]);
+
+        let mint = MintAccount::<false>::try_from(&infos[0]).unwrap();
+
+        for (info, expected) in infos[1..].iter().zip([
+            Ok(()),
+            Ok(()),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO)),
+        ]) {
+            let signer = Signer::<false>::try_from(info).unwrap();
+            assert_eq!(assert_mint_authority_or_freeze(&mint, &signer), expected);
+        }
+    }
+
+    #[test]
+    fn test_try_next_mint_and_token_account() {
+        let mint_key = Pubkey::new_unique();
+        let mint = mint_data(None, None);
+        let token_account = token_account_data(mint_key);
+        let other_token_account = token_account_data(Pubkey::new_unique());
+
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(mint_key, legacy_token::ID).data(&mint),
+            TestAccount::new(Pubkey::new_unique(), legacy_token::ID)
+                .data(&token_account)
+                .writable(),
+            TestAccount::new(mint_key, legacy_token::ID).data(&mint),
+            TestAccount::new(Pubkey::new_unique(), legacy_token::ID)
+                .data(&other_token_account)
+                .writable(),
+        ]);
+
+        let mut iter = infos.iter().enumerate();
+
+        let ((mint_index, mint), (token_account_index, token_account)) =
+            try_next_mint_and_token_account::<false, true>(
+                &mut iter,
+                Default::default(),
+                Default::default(),
+            )
+            .unwrap();
+        assert_eq!((mint_index, mint.key()), (0, &mint_key));
+        assert_eq!(
+            (token_account_index, token_account.data.mint),
+            (1, mint_key)
+        );
+
+        // Token account for another mint.
+        assert_eq!(
+            try_next_mint_and_token_account::<false, true>(
+                &mut iter,
+                Default::default(),
+                Default::default(),
+            )
+            .err(),
+            Some(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+    }
+
+    #[test]
+    fn test_is_frozen() {
+        let mint = Pubkey::new_unique();
+
+        let initialized = token_account_data(mint);
+        let mut frozen = [0; BaseTokenAccountState::LEN];
+        BaseTokenAccountState {
+            mint,
+            owner: Pubkey::new_unique(),
+            state: AccountState::Frozen,
+            ..Default::default()
+        }
+        .pack_into_slice(&mut frozen);
+
+        for program_id in TOKEN_PROGRAM_IDS {
+            let infos = TestAccountInfos::new(&[
+                TestAccount::new(Pubkey::new_unique(), *program_id).data(&initialized),
+                TestAccount::new(Pubkey::new_unique(), *program_id).data(&frozen),
+            ]);
+
+            let token_account = ReadonlyTokenAccount::try_from(&infos[0]).unwrap();
+            assert!(!token_account.is_frozen());
+            assert_eq!(token_account.assert_not_frozen(), Ok(()));
+
+            let token_account = ReadonlyTokenAccount::try_from(&infos[1]).unwrap();
+            assert!(token_account.is_frozen());
+            assert_eq!(
+                token_account.assert_not_frozen(),
+                Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+            );
+
+            if program_id == &legacy_token::ID {
+                let token_account = ReadonlyLegacyTokenAccount::try_from(&infos[0]).unwrap();
+                assert!(!token_account.is_frozen());
+                assert_eq!(token_account.assert_not_frozen(), Ok(()));
+
+                let token_account = ReadonlyLegacyTokenAccount::try_from(&infos[1]).unwrap();
+                assert!(token_account.is_frozen());
+                assert_eq!(
+                    token_account.assert_not_frozen(),
+                    Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+                );
+            } else {
+                let token_account =
+                    ReadonlyExtensionsBaseTokenAccount::try_from(&infos[0]).unwrap();
+                assert!(!token_account.is_frozen());
+                assert_eq!(token_account.assert_not_frozen(), Ok(()));
+
+                let token_account =
+                    ReadonlyExtensionsBaseTokenAccount::try_from(&infos[1]).unwrap();
+                assert!(token_account.is_frozen());
+                assert_eq!(
+                    token_account.assert_not_frozen(),
+                    Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_delegate() {
+        let mint = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+
+        let undelegated = token_account_data(mint);
+        let mut delegated = [0; BaseTokenAccountState::LEN];
+        BaseTokenAccountState {
+            mint,
+            owner: Pubkey::new_unique(),
+            amount: 69,
+            delegate: Some(delegate).into(),
+            state: AccountState::Initialized,
+            delegated_amount: 42,
+            ..Default::default()
+        }
+        .pack_into_slice(&mut delegated);
+
+        for program_id in TOKEN_PROGRAM_IDS {
+            let infos = TestAccountInfos::new(&[
+                TestAccount::new(Pubkey::new_unique(), *program_id).data(&undelegated),
+                TestAccount::new(Pubkey::new_unique(), *program_id).data(&delegated),
+            ]);
+
+            let token_account = ReadonlyTokenAccount::try_from(&infos[0]).unwrap();
+            assert_eq!(token_account.delegate(), None);
+            assert_eq!(token_account.delegated_amount(), 0);
+
+            let token_account = ReadonlyTokenAccount::try_from(&infos[1]).unwrap();
+            assert_eq!(token_account.delegate(), Some(delegate));
+            assert_eq!(token_account.delegated_amount(), 42);
+
+            if program_id == &legacy_token::ID {
+                let token_account = ReadonlyLegacyTokenAccount::try_from(&infos[0]).unwrap();
+                assert_eq!(token_account.delegate(), None);
+                assert_eq!(token_account.delegated_amount(), 0);
+
+                let token_account = ReadonlyLegacyTokenAccount::try_from(&infos[1]).unwrap();
+                assert_eq!(token_account.delegate(), Some(delegate));
+                assert_eq!(token_account.delegated_amount(), 42);
+            }
+        }
+    }
+
+    #[test]
+    fn test_with_unpacked() {
+        let mint = Pubkey::new_unique();
+        let data = token_account_data(mint);
+
+        let infos =
+            TestAccountInfos::new(&[TestAccount::new(Pubkey::new_unique(), legacy_token::ID)
+                .data(&data)
+                .writable()]);
+        let token_account = WritableLegacyTokenAccount::try_from(&infos[0]).unwrap();
+
+        assert_eq!(
+            token_account.with_unpacked(|state| (state.mint, state.amount)),
+            Ok((mint, 0))
+        );
+
+        // Closure reads the latest state, not the state deserialized when the wrapper was created.
+        BaseTokenAccountState {
+            amount: 69,
+            ..*token_account.data
+        }
+        .pack_into_slice(&mut token_account.try_borrow_mut_data().unwrap());
+        assert_eq!(token_account.with_unpacked(|state| state.amount), Ok(69));
+        assert_eq!(token_account.data.amount, 0);
+
+        // Invalid account state.
+        token_account.try_borrow_mut_data().unwrap()[108] = 3;
+        assert_eq!(
+            token_account.with_unpacked(|_| unreachable!()),
+            Err::<(), _>(ProgramError::InvalidAccountData)
+        );
+
+        // Uninitialized account state.
+        token_account.try_borrow_mut_data().unwrap()[108] = 0;
+        assert_eq!(
+            token_account.with_unpacked(|_| unreachable!()),
+            Err::<(), _>(ProgramError::UninitializedAccount)
+        );
+    }
+
+    /// Mint with the mint close authority extension, which is 82 bytes of base state padded to 165
+    /// bytes, followed by the account type (1 byte) and the extension's TLV entry (4 + 32 bytes).
+    fn mint_with_close_authority_data() -> [u8; 202] {
+        use crate::spl_token_2022::extension::{
+            mint_close_authority::MintCloseAuthority, BaseStateWithExtensionsMut,
+            StateWithExtensionsMut,
+        };
+
+        let mut data = [0; 202];
+        assert_eq!(
+            ExtensionType::try_calculate_account_len::<BaseMintState>(&[
+                ExtensionType::MintCloseAuthority
+            ]),
+            Ok(data.len())
+        );
+
+        let mut state =
+            StateWithExtensionsMut::<BaseMintState>::unpack_uninitialized(&mut data).unwrap();
+        state
+            .init_extension::<MintCloseAuthority>(true)
+            .unwrap()
+            .close_authority = Some(Pubkey::new_unique()).try_into().unwrap();
+        state.base = BaseMintState {
+            is_initialized: true,
+            ..Default::default()
+        };
+        state.pack_base();
+        state.init_account_type().unwrap();
+
+        data
+    }
+
+    #[test]
+    fn test_mint_has_extension() {
+        let data = mint_with_close_authority_data();
+        let legacy_data = mint_data(None, None);
+
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(Pubkey::new_unique(), token_extensions::ID).data(&data),
+            TestAccount::new(Pubkey::new_unique(), legacy_token::ID).data(&legacy_data),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique()).data(&data),
+        ]);
+
+        assert_eq!(
+            mint_has_extension(&infos[0], ExtensionType::MintCloseAuthority),
+            Ok(true)
+        );
+        assert_eq!(
+            mint_has_extension(&infos[0], ExtensionType::TransferHook),
+            Ok(false)
+        );
+        assert_eq!(
+            mint_has_extension(&infos[1], ExtensionType::MintCloseAuthority),
+            Ok(false)
+        );
+
+        // Not owned by either Token program.
+        assert_eq!(
+            mint_has_extension(&infos[2], ExtensionType::MintCloseAuthority),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+    }
+
+    #[test]
+    fn test_assert_mint_lacks_extensions() {
+        let data = mint_with_close_authority_data();
+        let legacy_data = mint_data(None, None);
+
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(Pubkey::new_unique(), token_extensions::ID).data(&data),
+            TestAccount::new(Pubkey::new_unique(), legacy_token::ID).data(&legacy_data),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique()).data(&legacy_data),
+        ]);
+
+        let forbidden = [
+            ExtensionType::TransferFeeConfig,
+            ExtensionType::MintCloseAuthority,
+        ];
+
+        assert_eq!(
+            assert_mint_lacks_extensions(&infos[0], &forbidden),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+        assert_eq!(
+            assert_mint_lacks_extensions(&infos[0], &[ExtensionType::TransferHook]),
+            Ok(())
+        );
+        assert_eq!(assert_mint_lacks_extensions(&infos[1], &forbidden), Ok(()));
+
+        // Legacy mint data that is not owned by either Token program.
+        assert_eq!(
+            assert_mint_lacks_extensions(&infos[2], &forbidden),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_get_mint_extension_types() {
+        let data = mint_with_close_authority_data();
+        let legacy_data = mint_data(None, None);
+
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(Pubkey::new_unique(), token_extensions::ID).data(&data),
+            TestAccount::new(Pubkey::new_unique(), legacy_token::ID).data(&legacy_data),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique()).data(&data),
+        ]);
+
+        assert_eq!(
+            get_mint_extension_types(&infos[0]),
+            Ok(alloc::vec![ExtensionType::MintCloseAuthority])
+        );
+        assert_eq!(get_mint_extension_types(&infos[1]), Ok(alloc::vec![]));
+
+        // Not owned by either Token program.
+        assert_eq!(
+            get_mint_extension_types(&infos[2]),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+    }
+
+    /// Serialize a TLV entry with a zeroed value of the given length into `buf`, returning the
+    /// number of bytes written.
+    fn write_tlv_entry(buf: &mut [u8], extension_type: ExtensionType, value_len: u16) -> usize {
+        buf[..2].copy_from_slice(&<[u8; 2]>::from(extension_type));
+        buf[2..4].copy_from_slice(&value_len.to_le_bytes());
+        4 + usize::from(value_len)
+    }
+
+    #[test]
+    fn test_find_extension_type() {
+        let find = |tlv_data: &[u8], extension_type: ExtensionType| {
+            _find_extension_type(tlv_data, |found| found == extension_type)
+        };
+
+        let mut tlv_data = [0; 128];
+        let mut len = write_tlv_entry(&mut tlv_data, ExtensionType::MintCloseAuthority, 32);
+        len += write_tlv_entry(&mut tlv_data[len..], ExtensionType::TransferFeeConfig, 16);
+
+        assert_eq!(
+            find(&tlv_data, ExtensionType::MintCloseAuthority),
+            Ok(Some(ExtensionType::MintCloseAuthority))
+        );
+        assert_eq!(
+            find(&tlv_data, ExtensionType::TransferFeeConfig),
+            Ok(Some(ExtensionType::TransferFeeConfig))
+        );
+
+        // Any of several extension types.
+        let forbidden = [
+            ExtensionType::TransferHook,
+            ExtensionType::TransferFeeConfig,
+        ];
+        assert_eq!(
+            _find_extension_type(&tlv_data, |found| forbidden.contains(&found)),
+            Ok(Some(ExtensionType::TransferFeeConfig))
+        );
+
+        // Uninitialized entry after the last extension ends the scan.
+        assert_eq!(find(&tlv_data, ExtensionType::TransferHook), Ok(None));
+
+        // Exact length and trailing byte (which cannot hold another type).
+        assert_eq!(
+            find(&tlv_data[..len], ExtensionType::TransferHook),
+            Ok(None)
+        );
+        assert_eq!(
+            find(&tlv_data[..(len + 1)], ExtensionType::TransferHook),
+            Ok(None)
+        );
+        assert_eq!(find(&[], ExtensionType::TransferHook), Ok(None));
+
+        // Value exceeds TLV data.
+        assert_eq!(
+            find(&tlv_data[..(len - 1)], ExtensionType::TransferHook),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        // Missing length.
+        assert_eq!(
+            find(&tlv_data[..38], ExtensionType::TransferHook),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+}
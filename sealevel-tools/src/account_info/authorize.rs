@@ -0,0 +1,158 @@
+#[cfg(feature = "alloc")]
+use alloc::format;
+
+use crate::{
+    account::AccountSerde, entrypoint::ProgramResult, error::SealevelToolsError, pubkey::Pubkey,
+};
+
+use super::{DataAccount, Signer};
+
+/// Assert that the signer's key equals the expected key (e.g. an admin key stored on-chain).
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account_info::{assert_signer_matches, try_next_enumerated_account, Authority},
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+///     pubkey::Pubkey,
+/// };
+///
+/// fn admin_only(accounts: &[NoStdAccountInfo], admin: &Pubkey) -> ProgramResult {
+///     let mut accounts_iter = accounts.iter().enumerate();
+///
+///     let (_, authority) =
+///         try_next_enumerated_account::<Authority>(&mut accounts_iter, Default::default())?;
+///     assert_signer_matches(&authority, admin)?;
+///
+///     Ok(())
+/// }
+/// ```
+#[inline(always)]
+pub fn assert_signer_matches<const WRITE: bool>(
+    signer: &Signer<'_, WRITE>,
+    expected: &Pubkey,
+) -> ProgramResult {
+    _assert_signer_key(signer.key(), expected)
+}
+
+/// Assert that the signer's key equals a key stored in a data account, which is selected with the
+/// `expected` closure (e.g. `|config| &config.admin`).
+#[inline(always)]
+pub fn assert_signer_is<
+    const SIGNER_WRITE: bool,
+    const WRITE: bool,
+    const DISC_LEN: usize,
+    T: AccountSerde<DISC_LEN>,
+>(
+    signer: &Signer<'_, SIGNER_WRITE>,
+    data_account: &DataAccount<'_, WRITE, DISC_LEN, T>,
+    expected: impl FnOnce(&T) -> &Pubkey,
+) -> ProgramResult {
+    _assert_signer_key(signer.key(), expected(&data_account.data))
+}
+
+#[inline(always)]
+fn _assert_signer_key(signer_key: &Pubkey, expected: &Pubkey) -> ProgramResult {
+    if signer_key == expected {
+        return Ok(());
+    }
+
+    #[cfg(feature = "alloc")]
+    return Err(SealevelToolsError::AccountInfo(&[
+        "Signer does not match expected key...",
+        format!("  Found: {}", signer_key).as_str(),
+        format!("  Expected: {}", expected).as_str(),
+    ])
+    .into());
+    #[cfg(not(feature = "alloc"))]
+    return Err(SealevelToolsError::AccountInfo(&["Signer does not match expected key"]).into());
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        program_error::ProgramError,
+        test_fixture::{TestAccount, TestAccountInfos},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_assert_signer_key() {
+        let admin = Pubkey::new_unique();
+        assert_eq!(_assert_signer_key(&admin, &admin), Ok(()));
+
+        let other = Pubkey::new_unique();
+        assert_eq!(
+            _assert_signer_key(&other, &admin),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+    }
+
+    #[test]
+    fn test_assert_signer_matches() {
+        let admin = Pubkey::new_unique();
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(admin, Pubkey::default()).signer(),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::default())
+                .signer()
+                .writable(),
+        ]);
+
+        let authority = Signer::<false>::try_from(&infos[0]).unwrap();
+        assert_eq!(assert_signer_matches(&authority, &admin), Ok(()));
+
+        let payer = Signer::<true>::try_from(&infos[1]).unwrap();
+        assert_eq!(
+            assert_signer_matches(&payer, &admin),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn test_assert_signer_is() {
+        use crate::{
+            account::BorshAccountSchema,
+            borsh::{BorshDeserialize, BorshSerialize},
+            discriminator::{Discriminate, Discriminator},
+        };
+
+        #[derive(BorshDeserialize, BorshSerialize)]
+        struct Config {
+            admin: Pubkey,
+        }
+
+        impl Discriminate<8> for Config {
+            const DISCRIMINATOR: [u8; 8] = Discriminator::Sha2(b"Config").to_bytes();
+        }
+
+        let admin = Pubkey::new_unique();
+
+        let mut data = [0; 40];
+        data[..8].copy_from_slice(&Config::DISCRIMINATOR);
+        data[8..].copy_from_slice(admin.as_ref());
+
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique()).data(&data),
+            TestAccount::new(admin, Pubkey::default()).signer(),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::default()).signer(),
+        ]);
+
+        let config =
+            DataAccount::<false, 8, BorshAccountSchema<8, Config>>::try_from(&infos[0]).unwrap();
+
+        let signer = Signer::<false>::try_from(&infos[1]).unwrap();
+        assert_eq!(
+            assert_signer_is(&signer, &config, |config| &config.admin),
+            Ok(())
+        );
+
+        let signer = Signer::<false>::try_from(&infos[2]).unwrap();
+        assert_eq!(
+            assert_signer_is(&signer, &config, |config| &config.admin),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+    }
+}
@@ -0,0 +1,92 @@
+#[cfg(feature = "alloc")]
+use alloc::format;
+
+use crate::{
+    entrypoint::{NoStdAccountInfo, ProgramResult},
+    error::SealevelToolsError,
+    pubkey::Pubkey,
+};
+
+/// Assert that no two of the given accounts share the same key. This guard is useful for
+/// instructions where the same account passed in for two different roles (e.g. source and
+/// destination of a transfer) can lead to unexpected behavior.
+///
+/// If any two accounts share a key, a custom program error code with
+/// [SealevelToolsError::ACCOUNT_INFO] is returned, as well as a program log indicating the indices
+/// (relative to the given slice) of the colliding accounts.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account_info::{assert_distinct, try_next_enumerated_account, WritableAccount},
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+/// };
+///
+/// fn process_swap(accounts: &[NoStdAccountInfo]) -> ProgramResult {
+///     let mut accounts_iter = accounts.iter().enumerate();
+///
+///     let (_, source) =
+///         try_next_enumerated_account::<WritableAccount>(&mut accounts_iter, Default::default())?;
+///     let (_, destination) =
+///         try_next_enumerated_account::<WritableAccount>(&mut accounts_iter, Default::default())?;
+///
+///     assert_distinct(&[&*source, &*destination])?;
+///
+///     Ok(())
+/// }
+/// ```
+#[inline(always)]
+pub fn assert_distinct(accounts: &[&NoStdAccountInfo]) -> ProgramResult {
+    match _find_duplicate_keys(accounts.iter().map(|account| account.key())) {
+        None => Ok(()),
+        Some((i, j)) => {
+            #[cfg(feature = "alloc")]
+            return Err(SealevelToolsError::AccountInfo(&[
+                format!("Accounts {} and {}: Duplicate key...", i, j).as_str(),
+                format!("  Found: {}", accounts[i].key()).as_str(),
+            ])
+            .into());
+            #[cfg(not(feature = "alloc"))]
+            {
+                let _ = (i, j);
+                Err(SealevelToolsError::AccountInfo(&["Accounts must have distinct keys"]).into())
+            }
+        }
+    }
+}
+
+/// Find the first pair of indices whose keys are equal.
+#[inline(always)]
+fn _find_duplicate_keys<'a>(
+    keys: impl Iterator<Item = &'a Pubkey> + Clone,
+) -> Option<(usize, usize)> {
+    keys.clone().enumerate().find_map(|(i, key)| {
+        keys.clone()
+            .enumerate()
+            .skip(i + 1)
+            .find_map(|(j, other)| (key == other).then_some((i, j)))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_find_duplicate_keys() {
+        let keys = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        assert_eq!(_find_duplicate_keys(keys.iter()), None);
+        assert_eq!(_find_duplicate_keys([].iter()), None);
+
+        let keys = [keys[0], keys[1], keys[2], keys[1]];
+        assert_eq!(_find_duplicate_keys(keys.iter()), Some((1, 3)));
+
+        let keys = [keys[0], keys[0]];
+        assert_eq!(_find_duplicate_keys(keys.iter()), Some((0, 1)));
+    }
+}
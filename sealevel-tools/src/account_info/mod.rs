@@ -1,10 +1,26 @@
 //! [NoStdAccountInfo] utilities.
 
 mod account;
+mod authorize;
 mod close;
+mod distinct;
+mod owner;
+mod program;
+mod rent;
+mod shrink;
+mod signer;
+mod withdraw;
 
 pub use account::*;
+pub use authorize::*;
 pub use close::*;
+pub use distinct::*;
+pub use owner::*;
+pub use program::*;
+pub use rent::*;
+pub use shrink::*;
+pub use signer::*;
+pub use withdraw::*;
 
 #[cfg(feature = "alloc")]
 use alloc::format;
@@ -13,6 +29,19 @@ use crate::{error::SealevelToolsError, program_error::ProgramError, pubkey::Pubk
 
 pub use crate::entrypoint::NoStdAccountInfo;
 
+/// Maximum number of bytes an account's data can grow by in a single instruction, which is
+/// enforced by the runtime.
+pub const MAX_PERMITTED_DATA_INCREASE: usize = 10_240;
+
+/// Default pubkey (all zeros), which is also the System program ID. An account with this key is
+/// commonly used as a placeholder.
+pub const DEFAULT_PUBKEY: Pubkey = Pubkey::new_from_array([0; 32]);
+
+/// Well-known addresses where lamports or tokens sent to them are effectively lost (the
+/// [incinerator](crate::account::incinerator::ID) and [DEFAULT_PUBKEY]). These keys can be used as
+/// [AccountInfoConstraints::reject_keys] for accounts receiving funds (e.g. a close beneficiary).
+pub const SPECIAL_KEYS: &[&Pubkey] = &[&crate::account::incinerator::ID, &DEFAULT_PUBKEY];
+
 /// Optional arguments for [try_next_enumerated_account_info], which specify constraints for the next
 /// [NoStdAccountInfo].
 #[derive(Debug, Default)]
@@ -23,18 +52,45 @@ pub struct AccountInfoConstraints<'a, 'b: 'a> {
     /// If provided, the next account's key must be one of these pubkeys.
     pub any_of_keys: Option<&'a [&'b Pubkey]>,
 
+    /// If provided, the next account's key must not be any of these pubkeys (e.g. a close
+    /// beneficiary must not be one of [SPECIAL_KEYS]).
+    pub reject_keys: Option<&'a [&'b Pubkey]>,
+
     /// If provided, the next account's owner must equal this pubkey.
     pub owner: Option<&'a Pubkey>,
 
     /// If provided, the next account's owner must be one of these pubkeys.
     pub any_of_owners: Option<&'a [&'b Pubkey]>,
 
+    /// If provided, the next account must be a valid data account for a specific program. See
+    /// [ProgramDataAccountConstraint] for more info.
+    pub program_data_account: Option<ProgramDataAccountConstraint<'a>>,
+
     /// If provided, the next account's key must be derived from these seeds and owner.
     pub seeds: Option<(
         &'a [&'b [u8]], // seeds
         &'a Pubkey,     // owner
     )>,
 
+    /// If provided, the next account's key must be derived from one of these sets of seeds and
+    /// owners (e.g. any one of several known vaults).
+    ///
+    /// ### Notes
+    ///
+    /// Each set of seeds costs a [Pubkey::find_program_address] call, which can be expensive
+    /// (especially when a canonical bump is far from 255). If the bump seeds are known (e.g.
+    /// stored in account data), it is cheaper to create the expected keys with
+    /// [Pubkey::create_program_address] and check them with [any_of_keys] instead.
+    ///
+    /// [any_of_keys]: Self::any_of_keys
+    #[allow(clippy::type_complexity)]
+    pub any_of_seeds: Option<
+        &'a [(
+            &'a [&'b [u8]], // seeds
+            &'a Pubkey,     // owner
+        )],
+    >,
+
     /// If provided, the next account's `is_signer` must equal this value.
     pub is_signer: Option<bool>,
 
@@ -83,9 +139,12 @@ pub const NO_ACCOUNT_INFO_CONSTRAINTS: AccountInfoConstraints<'static, 'static>
     AccountInfoConstraints {
         key: None,
         any_of_keys: None,
+        reject_keys: None,
         owner: None,
         any_of_owners: None,
+        program_data_account: None,
         seeds: None,
+        any_of_seeds: None,
         is_signer: None,
         is_writable: None,
         executable: None,
@@ -97,6 +156,24 @@ pub const NO_ACCOUNT_INFO_CONSTRAINTS: AccountInfoConstraints<'static, 'static>
         max_lamports: None,
     };
 
+/// Composite constraint for an account holding data for a specific program. Unlike specifying the
+/// owner, data length and data slice constraints separately, any violation of this constraint is
+/// reported as a single "not a valid program data account" failure.
+#[derive(Debug)]
+pub struct ProgramDataAccountConstraint<'a> {
+    /// The account's owner must equal this program ID.
+    pub program_id: &'a Pubkey,
+
+    /// The account's data length must be at least this value.
+    pub min_len: usize,
+
+    /// The account's data length must be at most this value.
+    pub max_len: usize,
+
+    /// If provided, the account's data must start with this discriminator.
+    pub expected_discriminator: Option<&'a [u8]>,
+}
+
 /// Slice of data to match against the next account's data.
 #[derive(Debug, Default)]
 pub struct MatchDataSlice<'a> {
@@ -104,6 +181,31 @@ pub struct MatchDataSlice<'a> {
     pub data: &'a [u8],
 }
 
+/// Code identifying which field of [AccountInfoConstraints] an account failed. With the
+/// "debug-errors" feature enabled and the "alloc" feature disabled, this code is logged along with
+/// the index of the failed account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum AccountInfoConstraintCode {
+    Key = 0,
+    AnyOfKeys = 1,
+    RejectKeys = 2,
+    Owner = 3,
+    AnyOfOwners = 4,
+    ProgramDataAccount = 5,
+    Seeds = 6,
+    AnyOfSeeds = 7,
+    IsSigner = 8,
+    IsWritable = 9,
+    Executable = 10,
+    ExactDataLen = 11,
+    MinDataLen = 12,
+    MaxDataLen = 13,
+    MatchDataSlice = 14,
+    MinLamports = 15,
+    MaxLamports = 16,
+}
+
 /// Similar to [next_account_info], but using an enumerated iterator and optional constraints.
 ///
 /// If any of the constraints are violated, a custom program error code with
@@ -236,6 +338,66 @@ where
     }
 }
 
+/// Like [try_next_enumerated_account_info], but takes the account from the back of the iterator.
+/// This method is useful for instructions whose fixed accounts are at the end of the account list
+/// (e.g. matching another protocol's trailing-account convention) with variable accounts in the
+/// middle. Because [Enumerate] pairs each account with its index before iterating from the back,
+/// the index returned is still the account's original (forward) index.
+///
+/// # Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account_info::{
+///         try_next_enumerated_account_info, try_prev_enumerated_account_info,
+///         AccountInfoConstraints,
+///     },
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+/// };
+///
+/// fn process_instruction(accounts: &[NoStdAccountInfo]) -> ProgramResult {
+///     let mut accounts_iter = accounts.iter().enumerate();
+///
+///     // First account must be writable.
+///     let (_, first_account) = try_next_enumerated_account_info(
+///         &mut accounts_iter,
+///         AccountInfoConstraints {
+///             is_writable: Some(true),
+///             ..Default::default()
+///         })?;
+///
+///     // Last account must be the System program.
+///     let (_, last_account) = try_prev_enumerated_account_info(
+///         &mut accounts_iter,
+///         AccountInfoConstraints {
+///             key: Some(&sealevel_tools::account::system::ID),
+///             ..Default::default()
+///         })?;
+///
+///     // Remaining accounts are in the middle.
+///     for (index, account) in accounts_iter {
+///         // Do something with these accounts.
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [Enumerate]: core::iter::Enumerate
+#[inline(always)]
+pub fn try_prev_enumerated_account_info<'a, I>(
+    iter: &mut I,
+    constraints: AccountInfoConstraints,
+) -> Result<I::Item, ProgramError>
+where
+    I: DoubleEndedIterator<Item = (usize, &'a NoStdAccountInfo)>,
+{
+    let (index, account) = _try_take_last_account_info(iter)?;
+    _process_enumerated_account_info(index, account, constraints)?;
+
+    Ok((index, account))
+}
+
 #[inline(always)]
 fn _try_take_account_info<'a, I>(iter: &mut I) -> Result<I::Item, ProgramError>
 where
@@ -244,16 +406,60 @@ where
     iter.next().ok_or(ProgramError::NotEnoughAccountKeys)
 }
 
+#[inline(always)]
+fn _try_take_last_account_info<'a, I>(iter: &mut I) -> Result<I::Item, ProgramError>
+where
+    I: DoubleEndedIterator<Item = (usize, &'a NoStdAccountInfo)>,
+{
+    iter.next_back().ok_or(ProgramError::NotEnoughAccountKeys)
+}
+
 #[inline(always)]
 fn _process_enumerated_account_info(
+    index: usize,
+    account: &NoStdAccountInfo,
+    constraints: AccountInfoConstraints,
+) -> Result<(), ProgramError> {
+    let mut constraint = AccountInfoConstraintCode::Key;
+    let result = _check_account_info_constraints(index, account, constraints, &mut constraint);
+
+    // Without an allocator, constraint errors do not include the account index. Log it separately
+    // (with the failed constraint) so failures are still traceable to a specific account.
+    #[cfg(all(feature = "debug-errors", not(feature = "alloc")))]
+    if result.is_err() {
+        crate::log::sol_log_labeled_u64("Failed account index: ", index as u64);
+        crate::log::sol_log_labeled_u64("Failed account constraint: ", constraint as u64);
+    }
+
+    result
+}
+
+/// Track which constraint is being checked. The code is only needed when it is logged (see
+/// [_process_enumerated_account_info]), so it is not tracked otherwise to save compute units.
+#[inline(always)]
+fn _track_constraint(constraint: &mut AccountInfoConstraintCode, code: AccountInfoConstraintCode) {
+    #[cfg(any(test, all(feature = "debug-errors", not(feature = "alloc"))))]
+    {
+        *constraint = code;
+    }
+
+    #[cfg(not(any(test, all(feature = "debug-errors", not(feature = "alloc")))))]
+    let _ = (constraint, code);
+}
+
+#[inline(always)]
+fn _check_account_info_constraints(
     index: usize,
     account: &NoStdAccountInfo,
     AccountInfoConstraints {
         key,
         any_of_keys,
+        reject_keys,
         owner,
         any_of_owners,
+        program_data_account,
         seeds,
+        any_of_seeds,
         is_signer,
         is_writable,
         executable,
@@ -264,7 +470,9 @@ fn _process_enumerated_account_info(
         min_lamports,
         max_lamports,
     }: AccountInfoConstraints,
+    constraint: &mut AccountInfoConstraintCode,
 ) -> Result<(), ProgramError> {
+    _track_constraint(constraint, AccountInfoConstraintCode::Key);
     if let Some(key) = key {
         if account.key() != key {
             #[cfg(feature = "alloc")]
@@ -281,6 +489,7 @@ fn _process_enumerated_account_info(
         }
     }
 
+    _track_constraint(constraint, AccountInfoConstraintCode::AnyOfKeys);
     if let Some(any_of_keys) = any_of_keys {
         if !any_of_keys.contains(&account.key()) {
             #[cfg(feature = "alloc")]
@@ -298,6 +507,24 @@ fn _process_enumerated_account_info(
         }
     }
 
+    _track_constraint(constraint, AccountInfoConstraintCode::RejectKeys);
+    if let Some(reject_keys) = reject_keys {
+        if reject_keys.contains(&account.key()) {
+            #[cfg(feature = "alloc")]
+            return Err(SealevelToolsError::AccountInfo(&[
+                format!("Account index {}: Key not allowed...", index).as_str(),
+                format!("  Found: {}", account.key()).as_str(),
+                format!("  Rejected keys: {:?}", reject_keys).as_str(),
+            ])
+            .into());
+            #[cfg(not(feature = "alloc"))]
+            return Err(
+                SealevelToolsError::AccountInfo(&["Account matches one of rejected keys"]).into(),
+            );
+        }
+    }
+
+    _track_constraint(constraint, AccountInfoConstraintCode::Owner);
     if let Some(owner) = owner {
         if account.owner() != owner {
             #[cfg(feature = "alloc")]
@@ -315,6 +542,7 @@ fn _process_enumerated_account_info(
         }
     }
 
+    _track_constraint(constraint, AccountInfoConstraintCode::AnyOfOwners);
     if let Some(any_of_owners) = any_of_owners {
         if !any_of_owners.contains(&account.owner()) {
             #[cfg(feature = "alloc")]
@@ -332,6 +560,42 @@ fn _process_enumerated_account_info(
         }
     }
 
+    _track_constraint(constraint, AccountInfoConstraintCode::ProgramDataAccount);
+    if let Some(program_data_account) = program_data_account {
+        if let Some(reason) = _check_program_data_account(account, &program_data_account)? {
+            #[cfg(feature = "alloc")]
+            return Err(SealevelToolsError::AccountInfo(&[
+                format!(
+                    "Account index {}: Not a valid program data account...",
+                    index
+                )
+                .as_str(),
+                format!("  Reason: {}", reason).as_str(),
+                format!(
+                    "  Found owner: {}, data length: {}",
+                    account.owner(),
+                    account.data_len()
+                )
+                .as_str(),
+                format!(
+                    "  Expected owner: {}, data length: {}..={}",
+                    program_data_account.program_id,
+                    program_data_account.min_len,
+                    program_data_account.max_len
+                )
+                .as_str(),
+            ])
+            .into());
+            #[cfg(not(feature = "alloc"))]
+            return Err(SealevelToolsError::AccountInfo(&[
+                "Account is not a valid program data account",
+                reason,
+            ])
+            .into());
+        }
+    }
+
+    _track_constraint(constraint, AccountInfoConstraintCode::Seeds);
     if let Some((seeds, owner)) = seeds {
         let (expected_key, _) = Pubkey::find_program_address(seeds, owner);
 
@@ -350,6 +614,32 @@ fn _process_enumerated_account_info(
         }
     }
 
+    _track_constraint(constraint, AccountInfoConstraintCode::AnyOfSeeds);
+    if let Some(any_of_seeds) = any_of_seeds {
+        if !any_of_seeds
+            .iter()
+            .any(|(seeds, owner)| *account.key() == Pubkey::find_program_address(seeds, owner).0)
+        {
+            #[cfg(feature = "alloc")]
+            return Err(SealevelToolsError::AccountInfo(&[
+                format!("Account index {}: PDA key mismatch...", index).as_str(),
+                format!("  Found: {}", account.key()).as_str(),
+                format!(
+                    "  Expected derivation from one of {} sets of seeds",
+                    any_of_seeds.len()
+                )
+                .as_str(),
+            ])
+            .into());
+            #[cfg(not(feature = "alloc"))]
+            return Err(SealevelToolsError::AccountInfo(&[
+                "Account does not match one of derived keys",
+            ])
+            .into());
+        }
+    }
+
+    _track_constraint(constraint, AccountInfoConstraintCode::IsSigner);
     if let Some(is_signer) = is_signer {
         if account.is_signer() != is_signer {
             #[cfg(feature = "alloc")]
@@ -377,6 +667,7 @@ fn _process_enumerated_account_info(
         }
     }
 
+    _track_constraint(constraint, AccountInfoConstraintCode::IsWritable);
     if let Some(is_writable) = is_writable {
         if account.is_writable() != is_writable {
             #[cfg(feature = "alloc")]
@@ -404,6 +695,7 @@ fn _process_enumerated_account_info(
         }
     }
 
+    _track_constraint(constraint, AccountInfoConstraintCode::Executable);
     if let Some(executable) = executable {
         if account.executable() != executable {
             #[cfg(feature = "alloc")]
@@ -431,6 +723,7 @@ fn _process_enumerated_account_info(
         }
     }
 
+    _track_constraint(constraint, AccountInfoConstraintCode::ExactDataLen);
     if let Some(exact_data_len) = exact_data_len {
         let data_len = account.data_len();
 
@@ -450,6 +743,7 @@ fn _process_enumerated_account_info(
         }
     }
 
+    _track_constraint(constraint, AccountInfoConstraintCode::MinDataLen);
     if let Some(min_data_len) = min_data_len {
         let data_len = account.data_len();
 
@@ -469,6 +763,7 @@ fn _process_enumerated_account_info(
         }
     }
 
+    _track_constraint(constraint, AccountInfoConstraintCode::MaxDataLen);
     if let Some(max_data_len) = max_data_len {
         let data_len = account.data_len();
 
@@ -488,6 +783,7 @@ fn _process_enumerated_account_info(
         }
     }
 
+    _track_constraint(constraint, AccountInfoConstraintCode::MatchDataSlice);
     if let Some(MatchDataSlice { offset, data }) = match_data_slice {
         let account_data = account.try_borrow_data()?;
         let end: usize = offset
@@ -537,6 +833,7 @@ fn _process_enumerated_account_info(
         }
     }
 
+    _track_constraint(constraint, AccountInfoConstraintCode::MinLamports);
     if let Some(min_lamports) = min_lamports {
         let lamports = *account.try_borrow_lamports()?;
 
@@ -556,6 +853,7 @@ fn _process_enumerated_account_info(
         }
     }
 
+    _track_constraint(constraint, AccountInfoConstraintCode::MaxLamports);
     if let Some(max_lamports) = max_lamports {
         let lamports = *account.try_borrow_lamports()?;
 
@@ -580,3 +878,309 @@ fn _process_enumerated_account_info(
 
     Ok(())
 }
+
+/// Returns the reason the account violates the program data account constraint, if any.
+#[inline(always)]
+fn _check_program_data_account(
+    account: &NoStdAccountInfo,
+    ProgramDataAccountConstraint {
+        program_id,
+        min_len,
+        max_len,
+        expected_discriminator,
+    }: &ProgramDataAccountConstraint,
+) -> Result<Option<&'static str>, ProgramError> {
+    let data_len = account.data_len();
+
+    if account.owner() != *program_id {
+        Ok(Some("Owner mismatch"))
+    } else if data_len < *min_len || data_len > *max_len {
+        Ok(Some("Data length out of range"))
+    } else if let Some(discriminator) = expected_discriminator {
+        Ok((!account.try_borrow_data()?.starts_with(discriminator))
+            .then_some("Discriminator mismatch"))
+    } else {
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_fixture::{TestAccount, TestAccountInfos};
+
+    use super::*;
+
+    #[test]
+    fn test_check_account_info_constraints_code() {
+        let owner = Pubkey::new_unique();
+        let other_owner = Pubkey::new_unique();
+        let infos = TestAccountInfos::new(&[TestAccount::new(Pubkey::new_unique(), owner)]);
+
+        let check = |constraints| {
+            let mut constraint = AccountInfoConstraintCode::Key;
+            _check_account_info_constraints(0, &infos[0], constraints, &mut constraint)
+                .map_err(|_| constraint)
+        };
+
+        assert_eq!(
+            check(AccountInfoConstraints {
+                owner: Some(&owner),
+                is_signer: Some(false),
+                ..Default::default()
+            }),
+            Ok(())
+        );
+        assert_eq!(
+            check(AccountInfoConstraints {
+                owner: Some(&other_owner),
+                ..Default::default()
+            }),
+            Err(AccountInfoConstraintCode::Owner)
+        );
+        assert_eq!(
+            check(AccountInfoConstraints {
+                owner: Some(&owner),
+                is_signer: Some(true),
+                ..Default::default()
+            }),
+            Err(AccountInfoConstraintCode::IsSigner)
+        );
+        assert_eq!(
+            check(AccountInfoConstraints {
+                min_lamports: Some(1),
+                ..Default::default()
+            }),
+            Err(AccountInfoConstraintCode::MinLamports)
+        );
+    }
+
+    #[test]
+    fn test_check_account_info_constraints_reject_special_keys() {
+        let owner = Pubkey::new_unique();
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(crate::account::incinerator::ID, owner).writable(),
+            TestAccount::new(DEFAULT_PUBKEY, owner).writable(),
+            TestAccount::new(Pubkey::new_unique(), owner).writable(),
+        ]);
+
+        let check = |index: usize| {
+            let mut constraint = AccountInfoConstraintCode::Key;
+            _check_account_info_constraints(
+                index,
+                &infos[index],
+                AccountInfoConstraints {
+                    reject_keys: Some(SPECIAL_KEYS),
+                    ..Default::default()
+                },
+                &mut constraint,
+            )
+            .map_err(|err| (err, constraint))
+        };
+
+        // Incinerator.
+        assert_eq!(
+            check(0),
+            Err((
+                ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO),
+                AccountInfoConstraintCode::RejectKeys
+            ))
+        );
+
+        // Default pubkey.
+        assert_eq!(
+            check(1),
+            Err((
+                ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO),
+                AccountInfoConstraintCode::RejectKeys
+            ))
+        );
+
+        // Any other key.
+        assert_eq!(check(2), Ok(()));
+    }
+
+    #[test]
+    fn test_check_program_data_account() {
+        let program_id = Pubkey::new_unique();
+        let data = [1, 2, 3, 4, 5, 6, 7, 8];
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(Pubkey::new_unique(), program_id).data(&data),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique()).data(&data),
+        ]);
+
+        let constraint = || ProgramDataAccountConstraint {
+            program_id: &program_id,
+            min_len: 4,
+            max_len: 8,
+            expected_discriminator: Some(&[1, 2, 3, 4]),
+        };
+
+        assert_eq!(
+            _check_program_data_account(&infos[0], &constraint()),
+            Ok(None)
+        );
+
+        // Fails the composite constraint as a whole.
+        let mut iter = infos.iter().enumerate();
+        assert_eq!(
+            try_next_enumerated_account_info(
+                &mut iter,
+                AccountInfoConstraints {
+                    program_data_account: Some(constraint()),
+                    ..Default::default()
+                }
+            )
+            .map(|(index, _)| index),
+            Ok(0)
+        );
+        assert_eq!(
+            try_next_enumerated_account_info(
+                &mut iter,
+                AccountInfoConstraints {
+                    program_data_account: Some(constraint()),
+                    ..Default::default()
+                }
+            )
+            .err(),
+            Some(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+    }
+
+    #[test]
+    fn test_check_program_data_account_owner_mismatch() {
+        let program_id = Pubkey::new_unique();
+        let infos =
+            TestAccountInfos::new(&[
+                TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique()).data(&[1, 2, 3, 4])
+            ]);
+
+        assert_eq!(
+            _check_program_data_account(
+                &infos[0],
+                &ProgramDataAccountConstraint {
+                    program_id: &program_id,
+                    min_len: 0,
+                    max_len: 8,
+                    expected_discriminator: None,
+                }
+            ),
+            Ok(Some("Owner mismatch"))
+        );
+    }
+
+    #[test]
+    fn test_check_program_data_account_data_len_out_of_range() {
+        let program_id = Pubkey::new_unique();
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(Pubkey::new_unique(), program_id).data(&[1, 2, 3, 4])
+        ]);
+
+        let check = |min_len, max_len| {
+            _check_program_data_account(
+                &infos[0],
+                &ProgramDataAccountConstraint {
+                    program_id: &program_id,
+                    min_len,
+                    max_len,
+                    expected_discriminator: None,
+                },
+            )
+        };
+
+        // Bounds are inclusive.
+        assert_eq!(check(4, 4), Ok(None));
+
+        // Data length less than minimum.
+        assert_eq!(check(5, 8), Ok(Some("Data length out of range")));
+
+        // Data length greater than maximum.
+        assert_eq!(check(0, 3), Ok(Some("Data length out of range")));
+    }
+
+    #[test]
+    fn test_check_program_data_account_discriminator_mismatch() {
+        let program_id = Pubkey::new_unique();
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(Pubkey::new_unique(), program_id).data(&[1, 2, 3, 4])
+        ]);
+
+        let check = |expected_discriminator| {
+            _check_program_data_account(
+                &infos[0],
+                &ProgramDataAccountConstraint {
+                    program_id: &program_id,
+                    min_len: 0,
+                    max_len: 8,
+                    expected_discriminator: Some(expected_discriminator),
+                },
+            )
+        };
+
+        assert_eq!(check(&[1, 2]), Ok(None));
+        assert_eq!(check(&[2, 1]), Ok(Some("Discriminator mismatch")));
+
+        // Discriminator longer than the account data.
+        assert_eq!(check(&[1, 2, 3, 4, 5]), Ok(Some("Discriminator mismatch")));
+    }
+
+    #[test]
+    fn test_any_of_seeds() {
+        let program_id = Pubkey::new_unique();
+        let other_program_id = Pubkey::new_unique();
+
+        let (vault_a, _) = Pubkey::find_program_address(&[b"vault", b"a"], &program_id);
+        let (vault_b, _) = Pubkey::find_program_address(&[b"vault", b"b"], &other_program_id);
+
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(vault_a, program_id),
+            TestAccount::new(vault_b, other_program_id),
+            // Seeds match, but derived with the wrong owner.
+            TestAccount::new(
+                Pubkey::find_program_address(&[b"vault", b"a"], &other_program_id).0,
+                other_program_id,
+            ),
+            TestAccount::new(Pubkey::new_unique(), program_id),
+        ]);
+
+        let any_of_seeds: &[(&[&[u8]], &Pubkey)] = &[
+            (&[b"vault", b"a"], &program_id),
+            (&[b"vault", b"b"], &other_program_id),
+        ];
+        let constraints = || AccountInfoConstraints {
+            any_of_seeds: Some(any_of_seeds),
+            ..Default::default()
+        };
+
+        let mut iter = infos.iter().enumerate();
+
+        assert_eq!(
+            try_next_enumerated_account_info(&mut iter, constraints())
+                .map(|(index, account)| (index, *account.key())),
+            Ok((0, vault_a))
+        );
+        assert_eq!(
+            try_next_enumerated_account_info(&mut iter, constraints())
+                .map(|(index, account)| (index, *account.key())),
+            Ok((1, vault_b))
+        );
+        for _ in 2..4 {
+            assert_eq!(
+                try_next_enumerated_account_info(&mut iter, constraints()).err(),
+                Some(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+            );
+        }
+
+        // No sets of seeds match any account.
+        assert_eq!(
+            try_next_enumerated_account_info(
+                &mut infos.iter().enumerate(),
+                AccountInfoConstraints {
+                    any_of_seeds: Some(&[]),
+                    ..Default::default()
+                }
+            )
+            .err(),
+            Some(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{entrypoint::NoStdAccountInfo, pubkey::Pubkey};
+
+/// Find the value associated with the account's owner in a dispatch table. Returns [None] if no
+/// case matches. Cases are checked in order, so the first matching owner wins.
+///
+/// This method only reads the account's owner, so the account's data is not borrowed.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account::{legacy_token, token_extensions},
+///     account_info::{match_owner, NoStdAccountInfo},
+///     program_error::ProgramError,
+/// };
+///
+/// enum TokenProgram {
+///     Legacy,
+///     Extensions,
+/// }
+///
+/// fn token_program_of(account: &NoStdAccountInfo) -> Result<&TokenProgram, ProgramError> {
+///     match_owner(
+///         account,
+///         &[
+///             (&legacy_token::ID, TokenProgram::Legacy),
+///             (&token_extensions::ID, TokenProgram::Extensions),
+///         ],
+///     )
+///     .ok_or(ProgramError::InvalidAccountOwner)
+/// }
+/// ```
+#[inline(always)]
+pub fn match_owner<'a, T>(account: &NoStdAccountInfo, cases: &'a [(&Pubkey, T)]) -> Option<&'a T> {
+    _match_owner_key(account.owner(), cases)
+}
+
+#[inline(always)]
+fn _match_owner_key<'a, T>(owner: &Pubkey, cases: &'a [(&Pubkey, T)]) -> Option<&'a T> {
+    cases
+        .iter()
+        .find_map(|(case_owner, value)| (*case_owner == owner).then_some(value))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_match_owner_key() {
+        let owner_a = Pubkey::new_unique();
+        let owner_b = Pubkey::new_unique();
+        let cases = [(&owner_a, 1), (&owner_b, 2), (&owner_a, 3)];
+
+        assert_eq!(_match_owner_key(&owner_a, &cases), Some(&1));
+        assert_eq!(_match_owner_key(&owner_b, &cases), Some(&2));
+        assert_eq!(_match_owner_key(&Pubkey::new_unique(), &cases), None);
+    }
+}
@@ -0,0 +1,98 @@
+#[cfg(feature = "alloc")]
+use alloc::format;
+
+use crate::{
+    entrypoint::{NoStdAccountInfo, ProgramResult},
+    error::SealevelToolsError,
+    pubkey::Pubkey,
+};
+
+/// Assert that the accounts contain an executable account with the given program ID. This check is
+/// useful when a program account is not taken explicitly (e.g. the System program when creating an
+/// account), so a missing program account fails fast with a clear message instead of an opaque CPI
+/// failure.
+///
+/// If the program is not found, a custom program error code with
+/// [SealevelToolsError::ACCOUNT_INFO] is returned.
+///
+/// ### Notes
+///
+/// This check scans the accounts linearly, so its cost grows with the number of accounts. If the
+/// program account's position is known, use [try_next_enumerated_account] with [Program] instead.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account::system,
+///     account_info::assert_program_present,
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+/// };
+///
+/// fn process_instruction(accounts: &[NoStdAccountInfo]) -> ProgramResult {
+///     // System program is needed for CPI, but it can be anywhere in the accounts.
+///     assert_program_present(accounts, &system::ID)?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [Program]: crate::account_info::Program
+/// [try_next_enumerated_account]: crate::account_info::try_next_enumerated_account
+#[inline(always)]
+pub fn assert_program_present(accounts: &[NoStdAccountInfo], program_id: &Pubkey) -> ProgramResult {
+    if _is_program_present(
+        accounts
+            .iter()
+            .map(|account| (account.key(), account.executable())),
+        program_id,
+    ) {
+        return Ok(());
+    }
+
+    #[cfg(feature = "alloc")]
+    return Err(SealevelToolsError::AccountInfo(&[
+        "Expected program account not found...",
+        format!("  Program: {}", program_id).as_str(),
+    ])
+    .into());
+    #[cfg(not(feature = "alloc"))]
+    return Err(SealevelToolsError::AccountInfo(&["Expected program account not found"]).into());
+}
+
+#[inline(always)]
+fn _is_program_present<'a>(
+    mut accounts: impl Iterator<Item = (&'a Pubkey, bool)>,
+    program_id: &Pubkey,
+) -> bool {
+    accounts.any(|(key, executable)| executable && key == program_id)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_is_program_present() {
+        let program_id = Pubkey::new_unique();
+        let other_id = Pubkey::new_unique();
+
+        assert!(_is_program_present(
+            [(&other_id, true), (&program_id, true)].into_iter(),
+            &program_id
+        ));
+
+        // Not executable.
+        assert!(!_is_program_present(
+            [(&other_id, true), (&program_id, false)].into_iter(),
+            &program_id
+        ));
+
+        // Not found.
+        assert!(!_is_program_present(
+            [(&other_id, true)].into_iter(),
+            &program_id
+        ));
+        assert!(!_is_program_present([].into_iter(), &program_id));
+    }
+}
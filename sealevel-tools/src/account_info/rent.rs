@@ -0,0 +1,89 @@
+#[cfg(feature = "alloc")]
+use alloc::format;
+
+use crate::{entrypoint::ProgramResult, error::SealevelToolsError};
+
+use super::NoStdAccountInfo;
+
+/// Assert that the account's lamports cover the rent-exempt minimum for `new_size` bytes. This
+/// method is a safety net for manual realloc flows (e.g. growing a stored list), where the lamport
+/// top-up logic might be wrong.
+///
+/// ### Notes
+///
+/// This method only checks lamports. It does not resize the account, so it can be called either
+/// before resizing (after topping up lamports) or after resizing (with the account's new data
+/// length). This method requires loading [Rent] via syscall.
+///
+/// If the account's lamports are below the rent-exempt minimum, a custom program error code with
+/// [SealevelToolsError::ACCOUNT_INFO] is returned. With the "alloc" feature enabled, the logged
+/// error includes the shortfall.
+///
+/// [Rent]: solana_rent::Rent
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account_info::{assert_rent_exempt_after, try_next_enumerated_account, WritableAccount},
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+/// };
+///
+/// fn append_element(accounts: &[NoStdAccountInfo], element_len: usize) -> ProgramResult {
+///     let mut accounts_iter = accounts.iter().enumerate();
+///
+///     // First account is the list, which is owned by this program.
+///     let (_, list) =
+///         try_next_enumerated_account::<WritableAccount>(&mut accounts_iter, Default::default())?;
+///
+///     let new_size = list.data_len().saturating_add(element_len);
+///
+///     // ... top up lamports ...
+///
+///     assert_rent_exempt_after(&list, new_size)?;
+///     list.realloc(new_size, false)
+/// }
+/// ```
+#[inline(always)]
+pub fn assert_rent_exempt_after(account: &NoStdAccountInfo, new_size: usize) -> ProgramResult {
+    _assert_covers_rent(
+        *account.try_borrow_lamports()?,
+        crate::sysvar::get_rent_minimum_balance(new_size),
+    )
+}
+
+#[inline(always)]
+fn _assert_covers_rent(lamports: u64, minimum_balance: u64) -> ProgramResult {
+    if lamports >= minimum_balance {
+        return Ok(());
+    }
+
+    #[cfg(feature = "alloc")]
+    return Err(SealevelToolsError::AccountInfo(&[
+        "Account lamports below rent-exempt minimum for new size...",
+        format!("  Shortfall: {}", minimum_balance - lamports).as_str(),
+    ])
+    .into());
+    #[cfg(not(feature = "alloc"))]
+    return Err(SealevelToolsError::AccountInfo(&[
+        "Account lamports below rent-exempt minimum for new size",
+    ])
+    .into());
+}
+
+#[cfg(test)]
+mod test {
+    use crate::program_error::ProgramError;
+
+    use super::*;
+
+    #[test]
+    fn test_assert_covers_rent() {
+        assert_eq!(_assert_covers_rent(1_000, 1_000), Ok(()));
+        assert_eq!(_assert_covers_rent(1_001, 1_000), Ok(()));
+        assert_eq!(
+            _assert_covers_rent(999, 1_000),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+    }
+}
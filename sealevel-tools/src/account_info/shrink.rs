@@ -0,0 +1,118 @@
+use crate::{entrypoint::ProgramResult, error::SealevelToolsError};
+
+use super::{try_withdraw_lamports_from_pda, Account};
+
+/// Shrink an account owned by the executing program to `new_size` bytes and move lamports in
+/// excess of the rent-exempt minimum for its new size to the beneficiary. This method is useful
+/// when account data shrinks (e.g. removing elements from a stored list), which leaves the account
+/// over-collateralized.
+///
+/// ### Notes
+///
+/// The refund is the account's lamports minus the rent-exempt minimum for `new_size`, so the
+/// account remains rent-exempt after the refund. If the account held more than the rent-exempt
+/// minimum for its old size, those extra lamports are refunded as well. This method requires
+/// loading [Rent] via syscall.
+///
+/// If `new_size` is larger than the account's current data length, a custom program error code with
+/// [SealevelToolsError::ACCOUNT_INFO] is returned.
+///
+/// [Rent]: solana_rent::Rent
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account_info::{try_next_enumerated_account, try_shrink_and_refund, WritableAccount},
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+/// };
+///
+/// fn remove_last_element(accounts: &[NoStdAccountInfo], element_len: usize) -> ProgramResult {
+///     let mut accounts_iter = accounts.iter().enumerate();
+///
+///     // First account is the list, which is owned by this program.
+///     let (_, list) =
+///         try_next_enumerated_account::<WritableAccount>(&mut accounts_iter, Default::default())?;
+///
+///     // Second account receives the refunded lamports.
+///     let (_, beneficiary) =
+///         try_next_enumerated_account::<WritableAccount>(&mut accounts_iter, Default::default())?;
+///
+///     let new_size = list.data_len().saturating_sub(element_len);
+///     try_shrink_and_refund(&list, new_size, &beneficiary)
+/// }
+/// ```
+#[inline(always)]
+pub fn try_shrink_and_refund(
+    account: &Account<'_, true>,
+    new_size: usize,
+    beneficiary: &Account<'_, true>,
+) -> ProgramResult {
+    if new_size > account.data_len() {
+        return Err(SealevelToolsError::AccountInfo(&[
+            "New size exceeds current account data length",
+        ])
+        .into());
+    }
+
+    account.realloc(new_size, false)?;
+
+    let refund = _refund_lamports(
+        *account.try_borrow_lamports()?,
+        crate::sysvar::get_rent_minimum_balance(new_size),
+    );
+
+    if refund == 0 {
+        Ok(())
+    } else {
+        try_withdraw_lamports_from_pda(account, beneficiary, refund, false)
+    }
+}
+
+#[inline(always)]
+fn _refund_lamports(lamports: u64, minimum_balance: u64) -> u64 {
+    lamports.saturating_sub(minimum_balance)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        test_fixture::{TestAccount, TestAccountInfos},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_try_shrink_and_refund_new_size_exceeds_data_len() {
+        let data = [1; 16];
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique())
+                .lamports(1_000)
+                .data(&data)
+                .writable(),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::default()).writable(),
+        ]);
+        let account = Account::<true>::try_from(&infos[0]).unwrap();
+        let beneficiary = Account::<true>::try_from(&infos[1]).unwrap();
+
+        assert_eq!(
+            try_shrink_and_refund(&account, 17, &beneficiary),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+        assert_eq!(account.data_len(), 16);
+        assert_eq!(*account.try_borrow_lamports().unwrap(), 1_000);
+        assert_eq!(*beneficiary.try_borrow_lamports().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_refund_lamports() {
+        // Over-collateralized for the new size.
+        assert_eq!(_refund_lamports(2_000, 1_500), 500);
+
+        // Exactly rent-exempt or under-collateralized.
+        assert_eq!(_refund_lamports(1_500, 1_500), 0);
+        assert_eq!(_refund_lamports(1_000, 1_500), 0);
+    }
+}
@@ -0,0 +1,108 @@
+#[cfg(feature = "alloc")]
+use alloc::format;
+
+use crate::{
+    entrypoint::{NoStdAccountInfo, ProgramResult},
+    error::SealevelToolsError,
+    pubkey::Pubkey,
+};
+
+/// Assert that the first signer found in the given accounts has the expected key. By convention,
+/// the fee payer is the first signer of a transaction, so this check is a best-effort way of
+/// requiring a particular account (e.g. a protocol's relayer) to have signed first.
+///
+/// If no account is a signer or the first signer's key does not match, a custom program error code
+/// with [SealevelToolsError::ACCOUNT_INFO] is returned, as well as a program log indicating the
+/// first signer's key.
+///
+/// ### Notes
+///
+/// This method only sees accounts passed into the instruction. The transaction's fee payer is not
+/// necessarily one of these accounts (and a program cannot read it directly), so this check does
+/// not guarantee that the expected account paid the transaction fee. It only guarantees that the
+/// expected account signed and is the first signer among the instruction's accounts.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account_info::assert_first_signer_is,
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+///     pubkey::Pubkey,
+/// };
+///
+/// const RELAYER: Pubkey = Pubkey::new_from_array([1; 32]);
+///
+/// fn process_instruction(accounts: &[NoStdAccountInfo]) -> ProgramResult {
+///     assert_first_signer_is(accounts, &RELAYER)?;
+///
+///     Ok(())
+/// }
+/// ```
+#[inline(always)]
+pub fn assert_first_signer_is(accounts: &[NoStdAccountInfo], expected: &Pubkey) -> ProgramResult {
+    let first_signer = _find_first_signer_key(
+        accounts
+            .iter()
+            .map(|account| (account.key(), account.is_signer())),
+    );
+
+    match first_signer {
+        Some(key) if key == expected => Ok(()),
+        Some(key) => {
+            #[cfg(feature = "alloc")]
+            return Err(SealevelToolsError::AccountInfo(&[
+                "First signer mismatch...",
+                format!("  Found: {}", key).as_str(),
+                format!("  Expected: {}", expected).as_str(),
+            ])
+            .into());
+            #[cfg(not(feature = "alloc"))]
+            {
+                let _ = key;
+                Err(
+                    SealevelToolsError::AccountInfo(&["First signer does not match expected key"])
+                        .into(),
+                )
+            }
+        }
+        None => Err(SealevelToolsError::AccountInfo(&["No signer found in accounts"]).into()),
+    }
+}
+
+#[inline(always)]
+fn _find_first_signer_key<'a>(
+    mut accounts: impl Iterator<Item = (&'a Pubkey, bool)>,
+) -> Option<&'a Pubkey> {
+    accounts.find_map(|(key, is_signer)| is_signer.then_some(key))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_find_first_signer_key() {
+        let keys = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+
+        assert_eq!(
+            _find_first_signer_key(keys.iter().zip([false, true, true])),
+            Some(&keys[1])
+        );
+        assert_eq!(
+            _find_first_signer_key(keys.iter().zip([true, false, true])),
+            Some(&keys[0])
+        );
+
+        // No signers.
+        assert_eq!(
+            _find_first_signer_key(keys.iter().zip([false, false, false])),
+            None
+        );
+        assert_eq!(_find_first_signer_key(core::iter::empty()), None);
+    }
+}
@@ -0,0 +1,184 @@
+use crate::{
+    entrypoint::ProgramResult, error::SealevelToolsError, program_error::ProgramError,
+};
+
+use super::Account;
+
+/// Withdraw lamports from an account owned by the executing program (e.g. a PDA holding SOL) by
+/// debiting its lamports and crediting the recipient directly.
+///
+/// ### Notes
+///
+/// The System program's transfer instruction requires that the source account be owned by the
+/// System program. An account owned by your program cannot be the source of this transfer (even if
+/// your program signs for it with its PDA seeds). Instead, the runtime allows the owning program to
+/// debit lamports from its account directly, which is what this method does.
+///
+/// If `keep_rent_exempt` is true, this method will fail if the withdrawal leaves the source account
+/// with fewer lamports than its rent-exempt minimum (based on its current data length), which
+/// requires loading [Rent] via syscall. Pass in false if you intend to drain the account (e.g. when
+/// closing it).
+///
+/// [Rent]: solana_rent::Rent
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account_info::{
+///         try_next_enumerated_account, try_withdraw_lamports_from_pda, WritableAccount,
+///     },
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+/// };
+///
+/// fn withdraw(accounts: &[NoStdAccountInfo], amount: u64) -> ProgramResult {
+///     let mut accounts_iter = accounts.iter().enumerate();
+///
+///     // First account is the vault, which is owned by this program.
+///     let (_, vault) =
+///         try_next_enumerated_account::<WritableAccount>(&mut accounts_iter, Default::default())?;
+///
+///     // Second account is the recipient.
+///     let (_, recipient) =
+///         try_next_enumerated_account::<WritableAccount>(&mut accounts_iter, Default::default())?;
+///
+///     try_withdraw_lamports_from_pda(&vault, &recipient, amount, true)
+/// }
+/// ```
+#[inline(always)]
+pub fn try_withdraw_lamports_from_pda(
+    pda: &Account<'_, true>,
+    to: &Account<'_, true>,
+    amount: u64,
+    keep_rent_exempt: bool,
+) -> ProgramResult {
+    let mut pda_lamports = pda.try_borrow_mut_lamports()?;
+
+    let remaining = pda_lamports
+        .checked_sub(amount)
+        .ok_or(ProgramError::InsufficientFunds)?;
+
+    if keep_rent_exempt {
+        _check_rent_exempt_remaining(
+            remaining,
+            crate::sysvar::get_rent_minimum_balance(pda.data_len()),
+        )?;
+    }
+
+    let mut to_lamports = to.try_borrow_mut_lamports()?;
+
+    *to_lamports = to_lamports
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    *pda_lamports = remaining;
+
+    Ok(())
+}
+
+#[inline(always)]
+fn _check_rent_exempt_remaining(remaining: u64, minimum_balance: u64) -> ProgramResult {
+    if remaining < minimum_balance {
+        Err(SealevelToolsError::AccountInfo(&[
+            "Withdrawal would leave account below rent-exempt minimum",
+        ])
+        .into())
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        pubkey::Pubkey,
+        test_fixture::{TestAccount, TestAccountInfos},
+    };
+
+    use super::*;
+
+    fn vault_and_recipient(vault_lamports: u64, recipient_lamports: u64) -> TestAccountInfos {
+        TestAccountInfos::new(&[
+            TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique())
+                .lamports(vault_lamports)
+                .writable(),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::default())
+                .lamports(recipient_lamports)
+                .writable(),
+        ])
+    }
+
+    #[test]
+    fn test_try_withdraw_lamports_from_pda() {
+        let infos = vault_and_recipient(1_000, 0);
+        let vault = Account::<true>::try_from(&infos[0]).unwrap();
+        let recipient = Account::<true>::try_from(&infos[1]).unwrap();
+
+        assert_eq!(
+            try_withdraw_lamports_from_pda(&vault, &recipient, 400, false),
+            Ok(())
+        );
+        assert_eq!(*vault.try_borrow_lamports().unwrap(), 600);
+        assert_eq!(*recipient.try_borrow_lamports().unwrap(), 400);
+
+        // Drain the account.
+        assert_eq!(
+            try_withdraw_lamports_from_pda(&vault, &recipient, 600, false),
+            Ok(())
+        );
+        assert_eq!(*vault.try_borrow_lamports().unwrap(), 0);
+        assert_eq!(*recipient.try_borrow_lamports().unwrap(), 1_000);
+    }
+
+    #[test]
+    fn test_try_withdraw_lamports_from_pda_insufficient_funds() {
+        let infos = vault_and_recipient(1_000, 0);
+        let vault = Account::<true>::try_from(&infos[0]).unwrap();
+        let recipient = Account::<true>::try_from(&infos[1]).unwrap();
+
+        assert_eq!(
+            try_withdraw_lamports_from_pda(&vault, &recipient, 1_001, false),
+            Err(ProgramError::InsufficientFunds)
+        );
+        assert_eq!(*vault.try_borrow_lamports().unwrap(), 1_000);
+        assert_eq!(*recipient.try_borrow_lamports().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_try_withdraw_lamports_from_pda_overflow() {
+        let infos = vault_and_recipient(1_000, u64::MAX);
+        let vault = Account::<true>::try_from(&infos[0]).unwrap();
+        let recipient = Account::<true>::try_from(&infos[1]).unwrap();
+
+        assert_eq!(
+            try_withdraw_lamports_from_pda(&vault, &recipient, 1, false),
+            Err(ProgramError::ArithmeticOverflow)
+        );
+        assert_eq!(*vault.try_borrow_lamports().unwrap(), 1_000);
+    }
+
+    #[test]
+    fn test_try_withdraw_lamports_from_pda_to_itself() {
+        let infos = vault_and_recipient(1_000, 0);
+        let vault = Account::<true>::try_from(&infos[0]).unwrap();
+
+        // Same account passed in twice.
+        let duplicate = infos[0].clone();
+        let to = Account::<true>::try_from(&duplicate).unwrap();
+
+        assert_eq!(
+            try_withdraw_lamports_from_pda(&vault, &to, 400, false),
+            Err(ProgramError::AccountBorrowFailed)
+        );
+        assert_eq!(*vault.try_borrow_lamports().unwrap(), 1_000);
+    }
+
+    #[test]
+    fn test_check_rent_exempt_remaining() {
+        assert_eq!(_check_rent_exempt_remaining(1_000, 1_000), Ok(()));
+        assert_eq!(_check_rent_exempt_remaining(1_001, 1_000), Ok(()));
+        assert_eq!(
+            _check_rent_exempt_remaining(999, 1_000),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        );
+    }
+}
@@ -0,0 +1,61 @@
+//! Checked arithmetic for token amounts and balances.
+//!
+//! The Token programs enforce their own math. But programs that track balances themselves (e.g.
+//! virtual balances in a vault) should use these methods so that overflows and insufficient
+//! balances consistently result in a [ProgramError].
+
+use crate::program_error::ProgramError;
+
+/// Subtract an amount from a balance. Returns [ProgramError::InsufficientFunds] if the amount
+/// exceeds the balance.
+#[inline(always)]
+pub fn try_sub_amount(balance: u64, amount: u64) -> Result<u64, ProgramError> {
+    balance
+        .checked_sub(amount)
+        .ok_or(ProgramError::InsufficientFunds)
+}
+
+/// Add an amount to a balance (or supply). Returns [ProgramError::ArithmeticOverflow] if the sum
+/// exceeds [u64::MAX].
+#[inline(always)]
+pub fn try_add_amount(balance: u64, amount: u64) -> Result<u64, ProgramError> {
+    balance
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_try_sub_amount() {
+        assert_eq!(try_sub_amount(69, 0), Ok(69));
+        assert_eq!(try_sub_amount(69, 69), Ok(0));
+        assert_eq!(try_sub_amount(u64::MAX, u64::MAX), Ok(0));
+        assert_eq!(try_sub_amount(u64::MAX, 1), Ok(u64::MAX - 1));
+        assert_eq!(
+            try_sub_amount(69, 70),
+            Err(ProgramError::InsufficientFunds)
+        );
+        assert_eq!(
+            try_sub_amount(0, u64::MAX),
+            Err(ProgramError::InsufficientFunds)
+        );
+    }
+
+    #[test]
+    fn test_try_add_amount() {
+        assert_eq!(try_add_amount(69, 0), Ok(69));
+        assert_eq!(try_add_amount(0, u64::MAX), Ok(u64::MAX));
+        assert_eq!(try_add_amount(u64::MAX - 1, 1), Ok(u64::MAX));
+        assert_eq!(
+            try_add_amount(u64::MAX, 1),
+            Err(ProgramError::ArithmeticOverflow)
+        );
+        assert_eq!(
+            try_add_amount(u64::MAX, u64::MAX),
+            Err(ProgramError::ArithmeticOverflow)
+        );
+    }
+}
@@ -0,0 +1,303 @@
+use core::mem::MaybeUninit;
+
+use crate::{
+    entrypoint::{AccountInfoC, AccountMetaC, NoStdAccountInfo, ProgramResult},
+    error::SealevelToolsError,
+    pubkey::Pubkey,
+};
+
+use super::CpiAuthority;
+
+const ERROR_CAPACITY_EXCEEDED: SealevelToolsError<'static> =
+    SealevelToolsError::Cpi(&["CPI accounts exceed fixed capacity"]);
+
+/// Fixed-capacity collection of [AccountMetaC] and [AccountInfoC] stored on the stack. This type is
+/// useful for variadic CPI calls (where the number of accounts is not known at compile time) when
+/// the "alloc" feature is disabled.
+///
+/// ### Notes
+///
+/// At most `CAP` accounts can be collected. Trying to push more accounts than this capacity returns
+/// a [SealevelToolsError::Cpi] error (as a custom program error code).
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     cpi::{CpiAccounts, CpiAuthority, CpiInstruction},
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+///     pubkey::Pubkey,
+/// };
+///
+/// fn invoke_variadic(
+///     program_id: &Pubkey,
+///     authority: &CpiAuthority,
+///     remaining_accounts: &[NoStdAccountInfo],
+///     data: &[u8],
+/// ) -> ProgramResult {
+///     let mut cpi_accounts = CpiAccounts::<8>::new();
+///     cpi_accounts.try_push_signer(authority)?;
+///     cpi_accounts.try_extend(remaining_accounts)?;
+///
+///     CpiInstruction {
+///         program_id,
+///         accounts: cpi_accounts.metas(),
+///         data,
+///     }
+///     .invoke_possibly_signed(cpi_accounts.infos(), &[authority.signer_seeds]);
+///
+///     Ok(())
+/// }
+/// ```
+pub struct CpiAccounts<const CAP: usize> {
+    metas: [MaybeUninit<AccountMetaC>; CAP],
+    infos: [MaybeUninit<AccountInfoC>; CAP],
+    len: usize,
+}
+
+impl<const CAP: usize> Default for CpiAccounts<CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const CAP: usize> CpiAccounts<CAP> {
+    /// Create an empty collection.
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self {
+            // Safety: An array of uninitialized elements does not require initialization.
+            metas: unsafe { MaybeUninit::uninit().assume_init() },
+            infos: unsafe { MaybeUninit::uninit().assume_init() },
+            len: 0,
+        }
+    }
+
+    /// Maximum number of accounts this collection can hold.
+    #[inline(always)]
+    pub const fn capacity(&self) -> usize {
+        CAP
+    }
+
+    /// Number of accounts collected.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether no accounts have been collected.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Try to push an account meta and its corresponding account info.
+    #[inline(always)]
+    pub fn try_push(&mut self, meta: AccountMetaC, info: AccountInfoC) -> ProgramResult {
+        if self.len == CAP {
+            return Err(ERROR_CAPACITY_EXCEEDED.into());
+        }
+
+        self.metas[self.len].write(meta);
+        self.infos[self.len].write(info);
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Try to push an account using its own signer and writable privileges.
+    #[inline(always)]
+    pub fn try_push_account(&mut self, account: &NoStdAccountInfo) -> ProgramResult {
+        self.try_push(account.to_meta_c(), account.to_info_c())
+    }
+
+    /// Try to push an authority, which is always flagged as a signer for the CPI call.
+    #[inline(always)]
+    pub fn try_push_signer(&mut self, authority: &CpiAuthority) -> ProgramResult {
+        self.try_push(authority.to_meta_c_signer(), authority.to_info_c())
+    }
+
+    /// Try to push all accounts in the slice. If the capacity is exceeded, no accounts from this
+    /// slice are pushed.
+    #[inline(always)]
+    pub fn try_extend(&mut self, accounts: &[NoStdAccountInfo]) -> ProgramResult {
+        if accounts.len() > CAP - self.len {
+            return Err(ERROR_CAPACITY_EXCEEDED.into());
+        }
+
+        accounts
+            .iter()
+            .try_for_each(|account| self.try_push_account(account))
+    }
+
+    /// Collected account metas, which can be used for [CpiInstruction::accounts].
+    ///
+    /// [CpiInstruction::accounts]: super::CpiInstruction::accounts
+    #[inline(always)]
+    pub fn metas(&self) -> &[AccountMetaC] {
+        // Safety: The first `len` elements have been initialized.
+        unsafe { core::slice::from_raw_parts(self.metas.as_ptr() as *const AccountMetaC, self.len) }
+    }
+
+    /// Collected account infos, which can be passed into an invoke method.
+    #[inline(always)]
+    pub fn infos(&self) -> &[AccountInfoC] {
+        // Safety: The first `len` elements have been initialized.
+        unsafe { core::slice::from_raw_parts(self.infos.as_ptr() as *const AccountInfoC, self.len) }
+    }
+}
+
+/// Create a read-only, non-signer [AccountMetaC] from only a pubkey. This is useful when building
+/// instruction accounts for [CpiInstruction::accounts] where the [NoStdAccountInfo] is not at hand
+/// (e.g. a program ID or sysvar referenced by key).
+///
+/// ### Notes
+///
+/// The runtime still requires an [AccountInfoC] for every account referenced by the instruction,
+/// so the corresponding account must be passed into your program and its info must be included in
+/// the infos passed into an invoke method. Otherwise the CPI call will fail.
+///
+/// The returned meta holds a raw pointer to the pubkey, so the pubkey must outlive the CPI call
+/// using this meta (e.g. do not create a meta from a temporary pubkey value).
+///
+/// [CpiInstruction::accounts]: super::CpiInstruction::accounts
+#[inline(always)]
+pub fn readonly_meta_c(key: &Pubkey) -> AccountMetaC {
+    AccountMetaC {
+        pubkey: key,
+        is_writable: false,
+        is_signer: false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        program_error::ProgramError,
+        test_fixture::{TestAccount, TestAccountInfos},
+    };
+
+    use super::*;
+
+    fn test_account_infos() -> TestAccountInfos {
+        TestAccountInfos::new(&[
+            TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique())
+                .signer()
+                .writable(),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique()).writable(),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique()),
+        ])
+    }
+
+    fn assert_accounts<const CAP: usize>(
+        cpi_accounts: &CpiAccounts<CAP>,
+        expected: &[NoStdAccountInfo],
+    ) {
+        assert_eq!(cpi_accounts.len(), expected.len());
+        assert_eq!(cpi_accounts.metas().len(), expected.len());
+        assert_eq!(cpi_accounts.infos().len(), expected.len());
+
+        for ((meta, info), account) in cpi_accounts
+            .metas()
+            .iter()
+            .zip(cpi_accounts.infos())
+            .zip(expected)
+        {
+            assert_eq!(unsafe { &*meta.pubkey }, account.key());
+            assert_eq!(meta.is_signer, account.is_signer());
+            assert_eq!(meta.is_writable, account.is_writable());
+
+            assert_eq!(unsafe { &*info.key }, account.key());
+            assert_eq!(unsafe { &*info.owner }, account.owner());
+            assert_eq!(info.is_signer, account.is_signer());
+            assert_eq!(info.is_writable, account.is_writable());
+        }
+    }
+
+    #[test]
+    fn test_try_push_account() {
+        let infos = test_account_infos();
+
+        let mut cpi_accounts = CpiAccounts::<3>::new();
+        assert!(cpi_accounts.is_empty());
+        assert_eq!(cpi_accounts.capacity(), 3);
+
+        for account in infos.iter() {
+            assert_eq!(cpi_accounts.try_push_account(account), Ok(()));
+        }
+        assert_accounts(&cpi_accounts, &infos);
+
+        // Capacity exceeded.
+        assert_eq!(
+            cpi_accounts.try_push_account(&infos[0]),
+            Err(ProgramError::Custom(SealevelToolsError::CPI))
+        );
+        assert_accounts(&cpi_accounts, &infos);
+    }
+
+    #[test]
+    fn test_try_push_signer() {
+        let infos = test_account_infos();
+
+        let mut cpi_accounts = CpiAccounts::<1>::new();
+        assert_eq!(
+            cpi_accounts.try_push_signer(&CpiAuthority::from_pda(&infos[2], &[b"pda"])),
+            Ok(())
+        );
+
+        // Read-only account is flagged as a signer for the CPI call.
+        let meta = &cpi_accounts.metas()[0];
+        assert_eq!(unsafe { &*meta.pubkey }, infos[2].key());
+        assert!(meta.is_signer);
+        assert!(!meta.is_writable);
+    }
+
+    #[test]
+    fn test_try_extend() {
+        let infos = test_account_infos();
+
+        let mut cpi_accounts = CpiAccounts::<4>::new();
+        assert_eq!(cpi_accounts.try_extend(&infos[..2]), Ok(()));
+        assert_accounts(&cpi_accounts, &infos[..2]);
+
+        // Only two of three accounts fit. None are pushed.
+        assert_eq!(
+            cpi_accounts.try_extend(&infos),
+            Err(ProgramError::Custom(SealevelToolsError::CPI))
+        );
+        assert_accounts(&cpi_accounts, &infos[..2]);
+
+        // Fill to capacity.
+        assert_eq!(cpi_accounts.try_extend(&infos[1..]), Ok(()));
+        assert_eq!(cpi_accounts.len(), 4);
+        assert_eq!(
+            cpi_accounts.try_extend(&infos[..1]),
+            Err(ProgramError::Custom(SealevelToolsError::CPI))
+        );
+        assert_eq!(cpi_accounts.try_extend(&[]), Ok(()));
+        assert_eq!(cpi_accounts.len(), 4);
+    }
+
+    #[test]
+    fn test_zero_capacity() {
+        let infos = test_account_infos();
+
+        let mut cpi_accounts = CpiAccounts::<0>::default();
+        assert_eq!(
+            cpi_accounts.try_push_account(&infos[0]),
+            Err(ProgramError::Custom(SealevelToolsError::CPI))
+        );
+        assert!(cpi_accounts.metas().is_empty());
+        assert!(cpi_accounts.infos().is_empty());
+    }
+
+    #[test]
+    fn test_readonly_meta_c() {
+        let key = Pubkey::new_unique();
+
+        let meta = readonly_meta_c(&key);
+        assert_eq!(unsafe { &*meta.pubkey }, &key);
+        assert!(!meta.is_signer);
+        assert!(!meta.is_writable);
+    }
+}
@@ -2,9 +2,14 @@ use alloc::vec::Vec;
 
 use solana_instruction::Instruction;
 
-use crate::entrypoint::{AccountInfoC, AccountMetaC, NoStdAccountInfo, ProgramResult};
+#[cfg(feature = "borsh")]
+use crate::{borsh::BorshSerialize, discriminator::build_instruction_data, pubkey::Pubkey};
+use crate::{
+    entrypoint::{AccountInfoC, AccountMetaC, NoStdAccountInfo, ProgramResult},
+    program_error::ProgramError,
+};
 
-use super::{try_check_borrow_account_info, CpiInstruction};
+use super::{try_check_borrow_account_info, CpiAccounts, CpiInstruction};
 
 /// Similar to [invoke_signed](solana_cpi::invoke_signed). This method performs [invoke_signed_c]
 /// under the hood for a given [Instruction]. This method is useful for SDKs that generate
@@ -75,3 +80,274 @@ pub fn try_invoke_signed(
     );
     Ok(())
 }
+
+/// Invoke a standard [Instruction] (e.g. one built by instruction builders found in SPL crates)
+/// by resolving each of its account metas to a [NoStdAccountInfo] by key. Account metas and infos
+/// are collected on the stack with [CpiAccounts] (holding at most `CAP` accounts), so no additional
+/// heap memory is allocated for CPI. Like [try_invoke_signed], [try_check_borrow_account_info] is
+/// performed on each resolved account before invoking.
+///
+/// Returns [ProgramError::NotEnoughAccountKeys] if an account meta's pubkey cannot be found in
+/// `accounts` and a custom program error code with [SealevelToolsError::CPI] if the instruction has
+/// more than `CAP` account metas.
+///
+/// ### Notes
+///
+/// Each account meta is resolved by comparing its pubkey against every account in `accounts` until
+/// a match is found, so the cost of this lookup scales with the number of account metas multiplied
+/// by the number of accounts. Pass in only the accounts relevant for this instruction to keep this
+/// cost low. If you know the order of the accounts upfront, it is cheaper to build a
+/// [CpiInstruction] directly.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     cpi::invoke_std_instruction,
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+///     program_error::ProgramError,
+/// };
+///
+/// fn thaw(accounts: &[NoStdAccountInfo]) -> ProgramResult {
+///     let [token_account, mint, freeze_authority] = accounts else {
+///         return Err(ProgramError::NotEnoughAccountKeys);
+///     };
+///
+///     let instruction = sealevel_tools::spl_token_2022::instruction::thaw_account(
+///         mint.owner(),
+///         token_account.key(),
+///         mint.key(),
+///         freeze_authority.key(),
+///         &[],
+///     )?;
+///
+///     // Thaw account instruction has three account metas.
+///     invoke_std_instruction::<3>(&instruction, accounts, &[])
+/// }
+/// ```
+///
+/// [SealevelToolsError::CPI]: crate::error::SealevelToolsError::CPI
+#[inline(always)]
+pub fn invoke_std_instruction<const CAP: usize>(
+    Instruction {
+        program_id,
+        accounts: metas,
+        data,
+    }: &Instruction,
+    accounts: &[NoStdAccountInfo],
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let mut cpi_accounts = CpiAccounts::<CAP>::new();
+
+    metas.iter().try_for_each(|meta| {
+        let account = accounts
+            .iter()
+            .find(|account| account.key() == &meta.pubkey)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        try_check_borrow_account_info(account)?;
+
+        cpi_accounts.try_push(
+            AccountMetaC {
+                pubkey: &meta.pubkey,
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            },
+            account.to_info_c(),
+        )
+    })?;
+
+    CpiInstruction {
+        program_id,
+        accounts: cpi_accounts.metas(),
+        data,
+    }
+    .invoke_signed(cpi_accounts.infos(), signers_seeds);
+
+    Ok(())
+}
+
+/// Invoke the executing program itself (self-CPI) with instruction data built by
+/// [build_instruction_data] (selector followed by [borsh]-serialized arguments), which matches how
+/// the program decodes its own instruction data. This pattern is useful for privilege separation,
+/// where one instruction invokes another instruction of the same program with a different
+/// selector (e.g. signing as a PDA).
+///
+/// Each account's meta is built with [NoStdAccountInfo::to_meta_c], so its signer and writable
+/// privileges are passed through as is. Provide signer seeds for any PDA that needs to sign.
+///
+/// ### Notes
+///
+/// The runtime allows a program to invoke itself directly (but not indirectly through another
+/// program), so the invoked instruction must be safe to run while the calling instruction is in
+/// progress. Release any account data borrows before invoking (and re-read account data
+/// afterwards), because the invoked instruction may modify these accounts.
+///
+/// Each self-CPI counts towards the CPI depth limit (4 levels) and costs compute units for the
+/// invocation itself on top of the invoked instruction's compute units.
+///
+/// [borsh]: https://docs.rs/borsh/latest/borsh/
+#[cfg(feature = "borsh")]
+#[inline(always)]
+pub fn self_invoke<const N: usize, A: BorshSerialize>(
+    program_id: &Pubkey,
+    accounts: &[&NoStdAccountInfo],
+    selector: &[u8; N],
+    args: &A,
+    signers_seeds: &[&[&[u8]]],
+) {
+    _with_self_instruction(
+        program_id,
+        accounts,
+        selector,
+        args,
+        |instruction, infos| instruction.invoke_signed(infos, signers_seeds),
+    );
+}
+
+/// Build the self-CPI instruction and its account infos, then pass them to the closure.
+#[cfg(feature = "borsh")]
+#[inline(always)]
+fn _with_self_instruction<const N: usize, A: BorshSerialize, R>(
+    program_id: &Pubkey,
+    accounts: &[&NoStdAccountInfo],
+    selector: &[u8; N],
+    args: &A,
+    f: impl FnOnce(&CpiInstruction, &[AccountInfoC]) -> R,
+) -> R {
+    let data = build_instruction_data(selector, args);
+
+    f(
+        &CpiInstruction {
+            program_id,
+            accounts: &accounts
+                .iter()
+                .map(|account| account.to_meta_c())
+                .collect::<Vec<_>>(),
+            data: &data,
+        },
+        &accounts
+            .iter()
+            .map(|account| account.to_info_c())
+            .collect::<Vec<_>>(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use solana_instruction::AccountMeta;
+
+    use crate::{
+        error::SealevelToolsError,
+        pubkey::Pubkey,
+        test_fixture::{TestAccount, TestAccountInfos},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_invoke_std_instruction() {
+        let mut test_accounts = (0..20)
+            .map(|_| TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique()))
+            .collect::<Vec<_>>();
+        test_accounts[0] = test_accounts[0].writable();
+        let infos = TestAccountInfos::new(&test_accounts);
+
+        // Metas reference accounts in a different order than they are passed in.
+        let instruction = Instruction {
+            program_id: Pubkey::new_unique(),
+            accounts: infos
+                .iter()
+                .rev()
+                .map(|account| AccountMeta::new_readonly(*account.key(), false))
+                .collect(),
+            data: Vec::new(),
+        };
+        assert_eq!(
+            invoke_std_instruction::<20>(&instruction, &infos, &[]),
+            Ok(())
+        );
+
+        // More account metas than the capacity.
+        assert_eq!(
+            invoke_std_instruction::<19>(&instruction, &infos, &[]),
+            Err(ProgramError::Custom(SealevelToolsError::CPI))
+        );
+
+        // Account meta not found.
+        assert_eq!(
+            invoke_std_instruction::<20>(&instruction, &infos[1..], &[]),
+            Err(ProgramError::NotEnoughAccountKeys)
+        );
+
+        // Writable account's data is already borrowed.
+        {
+            let _data = infos[0].try_borrow_data().unwrap();
+            assert_eq!(
+                invoke_std_instruction::<20>(&instruction, &infos, &[]),
+                Err(ProgramError::AccountBorrowFailed)
+            );
+        }
+
+        // Read-only account's data is already mutably borrowed.
+        {
+            let _data = infos[1].try_borrow_mut_data().unwrap();
+            assert_eq!(
+                invoke_std_instruction::<20>(&instruction, &infos, &[]),
+                Err(ProgramError::AccountBorrowFailed)
+            );
+        }
+        assert_eq!(
+            invoke_std_instruction::<20>(&instruction, &infos, &[]),
+            Ok(())
+        );
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn test_self_invoke() {
+        use crate::discriminator::Discriminator;
+
+        const SELECTOR: [u8; 8] = Discriminator::Sha2(b"ix::do_thing").to_bytes();
+
+        let program_id = Pubkey::new_unique();
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique())
+                .signer()
+                .writable(),
+            TestAccount::new(Pubkey::new_unique(), program_id).writable(),
+            TestAccount::new(Pubkey::new_unique(), program_id),
+        ]);
+        let accounts = infos.iter().collect::<Vec<_>>();
+        let args = (69_u64, true);
+
+        _with_self_instruction(
+            &program_id,
+            &accounts,
+            &SELECTOR,
+            &args,
+            |instruction, cpi_infos| {
+                assert_eq!(instruction.program_id, &program_id);
+
+                let mut expected_data = SELECTOR.to_vec();
+                expected_data.extend_from_slice(&69_u64.to_le_bytes());
+                expected_data.push(1);
+                assert_eq!(instruction.data, expected_data);
+
+                assert_eq!(instruction.accounts.len(), accounts.len());
+                assert_eq!(cpi_infos.len(), accounts.len());
+
+                for ((meta, info), account) in
+                    instruction.accounts.iter().zip(cpi_infos).zip(&accounts)
+                {
+                    assert_eq!(unsafe { &*meta.pubkey }, account.key());
+                    assert_eq!(unsafe { &*info.key }, account.key());
+                    assert_eq!(meta.is_signer, account.is_signer());
+                    assert_eq!(meta.is_writable, account.is_writable());
+                }
+            },
+        );
+
+        // CPI is a no-op off-chain.
+        self_invoke(&program_id, &accounts, &SELECTOR, &args, &[]);
+    }
+}
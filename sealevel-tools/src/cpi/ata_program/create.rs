@@ -7,6 +7,22 @@ use crate::{
 
 /// Arguments for the create instruction on an Associated Token Account program, which creates a
 /// token account with an address seeded by its owner and mint.
+///
+/// ### Notes
+///
+/// Because the token program account is passed in, this instruction works with both the legacy
+/// Token program and the Token Extensions program.
+///
+/// The accounts are ordered for CPI as follows:
+/// 1. `[writable, signer]` Payer.
+/// 2. `[writable]` Associated token account.
+/// 3. `[]` Associated token account owner.
+/// 4. `[]` Mint.
+/// 5. `[]` System program.
+/// 6. `[]` Token program.
+///
+/// The ATA program itself is the CPI program ID (see [Self::ata_program_id]), so its account only
+/// needs to be present in the transaction.
 #[derive(Clone, PartialEq, Eq)]
 pub struct Create<'a, 'b: 'a> {
     /// If your program interacts with a fork of the official ATA program, provide the ID here.
@@ -19,6 +35,10 @@ pub struct Create<'a, 'b: 'a> {
     pub mint: &'b NoStdAccountInfo,
     pub system_program: &'b NoStdAccountInfo,
     pub token_program: &'b NoStdAccountInfo,
+
+    /// If true, the create idempotent instruction (selector == 1) is invoked, which succeeds if
+    /// the associated token account already exists with the same owner and mint. Otherwise the
+    /// create instruction (selector == 0) is invoked, which fails if the account already exists.
     pub idempotent: bool,
 }
 
@@ -26,13 +46,8 @@ impl<'a, 'b: 'a> Create<'a, 'b> {
     /// Consume arguments to perform CPI call.
     #[inline(always)]
     pub fn into_invoke(self) {
-        if self.idempotent {
-            // Create idempotent selector == 1.
-            self._into_invoke(&[1]);
-        } else {
-            // Empty data for create.
-            self._into_invoke(&[]);
-        }
+        let data = _instruction_data(self.idempotent);
+        self._into_invoke(data);
     }
 
     #[inline(always)]
@@ -73,3 +88,41 @@ impl<'a, 'b: 'a> Create<'a, 'b> {
         );
     }
 }
+
+#[inline(always)]
+const fn _instruction_data(idempotent: bool) -> &'static [u8] {
+    if idempotent {
+        // Create idempotent selector == 1.
+        &[1]
+    } else {
+        // Create selector == 0.
+        &[0]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use spl_associated_token_account::instruction::{
+        create_associated_token_account, create_associated_token_account_idempotent,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_instruction_data() {
+        let payer = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let token_program_id = Pubkey::new_unique();
+
+        assert_eq!(
+            _instruction_data(false),
+            create_associated_token_account(&payer, &owner, &mint, &token_program_id).data
+        );
+        assert_eq!(
+            _instruction_data(true),
+            create_associated_token_account_idempotent(&payer, &owner, &mint, &token_program_id)
+                .data
+        );
+    }
+}
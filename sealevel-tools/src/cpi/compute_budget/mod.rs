@@ -0,0 +1,125 @@
+//! CPI for the Compute Budget program.
+//!
+//! ### Notes
+//!
+//! Compute Budget instructions are meant to be top-level instructions in a transaction. The
+//! runtime reads them from the transaction message before any instruction is executed, so invoking
+//! them via CPI does not change the transaction's compute unit limit or price (the Compute Budget
+//! program does nothing when invoked). The CPI builders here only exist for the rare use case that
+//! needs this behavior (e.g. programs relaying instructions).
+//!
+//! To request a compute budget, build these instructions for the transaction instead. Use
+//! [ID] and the instruction data serialization methods (e.g. [SetComputeUnitLimit::to_bytes]) to
+//! do so.
+
+crate::declare_id!("ComputeBudget111111111111111111111111111111");
+
+use core::mem::size_of;
+
+use super::CpiInstruction;
+
+/// Arguments for the set compute unit limit instruction on the Compute Budget program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetComputeUnitLimit {
+    pub units: u32,
+}
+
+impl SetComputeUnitLimit {
+    /// Serialize instruction data.
+    #[inline(always)]
+    pub fn to_bytes(&self) -> [u8; SET_COMPUTE_UNIT_LIMIT_IX_DATA_LEN] {
+        let mut instruction_data = [0; SET_COMPUTE_UNIT_LIMIT_IX_DATA_LEN];
+
+        // Set compute unit limit selector == 2.
+        instruction_data[0] = 2;
+        instruction_data[1..].copy_from_slice(&self.units.to_le_bytes());
+
+        instruction_data
+    }
+
+    /// Consume arguments to perform CPI call. See [module-level notes](self) for why this CPI call
+    /// does not change the compute unit limit.
+    #[inline(always)]
+    pub fn into_invoke(self) {
+        _invoke_compute_budget(&self.to_bytes());
+    }
+}
+
+/// Arguments for the set compute unit price instruction on the Compute Budget program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetComputeUnitPrice {
+    pub micro_lamports: u64,
+}
+
+impl SetComputeUnitPrice {
+    /// Serialize instruction data.
+    #[inline(always)]
+    pub fn to_bytes(&self) -> [u8; SET_COMPUTE_UNIT_PRICE_IX_DATA_LEN] {
+        let mut instruction_data = [0; SET_COMPUTE_UNIT_PRICE_IX_DATA_LEN];
+
+        // Set compute unit price selector == 3.
+        instruction_data[0] = 3;
+        instruction_data[1..].copy_from_slice(&self.micro_lamports.to_le_bytes());
+
+        instruction_data
+    }
+
+    /// Consume arguments to perform CPI call. See [module-level notes](self) for why this CPI call
+    /// does not change the compute unit price.
+    #[inline(always)]
+    pub fn into_invoke(self) {
+        _invoke_compute_budget(&self.to_bytes());
+    }
+}
+
+/// Length of serialized [SetComputeUnitLimit] instruction data.
+pub const SET_COMPUTE_UNIT_LIMIT_IX_DATA_LEN: usize = {
+    size_of::<u8>() // selector
+    + size_of::<u32>() // units
+};
+
+/// Length of serialized [SetComputeUnitPrice] instruction data.
+pub const SET_COMPUTE_UNIT_PRICE_IX_DATA_LEN: usize = {
+    size_of::<u8>() // selector
+    + size_of::<u64>() // micro_lamports
+};
+
+#[inline(always)]
+fn _invoke_compute_budget(data: &[u8]) {
+    CpiInstruction {
+        program_id: &ID,
+        accounts: &[],
+        data,
+    }
+    .invoke_signed(&[], &[]);
+}
+
+#[cfg(test)]
+mod test {
+    use solana_sdk::compute_budget::ComputeBudgetInstruction;
+
+    use super::*;
+
+    #[test]
+    fn test_id() {
+        assert_eq!(ID, solana_sdk::compute_budget::ID);
+    }
+
+    #[test]
+    fn test_set_compute_unit_limit() {
+        assert_eq!(
+            SetComputeUnitLimit { units: 420_000 }.to_bytes().as_slice(),
+            ComputeBudgetInstruction::set_compute_unit_limit(420_000).data
+        );
+    }
+
+    #[test]
+    fn test_set_compute_unit_price() {
+        assert_eq!(
+            SetComputeUnitPrice { micro_lamports: 69 }
+                .to_bytes()
+                .as_slice(),
+            ComputeBudgetInstruction::set_compute_unit_price(69).data
+        );
+    }
+}
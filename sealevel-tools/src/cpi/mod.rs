@@ -1,13 +1,16 @@
 //! Utility methods for cross-program invocations.
 
+mod accounts;
 #[cfg(feature = "alloc")]
 mod alloc;
 #[cfg(feature = "token")]
 pub mod ata_program;
+pub mod compute_budget;
 pub mod system_program;
 #[cfg(feature = "token")]
 pub mod token_program;
 
+pub use accounts::*;
 #[cfg(feature = "alloc")]
 pub use alloc::*;
 
@@ -31,6 +34,27 @@ pub struct CpiAuthority<'a, 'b: 'a> {
     pub signer_seeds: Option<&'a [&'b [u8]]>,
 }
 
+impl<'a, 'b: 'a> CpiAuthority<'a, 'b> {
+    /// Authority that signed the transaction (e.g. a keypair), so no signer seeds are needed.
+    #[inline(always)]
+    pub const fn from_signer(account: &'b NoStdAccountInfo) -> Self {
+        Self {
+            account,
+            signer_seeds: None,
+        }
+    }
+
+    /// Authority that is a PDA of the executing program, which signs with the given seeds
+    /// (including the bump seed).
+    #[inline(always)]
+    pub const fn from_pda(account: &'b NoStdAccountInfo, signer_seeds: &'a [&'b [u8]]) -> Self {
+        Self {
+            account,
+            signer_seeds: Some(signer_seeds),
+        }
+    }
+}
+
 impl<'a, 'b: 'a> Deref for CpiAuthority<'a, 'b> {
     type Target = NoStdAccountInfo;
 
@@ -60,6 +84,35 @@ pub fn unwrap_signers_seeds<'a, 'b: 'a, const NUM_POSSIBLE: usize>(
     (signers_seeds, end)
 }
 
+/// Like [unwrap_signers_seeds], but collects the signer seeds from [CpiAuthority] references (e.g.
+/// a vault authority and a fee authority, which are both PDAs). Authorities without signer seeds
+/// are skipped, so only the first returned number of seeds should be passed into an invoke method.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     cpi::{combine_signer_seeds, CpiAuthority, CpiInstruction},
+///     entrypoint::AccountInfoC,
+/// };
+///
+/// fn invoke_with_authorities(
+///     instruction: &CpiInstruction,
+///     infos: &[AccountInfoC],
+///     vault_authority: &CpiAuthority,
+///     fee_authority: &CpiAuthority,
+/// ) {
+///     let (signers_seeds, end) = combine_signer_seeds(&[vault_authority, fee_authority]);
+///     instruction.invoke_signed(infos, &signers_seeds[..end]);
+/// }
+/// ```
+#[inline(always)]
+pub fn combine_signer_seeds<'a, 'b: 'a, const NUM_POSSIBLE: usize>(
+    authorities: &[&CpiAuthority<'a, 'b>; NUM_POSSIBLE],
+) -> ([&'a [&'b [u8]]; NUM_POSSIBLE], usize) {
+    unwrap_signers_seeds(&core::array::from_fn(|i| authorities[i].signer_seeds))
+}
+
 /// Setup to invoke a cross-program instruction. To avoid using heap memory, it is recommended to
 /// pass in references to fixed arrays of accounts and infos.
 #[derive(Debug, Clone)]
@@ -203,3 +256,43 @@ pub fn checked_return_data<const DATA_LEN: usize>() -> Option<(Pubkey, [u8; DATA
     #[cfg(not(target_os = "solana"))]
     None
 }
+
+#[cfg(test)]
+mod test {
+    use crate::test_fixture::{TestAccount, TestAccountInfos};
+
+    use super::*;
+
+    #[test]
+    fn test_combine_signer_seeds() {
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(Pubkey::new_unique(), Pubkey::default()).signer(),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique()),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::default()).signer(),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique()),
+        ]);
+
+        let vault_seeds: &[&[u8]] = &[b"vault", &[255]];
+        let fee_seeds: &[&[u8]] = &[b"fee", &[254]];
+
+        let payer = CpiAuthority::from_signer(&infos[0]);
+        let vault_authority = CpiAuthority::from_pda(&infos[1], vault_seeds);
+        let owner = CpiAuthority::from_signer(&infos[2]);
+        let fee_authority = CpiAuthority::from_pda(&infos[3], fee_seeds);
+
+        // Authorities without signer seeds are skipped and the order of the rest is preserved.
+        let (signers_seeds, end) =
+            combine_signer_seeds(&[&payer, &vault_authority, &owner, &fee_authority]);
+        assert_eq!(end, 2);
+        assert_eq!(signers_seeds[..end], [vault_seeds, fee_seeds]);
+
+        let (signers_seeds, end) =
+            combine_signer_seeds(&[&fee_authority, &payer, &vault_authority]);
+        assert_eq!(end, 2);
+        assert_eq!(signers_seeds[..end], [fee_seeds, vault_seeds]);
+
+        // Only signers.
+        let (_, end) = combine_signer_seeds(&[&payer, &owner]);
+        assert_eq!(end, 0);
+    }
+}
@@ -141,6 +141,13 @@ pub struct CreateAccount<'a, 'b: 'a> {
     pub to: CpiAuthority<'a, 'b>,
 
     /// The program to assign the account to.
+    ///
+    /// ### Notes
+    ///
+    /// This program ID can be any program (not only the executing program), which is useful for
+    /// pre-creating an account for another program to initialize. But once assigned, only the
+    /// owning program can write to this account's data or reassign it, so the executing program
+    /// will not be able to modify or reassign this account afterward.
     pub program_id: &'a Pubkey,
 
     /// The space to allocate for the account. If [None], defaults to zero for
@@ -197,6 +204,14 @@ impl<'a, 'b: 'a> CreateAccount<'a, 'b> {
     ///
     /// The space to allocate for the account. If not specified, the space will be determined by
     /// [AccountSerde::try_account_space].
+    ///
+    /// ### Notes
+    ///
+    /// If the "debug-verify" feature is enabled, the serialized data is read back and deserialized
+    /// using [AccountSerde::try_deserialize_data]. If deserialization fails, a custom program error
+    /// code with [SealevelToolsError::CPI] is returned. This check is not compiled otherwise.
+    ///
+    /// [SealevelToolsError::CPI]: crate::error::SealevelToolsError::CPI
     #[inline(always)]
     pub fn try_invoke_and_serialize<const DISC_LEN: usize, T: AccountSerde<DISC_LEN>>(
         mut self,
@@ -215,10 +230,28 @@ impl<'a, 'b: 'a> CreateAccount<'a, 'b> {
             account_data.try_serialize_data(&mut data)?;
         }
 
+        #[cfg(feature = "debug-verify")]
+        _verify_serialized_data::<DISC_LEN, T>(&account.try_borrow_data()?)?;
+
         Ok(account)
     }
 }
 
+#[cfg(feature = "debug-verify")]
+#[inline(always)]
+fn _verify_serialized_data<const DISC_LEN: usize, T: AccountSerde<DISC_LEN>>(
+    data: &[u8],
+) -> Result<(), ProgramError> {
+    if T::try_deserialize_data(&mut &data[..]).is_err() {
+        return Err(crate::error::SealevelToolsError::Cpi(&[
+            "Serialized account data does not deserialize",
+        ])
+        .into());
+    }
+
+    Ok(())
+}
+
 #[inline(always)]
 fn _invoke_create_account(
     from: &CpiAuthority,
@@ -281,4 +314,55 @@ mod test {
             }
         );
     }
+
+    #[cfg(feature = "debug-verify")]
+    #[test]
+    fn test_verify_serialized_data() {
+        use crate::{
+            discriminator::{Discriminate, Discriminator},
+            error::SealevelToolsError,
+        };
+
+        struct Thing {
+            value: u8,
+        }
+
+        impl Discriminate<8> for Thing {
+            const DISCRIMINATOR: [u8; 8] = Discriminator::Sha2(b"state::Thing").to_bytes();
+        }
+
+        impl AccountSerde<8> for Thing {
+            fn try_deserialize_schema(data: &mut &[u8]) -> Result<Self, ProgramError> {
+                data.first()
+                    .map(|&value| Thing { value })
+                    .ok_or(ProgramError::InvalidAccountData)
+            }
+
+            fn try_serialize_schema(&self, buf: &mut [u8]) -> Result<(), ProgramError> {
+                buf[0] = self.value;
+                Ok(())
+            }
+
+            fn try_account_schema_space(&self) -> Result<usize, ProgramError> {
+                Ok(1)
+            }
+        }
+
+        let mut data = [0; 9];
+        Thing { value: 69 }.try_serialize_data(&mut data).unwrap();
+        assert_eq!(_verify_serialized_data::<8, Thing>(&data), Ok(()));
+
+        // Missing schema data.
+        assert_eq!(
+            _verify_serialized_data::<8, Thing>(&data[..8]),
+            Err(ProgramError::Custom(SealevelToolsError::CPI))
+        );
+
+        // Discriminator mismatch.
+        data[0] = data[0].wrapping_add(1);
+        assert_eq!(
+            _verify_serialized_data::<8, Thing>(&data),
+            Err(ProgramError::Custom(SealevelToolsError::CPI))
+        );
+    }
 }
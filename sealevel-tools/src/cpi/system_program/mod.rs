@@ -14,9 +14,11 @@
 mod allocate;
 mod assign;
 mod create_account;
+mod nonce;
 mod transfer;
 
 pub use allocate::*;
 pub use assign::*;
 pub use create_account::*;
+pub use nonce::*;
 pub use transfer::*;
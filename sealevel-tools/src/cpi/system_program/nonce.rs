@@ -0,0 +1,362 @@
+use core::mem::size_of;
+
+use crate::{
+    account::system::ID,
+    cpi::{CpiAuthority, CpiInstruction},
+    entrypoint::{NoStdAccountInfo, ProgramResult},
+    pubkey::Pubkey,
+};
+
+use super::CreateAccount;
+
+/// Space required for a System nonce account (serialized nonce state).
+pub const NONCE_STATE_SPACE: usize = 80;
+
+/// Arguments for the initialize nonce account instruction on the System program, which sets the
+/// nonce authority and stores the current durable nonce in a System-owned account that has already
+/// been allocated with [NONCE_STATE_SPACE].
+///
+/// ### Notes
+///
+/// The recent blockhashes and rent sysvar accounts are required by this instruction. Their keys
+/// are checked by the System program.
+#[derive(Clone, PartialEq, Eq)]
+pub struct InitializeNonceAccount<'a, 'b: 'a> {
+    pub nonce_account: &'b NoStdAccountInfo,
+    pub recent_blockhashes_sysvar: &'b NoStdAccountInfo,
+    pub rent_sysvar: &'b NoStdAccountInfo,
+    pub authority: &'a Pubkey,
+}
+
+impl<'a, 'b: 'a> InitializeNonceAccount<'a, 'b> {
+    /// Consume arguments to perform CPI call.
+    #[inline(always)]
+    pub fn into_invoke(self) {
+        let Self {
+            nonce_account,
+            recent_blockhashes_sysvar,
+            rent_sysvar,
+            authority,
+        } = self;
+
+        _invoke_initialize_nonce_account(
+            nonce_account,
+            recent_blockhashes_sysvar,
+            rent_sysvar,
+            authority,
+        );
+    }
+}
+
+/// Arguments to create a System nonce account and initialize it. The nonce account is created
+/// using [CreateAccount] with [NONCE_STATE_SPACE] (owned by the System program) and then
+/// initialized using [InitializeNonceAccount].
+///
+/// ### Notes
+///
+/// Pass in [None] for [CpiAuthority::signer_seeds] if the nonce account is passed in as a random
+/// keypair.
+#[derive(Clone, PartialEq, Eq)]
+pub struct CreateNonceAccount<'a, 'b: 'a> {
+    /// The account that will pay for the rent.
+    pub payer: CpiAuthority<'a, 'b>,
+
+    /// The nonce account to be created.
+    pub nonce_account: CpiAuthority<'a, 'b>,
+
+    pub recent_blockhashes_sysvar: &'b NoStdAccountInfo,
+    pub rent_sysvar: &'b NoStdAccountInfo,
+
+    /// The authority that can advance, withdraw from and authorize the nonce account.
+    pub authority: &'a Pubkey,
+
+    /// Lamports to fund the nonce account with. If [None], defaults to the minimum balance for
+    /// rent-exemption of [NONCE_STATE_SPACE].
+    pub lamports: Option<u64>,
+}
+
+impl<'a, 'b: 'a> CreateNonceAccount<'a, 'b> {
+    /// Try to consume arguments to perform CPI calls.
+    #[inline(always)]
+    pub fn try_into_invoke(self) -> ProgramResult {
+        let Self {
+            payer,
+            nonce_account,
+            recent_blockhashes_sysvar,
+            rent_sysvar,
+            authority,
+            lamports,
+        } = self;
+
+        let nonce_account = CreateAccount {
+            payer,
+            to: nonce_account,
+            program_id: &ID,
+            space: Some(NONCE_STATE_SPACE),
+            lamports,
+        }
+        .try_into_invoke()?;
+
+        _invoke_initialize_nonce_account(
+            &nonce_account,
+            recent_blockhashes_sysvar,
+            rent_sysvar,
+            authority,
+        );
+
+        Ok(())
+    }
+}
+
+/// Arguments for the advance nonce account instruction on the System program, which replaces the
+/// stored durable nonce with a new one. Only the nonce authority can invoke this instruction.
+///
+/// ### Notes
+///
+/// The recent blockhashes sysvar account is required by this instruction. Its key is checked by
+/// the System program.
+#[derive(Clone, PartialEq, Eq)]
+pub struct AdvanceNonceAccount<'a, 'b: 'a> {
+    pub nonce_account: &'b NoStdAccountInfo,
+    pub recent_blockhashes_sysvar: &'b NoStdAccountInfo,
+    pub authority: CpiAuthority<'a, 'b>,
+}
+
+impl<'a, 'b: 'a> AdvanceNonceAccount<'a, 'b> {
+    /// Consume arguments to perform CPI call.
+    #[inline(always)]
+    pub fn into_invoke(self) {
+        let Self {
+            nonce_account,
+            recent_blockhashes_sysvar,
+            authority,
+        } = self;
+
+        // Advance nonce account selector == 4.
+        CpiInstruction {
+            program_id: &ID,
+            accounts: &[
+                nonce_account.to_meta_c(),
+                recent_blockhashes_sysvar.to_meta_c(),
+                authority.to_meta_c_signer(),
+            ],
+            data: &[4, 0, 0, 0],
+        }
+        .invoke_possibly_signed(
+            &[
+                nonce_account.to_info_c(),
+                recent_blockhashes_sysvar.to_info_c(),
+                authority.to_info_c(),
+            ],
+            &[authority.signer_seeds],
+        );
+    }
+}
+
+/// Arguments for the withdraw nonce account instruction on the System program, which transfers
+/// lamports out of a nonce account. Only the nonce authority can invoke this instruction.
+///
+/// ### Notes
+///
+/// The recent blockhashes and rent sysvar accounts are required by this instruction. Their keys
+/// are checked by the System program.
+#[derive(Clone, PartialEq, Eq)]
+pub struct WithdrawNonceAccount<'a, 'b: 'a> {
+    pub nonce_account: &'b NoStdAccountInfo,
+    pub to: &'b NoStdAccountInfo,
+    pub recent_blockhashes_sysvar: &'b NoStdAccountInfo,
+    pub rent_sysvar: &'b NoStdAccountInfo,
+    pub authority: CpiAuthority<'a, 'b>,
+    pub lamports: u64,
+}
+
+impl<'a, 'b: 'a> WithdrawNonceAccount<'a, 'b> {
+    /// Consume arguments to perform CPI call.
+    #[inline(always)]
+    pub fn into_invoke(self) {
+        let Self {
+            nonce_account,
+            to,
+            recent_blockhashes_sysvar,
+            rent_sysvar,
+            authority,
+            lamports,
+        } = self;
+
+        let instruction_data = _serialize_withdraw_instruction_data(lamports);
+
+        CpiInstruction {
+            program_id: &ID,
+            accounts: &[
+                nonce_account.to_meta_c(),
+                to.to_meta_c(),
+                recent_blockhashes_sysvar.to_meta_c(),
+                rent_sysvar.to_meta_c(),
+                authority.to_meta_c_signer(),
+            ],
+            data: &instruction_data,
+        }
+        .invoke_possibly_signed(
+            &[
+                nonce_account.to_info_c(),
+                to.to_info_c(),
+                recent_blockhashes_sysvar.to_info_c(),
+                rent_sysvar.to_info_c(),
+                authority.to_info_c(),
+            ],
+            &[authority.signer_seeds],
+        );
+    }
+}
+
+/// Arguments for the authorize nonce account instruction on the System program, which changes the
+/// nonce authority. Only the current nonce authority can invoke this instruction.
+#[derive(Clone, PartialEq, Eq)]
+pub struct AuthorizeNonceAccount<'a, 'b: 'a> {
+    pub nonce_account: &'b NoStdAccountInfo,
+    pub authority: CpiAuthority<'a, 'b>,
+    pub new_authority: &'a Pubkey,
+}
+
+impl<'a, 'b: 'a> AuthorizeNonceAccount<'a, 'b> {
+    /// Consume arguments to perform CPI call.
+    #[inline(always)]
+    pub fn into_invoke(self) {
+        let Self {
+            nonce_account,
+            authority,
+            new_authority,
+        } = self;
+
+        // Authorize nonce account selector == 7.
+        let instruction_data = _serialize_pubkey_instruction_data(7, new_authority);
+
+        CpiInstruction {
+            program_id: &ID,
+            accounts: &[nonce_account.to_meta_c(), authority.to_meta_c_signer()],
+            data: &instruction_data,
+        }
+        .invoke_possibly_signed(
+            &[nonce_account.to_info_c(), authority.to_info_c()],
+            &[authority.signer_seeds],
+        );
+    }
+}
+
+#[inline(always)]
+fn _invoke_initialize_nonce_account(
+    nonce_account: &NoStdAccountInfo,
+    recent_blockhashes_sysvar: &NoStdAccountInfo,
+    rent_sysvar: &NoStdAccountInfo,
+    authority: &Pubkey,
+) {
+    // Initialize nonce account selector == 6.
+    let instruction_data = _serialize_pubkey_instruction_data(6, authority);
+
+    CpiInstruction {
+        program_id: &ID,
+        accounts: &[
+            nonce_account.to_meta_c(),
+            recent_blockhashes_sysvar.to_meta_c(),
+            rent_sysvar.to_meta_c(),
+        ],
+        data: &instruction_data,
+    }
+    .invoke_signed(
+        &[
+            nonce_account.to_info_c(),
+            recent_blockhashes_sysvar.to_info_c(),
+            rent_sysvar.to_info_c(),
+        ],
+        &[],
+    );
+}
+
+const PUBKEY_IX_DATA_LEN: usize = {
+    4 // selector
+    + size_of::<Pubkey>() // authority
+};
+
+#[inline(always)]
+fn _serialize_pubkey_instruction_data(
+    selector: u8,
+    authority: &Pubkey,
+) -> [u8; PUBKEY_IX_DATA_LEN] {
+    let mut instruction_data = [0; PUBKEY_IX_DATA_LEN];
+    instruction_data[0] = selector;
+    instruction_data[4..36].copy_from_slice(&authority.to_bytes());
+
+    instruction_data
+}
+
+const WITHDRAW_IX_DATA_LEN: usize = {
+    4 // selector
+    + size_of::<u64>() // lamports
+};
+
+#[inline(always)]
+fn _serialize_withdraw_instruction_data(lamports: u64) -> [u8; WITHDRAW_IX_DATA_LEN] {
+    let mut instruction_data = [0; WITHDRAW_IX_DATA_LEN];
+
+    // Withdraw nonce account selector == 5.
+    instruction_data[0] = 5;
+    instruction_data[4..12].copy_from_slice(&lamports.to_le_bytes());
+
+    instruction_data
+}
+
+#[cfg(test)]
+mod test {
+    use solana_sdk::system_instruction::SystemInstruction;
+
+    use super::*;
+
+    #[test]
+    fn test_nonce_state_space() {
+        assert_eq!(NONCE_STATE_SPACE, solana_sdk::nonce::State::size());
+    }
+
+    #[test]
+    fn test_advance_nonce_account_instruction_data() {
+        assert_eq!(
+            bincode::deserialize::<SystemInstruction>(&[4, 0, 0, 0]).unwrap(),
+            SystemInstruction::AdvanceNonceAccount
+        );
+    }
+
+    #[test]
+    fn test_serialize_initialize_instruction_data() {
+        let authority = Pubkey::new_unique();
+
+        let instruction_data = _serialize_pubkey_instruction_data(6, &authority);
+
+        assert_eq!(
+            bincode::deserialize::<SystemInstruction>(&instruction_data).unwrap(),
+            SystemInstruction::InitializeNonceAccount(authority)
+        );
+    }
+
+    #[test]
+    fn test_serialize_authorize_instruction_data() {
+        let new_authority = Pubkey::new_unique();
+
+        let instruction_data = _serialize_pubkey_instruction_data(7, &new_authority);
+
+        assert_eq!(
+            bincode::deserialize::<SystemInstruction>(&instruction_data).unwrap(),
+            SystemInstruction::AuthorizeNonceAccount(new_authority)
+        );
+    }
+
+    #[test]
+    fn test_serialize_withdraw_instruction_data() {
+        let lamports = 420;
+
+        let instruction_data = _serialize_withdraw_instruction_data(lamports);
+
+        assert_eq!(
+            bincode::deserialize::<SystemInstruction>(&instruction_data).unwrap(),
+            SystemInstruction::WithdrawNonceAccount(lamports)
+        );
+    }
+}
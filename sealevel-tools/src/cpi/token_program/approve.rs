@@ -1,9 +1,11 @@
 use crate::{
     cpi::{CpiAuthority, CpiInstruction},
-    entrypoint::NoStdAccountInfo,
+    entrypoint::{NoStdAccountInfo, ProgramResult},
     pubkey::Pubkey,
 };
 
+use super::MultisigSigners;
+
 /// Arguments for the approve instruction on the specified Token program, which allows a delegated
 /// authority to move a specified amount from a token account. Only the token account's owner can
 /// approve an amount to a delegated authority.
@@ -49,4 +51,29 @@ impl<'a, 'b: 'a> Approve<'a, 'b> {
             &[authority.signer_seeds],
         );
     }
+
+    /// Try to consume arguments to perform CPI call, where [Self::authority] is a multisig account
+    /// (its signer seeds are disregarded) and the given multisig signers sign on its behalf. See
+    /// [MultisigSigners] for more information.
+    #[inline(always)]
+    pub fn try_into_invoke_multisig(self, signers: MultisigSigners) -> ProgramResult {
+        let Self {
+            token_program_id,
+            source,
+            delegate,
+            authority,
+            amount,
+        } = self;
+
+        // Approve selector == 4.
+        let instruction_data = super::serialize_amount_instruction_data(4, amount);
+
+        super::_invoke_multisig_signed_without_additional_accounts(
+            token_program_id,
+            &[source, delegate],
+            authority.account,
+            &signers,
+            &instruction_data,
+        )
+    }
 }
@@ -0,0 +1,270 @@
+use crate::{
+    account_info::Account,
+    cpi::{ata_program, CpiAuthority},
+    entrypoint::NoStdAccountInfo,
+    error::SealevelToolsError,
+    program_error::ProgramError,
+};
+
+use super::{CreateMint, MintTo};
+
+/// Accounts and amount for the initial supply minted by [bootstrap_mint_with_supply].
+#[derive(Clone, PartialEq, Eq)]
+pub struct InitialSupply<'a, 'b: 'a> {
+    /// Mint authority used to sign the mint to instruction. Its key must equal
+    /// [CreateMint::mint_authority].
+    pub mint_authority: CpiAuthority<'a, 'b>,
+
+    /// Owner of the Associated Token Account receiving the initial supply.
+    pub owner: &'b NoStdAccountInfo,
+
+    /// Associated Token Account of the owner for the new mint, which will be created.
+    pub associated_account: &'b NoStdAccountInfo,
+
+    pub system_program: &'b NoStdAccountInfo,
+
+    /// Token program account. Its key must equal [CreateMint::token_program_id].
+    pub token_program: &'b NoStdAccountInfo,
+
+    pub amount: u64,
+}
+
+/// Opinionated helper to bootstrap a token launch in one instruction. This method creates a mint
+/// (see [CreateMint]), creates the Associated Token Account for the owner of the initial supply
+/// (using the official ATA program) and mints the initial supply to it, in that order.
+///
+/// ### Notes
+///
+/// The accounts required for these CPI calls are:
+/// - payer (writable signer), from [CreateMint::payer]. It pays for both the mint and the ATA.
+/// - new mint (writable signer), from [CreateMint::mint].
+/// - mint authority (signer), from [InitialSupply::mint_authority].
+/// - owner of the initial supply, from [InitialSupply::owner].
+/// - new Associated Token Account (writable), from [InitialSupply::associated_account].
+/// - System program, from [InitialSupply::system_program].
+/// - Token program, from [InitialSupply::token_program].
+/// - Associated Token Account program (not passed in, but must be in the transaction).
+///
+/// The mint authority and Token program accounts must match the mint creation args. Otherwise, a
+/// custom program error code with [SealevelToolsError::CPI] is returned before any CPI call is
+/// made. If the initial supply amount is zero, the mint to instruction is skipped.
+///
+/// This helper is only a composition of [CreateMint], [ata_program::Create] and [MintTo], so it is
+/// available wherever these builders are (behind the `token` feature) instead of behind its own
+/// feature. It does not require the `alloc` feature.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account_info::{
+///         try_next_enumerated_account, try_next_enumerated_account_info, AccountInfoConstraints,
+///         Payer, ReadonlyAccount, SystemProgram, TokenProgram, WritableAccount,
+///     },
+///     cpi::token_program::{bootstrap_mint_with_supply, CreateMint, InitialSupply},
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+///     pubkey::Pubkey,
+/// };
+///
+/// fn process_instruction(program_id: &Pubkey, accounts: &[NoStdAccountInfo]) -> ProgramResult {
+///     let mut accounts_iter = accounts.iter().enumerate();
+///
+///     let (_, payer) =
+///         try_next_enumerated_account::<Payer>(&mut accounts_iter, Default::default())?;
+///
+///     let (new_mint_addr, new_mint_bump) = Pubkey::find_program_address(&[b"mint"], program_id);
+///
+///     let (_, new_mint_account) = try_next_enumerated_account::<WritableAccount>(
+///         &mut accounts_iter,
+///         AccountInfoConstraints {
+///             key: Some(&new_mint_addr),
+///             ..Default::default()
+///         },
+///     )?;
+///
+///     let (mint_authority_addr, mint_authority_bump) =
+///         Pubkey::find_program_address(&[b"authority"], program_id);
+///
+///     let (_, mint_authority) = try_next_enumerated_account::<ReadonlyAccount>(
+///         &mut accounts_iter,
+///         AccountInfoConstraints {
+///             key: Some(&mint_authority_addr),
+///             ..Default::default()
+///         },
+///     )?;
+///
+///     let (_, owner) = try_next_enumerated_account_info(&mut accounts_iter, Default::default())?;
+///
+///     let (_, associated_account) =
+///         try_next_enumerated_account::<WritableAccount>(&mut accounts_iter, Default::default())?;
+///
+///     let (_, system_program) =
+///         try_next_enumerated_account::<SystemProgram>(&mut accounts_iter, Default::default())?;
+///
+///     let (_, token_program) =
+///         try_next_enumerated_account::<TokenProgram>(&mut accounts_iter, Default::default())?;
+///
+///     bootstrap_mint_with_supply(
+///         CreateMint {
+///             token_program_id: token_program.key(),
+///             payer: payer.as_cpi_authority(),
+///             mint: new_mint_account.as_cpi_authority(Some(&[b"mint", &[new_mint_bump]])),
+///             mint_authority: &mint_authority_addr,
+///             decimals: 9,
+///             freeze_authority: None,
+///             extensions: Default::default(),
+///         },
+///         InitialSupply {
+///             mint_authority: mint_authority
+///                 .as_cpi_authority(Some(&[b"authority", &[mint_authority_bump]])),
+///             owner,
+///             associated_account: &associated_account,
+///             system_program: &system_program,
+///             token_program: &token_program,
+///             amount: 1_000_000_000,
+///         },
+///     )?;
+///
+///     Ok(())
+/// }
+/// ```
+#[inline(always)]
+pub fn bootstrap_mint_with_supply<'a, 'b: 'a>(
+    create_mint: CreateMint<'a, 'b>,
+    initial_supply: InitialSupply<'a, 'b>,
+) -> Result<Account<'b, true>, ProgramError> {
+    let InitialSupply {
+        mint_authority,
+        owner,
+        associated_account,
+        system_program,
+        token_program,
+        amount,
+    } = initial_supply;
+
+    if mint_authority.key() != create_mint.mint_authority {
+        return Err(SealevelToolsError::Cpi(&[
+            "Initial supply mint authority does not match mint authority",
+        ])
+        .into());
+    }
+
+    let token_program_id = create_mint.token_program_id;
+
+    if token_program.key() != token_program_id {
+        return Err(SealevelToolsError::Cpi(&[
+            "Token program account does not match mint's Token program ID",
+        ])
+        .into());
+    }
+
+    let payer = create_mint.payer.clone();
+    let mint = create_mint.try_into_invoke()?;
+
+    ata_program::Create {
+        ata_program_id: None,
+        payer,
+        associated_account,
+        account_owner: owner,
+        mint: mint.0,
+        system_program,
+        token_program,
+        idempotent: false,
+    }
+    .into_invoke();
+
+    if amount != 0 {
+        MintTo {
+            token_program_id,
+            mint: mint.0,
+            destination: associated_account,
+            mint_authority,
+            amount,
+        }
+        .into_invoke();
+    }
+
+    Ok(mint)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        cpi::token_program::InitializeMintExtensions,
+        pubkey::Pubkey,
+        test_fixture::{TestAccount, TestAccountInfos},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_bootstrap_mint_with_supply_account_mismatch() {
+        let token_program_id = crate::account::token_extensions::ID;
+        let system_program_id = crate::account::system::ID;
+
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(Pubkey::new_unique(), system_program_id)
+                .signer()
+                .writable(),
+            TestAccount::new(Pubkey::new_unique(), system_program_id)
+                .signer()
+                .writable(),
+            TestAccount::new(Pubkey::new_unique(), system_program_id).signer(),
+            TestAccount::new(Pubkey::new_unique(), system_program_id),
+            TestAccount::new(Pubkey::new_unique(), system_program_id).writable(),
+            TestAccount::new(system_program_id, Pubkey::new_unique()).executable(),
+            TestAccount::new(token_program_id, Pubkey::new_unique()).executable(),
+            TestAccount::new(crate::account::legacy_token::ID, Pubkey::new_unique()).executable(),
+        ]);
+        let [payer, mint, mint_authority, owner, associated_account, system_program, token_program, legacy_token_program] =
+            &infos[..]
+        else {
+            unreachable!()
+        };
+
+        let create_mint = CreateMint {
+            token_program_id: &token_program_id,
+            payer: CpiAuthority::from_signer(payer),
+            mint: CpiAuthority::from_signer(mint),
+            mint_authority: mint_authority.key(),
+            decimals: 9,
+            freeze_authority: None,
+            extensions: InitializeMintExtensions::default(),
+        };
+        let initial_supply = InitialSupply {
+            mint_authority: CpiAuthority::from_signer(mint_authority),
+            owner,
+            associated_account,
+            system_program,
+            token_program,
+            amount: 1_000_000_000,
+        };
+
+        // Mint authority does not match the mint creation args.
+        let other_mint_authority = Pubkey::new_unique();
+        assert_eq!(
+            bootstrap_mint_with_supply(
+                CreateMint {
+                    mint_authority: &other_mint_authority,
+                    ..create_mint.clone()
+                },
+                initial_supply.clone(),
+            )
+            .err(),
+            Some(ProgramError::Custom(SealevelToolsError::CPI))
+        );
+
+        // Token program account does not match the mint's Token program ID.
+        assert_eq!(
+            bootstrap_mint_with_supply(
+                create_mint,
+                InitialSupply {
+                    token_program: legacy_token_program,
+                    ..initial_supply
+                },
+            )
+            .err(),
+            Some(ProgramError::Custom(SealevelToolsError::CPI))
+        );
+    }
+}
@@ -1,9 +1,11 @@
 use crate::{
     cpi::{CpiAuthority, CpiInstruction},
-    entrypoint::NoStdAccountInfo,
+    entrypoint::{NoStdAccountInfo, ProgramResult},
     pubkey::Pubkey,
 };
 
+use super::MultisigSigners;
+
 /// Arguments for the close account instruction, which closes a token account or mint account (if
 /// the mint close authority extension exists). For token accounts, only the token account's owner
 /// can invoke this instruction. For mint accounts with the mint close authority extension, only
@@ -46,4 +48,26 @@ impl<'a, 'b: 'a> CloseAccount<'a, 'b> {
             &[authority.signer_seeds],
         );
     }
+
+    /// Try to consume arguments to perform CPI call, where [Self::authority] is a multisig account
+    /// (its signer seeds are disregarded) and the given multisig signers sign on its behalf. See
+    /// [MultisigSigners] for more information.
+    #[inline(always)]
+    pub fn try_into_invoke_multisig(self, signers: MultisigSigners) -> ProgramResult {
+        let Self {
+            token_program_id,
+            account,
+            beneficiary,
+            authority,
+        } = self;
+
+        super::_invoke_multisig_signed_without_additional_accounts(
+            token_program_id,
+            &[account, beneficiary],
+            authority.account,
+            &signers,
+            // Close account selector == 9.
+            &[9],
+        )
+    }
 }
@@ -88,7 +88,11 @@ pub struct CreateMint<'a, 'b: 'a> {
     pub mint: CpiAuthority<'a, 'b>,
     pub mint_authority: &'a Pubkey,
     pub decimals: u8,
-    pub freeze_authority: Option<&'a Pubkey>,
+
+    /// Who has authority to freeze and thaw token accounts for this mint. If [None], token accounts
+    /// for this mint cannot be frozen.
+    pub freeze_authority: Option<AuthoritySpec<'a>>,
+
     pub extensions: InitializeMintExtensions<'a>,
 }
 
@@ -96,7 +100,15 @@ pub struct CreateMint<'a, 'b: 'a> {
 /// mint accounts.
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct InitializeMintExtensions<'a> {
-    pub close_authority: Option<&'a Pubkey>,
+    /// Who has authority to close the mint (which can only happen when its supply is zero). This
+    /// authority does not have to be the mint authority.
+    ///
+    /// ### Notes
+    ///
+    /// Like other mint extensions, the close authority is initialized before the mint itself
+    /// (initializing it after the mint is rejected by the Token Extensions program).
+    pub close_authority: Option<AuthoritySpec<'a>>,
+
     pub group_pointer: Option<InitializeGroupPointerData<'a>>,
     pub group_member_pointer: Option<InitializeGroupMemberPointerData<'a>>,
     pub metadata_pointer: Option<InitializeMetadataPointerData<'a>>,
@@ -115,6 +127,38 @@ pub struct InitializeMintExtensions<'a> {
     // FIXME: Uncomment when the extension is implemented.
     //pub confidential_mint_burn: Option<InitializeConfidentialMintBurnData<'a>>,
 }
+
+/// Authority of a mint (e.g. freeze authority or close authority), which is either an explicit
+/// pubkey or the PDA derived from seeds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuthoritySpec<'a> {
+    Pubkey(&'a Pubkey),
+
+    /// The authority is the PDA derived from these seeds and program ID. This is useful when the
+    /// authority is a PDA of your program.
+    ///
+    /// ### Notes
+    ///
+    /// Deriving the address costs compute units. If the address is already known, use
+    /// [AuthoritySpec::Pubkey] instead.
+    Seeds {
+        seeds: &'a [&'a [u8]],
+        program_id: &'a Pubkey,
+    },
+}
+
+impl<'a> AuthoritySpec<'a> {
+    /// Authority's address, which is derived with [Pubkey::find_program_address] if
+    /// [AuthoritySpec::Seeds] is specified.
+    #[inline(always)]
+    pub fn to_pubkey(&self) -> Pubkey {
+        match self {
+            Self::Pubkey(pubkey) => **pubkey,
+            Self::Seeds { seeds, program_id } => Pubkey::find_program_address(seeds, program_id).0,
+        }
+    }
+}
+
 /// Data required to initialize the group pointer extension, which is used to establish a collection
 /// of mints.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -424,7 +468,7 @@ impl<'a, 'b: 'a> CreateMint<'a, 'b> {
                 super::extensions::InitializeMintCloseAuthority {
                     token_program_id,
                     mint: &mint_account,
-                    authority: Some(close_authority),
+                    authority: Some(&close_authority.to_pubkey()),
                 }
                 .into_invoke();
             }
@@ -582,7 +626,7 @@ impl<'a, 'b: 'a> CreateMint<'a, 'b> {
             token_program_id,
             &mint_account,
             mint_authority,
-            freeze_authority,
+            freeze_authority.map(|spec| spec.to_pubkey()).as_ref(),
             decimals,
         );
 
@@ -637,14 +681,32 @@ fn _invoke_initialize_mint2(
     freeze_authority: Option<&Pubkey>,
     decimals: u8,
 ) {
-    const IX_DATA_LEN: usize = {
-        size_of::<u8>() // selector
-        + size_of::<u8>() // decimals
-        + size_of::<Pubkey>() // mint_authority
-        + size_of::<u8>() + size_of::<Pubkey>() // freeze_authority
-    };
+    CpiInstruction {
+        program_id: token_program_id,
+        accounts: &[mint.to_meta_c()],
+        data: &_serialize_initialize_mint2_instruction_data(
+            mint_authority,
+            freeze_authority,
+            decimals,
+        ),
+    }
+    .invoke_signed(&[mint.to_info_c()], &[]);
+}
+
+const IX_INITIALIZE_MINT2_DATA_LEN: usize = {
+    size_of::<u8>() // selector
+    + size_of::<u8>() // decimals
+    + size_of::<Pubkey>() // mint_authority
+    + size_of::<u8>() + size_of::<Pubkey>() // freeze_authority
+};
 
-    let mut instruction_data = [0; IX_DATA_LEN];
+#[inline(always)]
+fn _serialize_initialize_mint2_instruction_data(
+    mint_authority: &Pubkey,
+    freeze_authority: Option<&Pubkey>,
+    decimals: u8,
+) -> [u8; IX_INITIALIZE_MINT2_DATA_LEN] {
+    let mut instruction_data = [0; IX_INITIALIZE_MINT2_DATA_LEN];
 
     // Initialize mint 2 selector == 20.
     instruction_data[0] = 20;
@@ -656,10 +718,99 @@ fn _invoke_initialize_mint2(
         instruction_data[35..67].copy_from_slice(&freeze_authority.to_bytes());
     }
 
-    CpiInstruction {
-        program_id: token_program_id,
-        accounts: &[mint.to_meta_c()],
-        data: &instruction_data,
+    instruction_data
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_authority_spec_to_pubkey() {
+        let authority = Pubkey::new_unique();
+        assert_eq!(AuthoritySpec::Pubkey(&authority).to_pubkey(), authority);
+
+        let program_id = Pubkey::new_unique();
+        let seeds: &[&[u8]] = &[b"close_authority", b"mint"];
+        let (expected, _) = Pubkey::find_program_address(seeds, &program_id);
+        assert_eq!(
+            AuthoritySpec::Seeds {
+                seeds,
+                program_id: &program_id,
+            }
+            .to_pubkey(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_serialize_initialize_mint2_instruction_data() {
+        let mint = Pubkey::new_unique();
+        let mint_authority = Pubkey::new_unique();
+        let program_id = Pubkey::new_unique();
+        let seeds: &[&[u8]] = &[b"freeze_authority"];
+        let (derived_freeze_authority, _) = Pubkey::find_program_address(seeds, &program_id);
+
+        // No freeze authority. The Token program packs fewer bytes in this case, so the trailing
+        // bytes of the fixed-length data must be zero.
+        let instruction_data =
+            _serialize_initialize_mint2_instruction_data(&mint_authority, None, 9);
+        let expected = spl_token_2022::instruction::initialize_mint2(
+            &spl_token_2022::ID,
+            &mint,
+            &mint_authority,
+            None,
+            9,
+        )
+        .unwrap()
+        .data;
+        assert_eq!(instruction_data[..expected.len()], expected[..]);
+        assert!(instruction_data[expected.len()..]
+            .iter()
+            .all(|&byte| byte == 0));
+
+        // Freeze authority derived from seeds.
+        let freeze_authority = AuthoritySpec::Seeds {
+            seeds,
+            program_id: &program_id,
+        };
+        assert_eq!(
+            _serialize_initialize_mint2_instruction_data(
+                &mint_authority,
+                Some(&freeze_authority.to_pubkey()),
+                6,
+            ),
+            spl_token_2022::instruction::initialize_mint2(
+                &spl_token_2022::ID,
+                &mint,
+                &mint_authority,
+                Some(&derived_freeze_authority),
+                6,
+            )
+            .unwrap()
+            .data[..]
+        );
+
+        // Explicit freeze authority is used as-is (not derived).
+        let explicit_freeze_authority = Pubkey::new_unique();
+        assert_ne!(explicit_freeze_authority, derived_freeze_authority);
+
+        let freeze_authority = AuthoritySpec::Pubkey(&explicit_freeze_authority);
+        assert_eq!(
+            _serialize_initialize_mint2_instruction_data(
+                &mint_authority,
+                Some(&freeze_authority.to_pubkey()),
+                6,
+            ),
+            spl_token_2022::instruction::initialize_mint2(
+                &spl_token_2022::ID,
+                &mint,
+                &mint_authority,
+                Some(&explicit_freeze_authority),
+                6,
+            )
+            .unwrap()
+            .data[..]
+        );
     }
-    .invoke_signed(&[mint.to_info_c()], &[]);
 }
@@ -91,6 +91,17 @@ pub struct CreateTokenAccount<'a, 'b: 'a> {
     pub token_account: CpiAuthority<'a, 'b>,
     pub mint: &'b NoStdAccountInfo,
     pub token_account_owner: &'a Pubkey,
+
+    /// If true, the token account is created with the immutable owner extension. The extended
+    /// account size is allocated and the immutable owner extension is initialized before the token
+    /// account is initialized (which is the order the Token Extensions program requires).
+    ///
+    /// ### Notes
+    ///
+    /// This extension is only supported by the SPL Token Extensions program, so an error is
+    /// returned if the mint belongs to the legacy SPL Token program. If the mint has the
+    /// non-transferable extension, the Token Extensions program makes the token account immutable
+    /// regardless of this flag.
     pub immutable_owner: bool,
 }
 
@@ -0,0 +1,59 @@
+/// Instruction data for the enable CPI guard instruction on the Token Extensions program, which
+/// prevents certain token account operations (e.g. transferring by the owner, approving and
+/// closing) from being performed via CPI. The instruction's accounts are the token account
+/// (writable) and its owner (signer).
+///
+/// ### Notes
+///
+/// The Token Extensions program rejects toggling the CPI guard from within a CPI call (so a program
+/// cannot lift the protection on a user's behalf) with its CPI guard settings locked error. So
+/// there is no CPI builder for this instruction. Use this data to build a top-level instruction
+/// instead.
+pub const ENABLE_CPI_GUARD_INSTRUCTION_DATA: [u8; 2] = [
+    34, // CPI guard extension selector.
+    0,  // Enable selector.
+];
+
+/// Instruction data for the disable CPI guard instruction on the Token Extensions program, which
+/// allows all token account operations to be performed via CPI again. The instruction's accounts
+/// are the token account (writable) and its owner (signer).
+///
+/// ### Notes
+///
+/// Like [ENABLE_CPI_GUARD_INSTRUCTION_DATA], this instruction cannot be invoked via CPI. Use this
+/// data to build a top-level instruction instead.
+pub const DISABLE_CPI_GUARD_INSTRUCTION_DATA: [u8; 2] = [
+    34, // CPI guard extension selector.
+    1,  // Disable selector.
+];
+
+#[cfg(test)]
+mod test {
+    use spl_token_2022::extension::cpi_guard::instruction::{disable_cpi_guard, enable_cpi_guard};
+
+    use crate::pubkey::Pubkey;
+
+    use super::*;
+
+    #[test]
+    fn test_cpi_guard_instruction_data() {
+        let account = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        assert_eq!(ENABLE_CPI_GUARD_INSTRUCTION_DATA, [34, 0]);
+        assert_eq!(
+            enable_cpi_guard(&spl_token_2022::ID, &account, &owner, &[])
+                .unwrap()
+                .data,
+            ENABLE_CPI_GUARD_INSTRUCTION_DATA
+        );
+
+        assert_eq!(DISABLE_CPI_GUARD_INSTRUCTION_DATA, [34, 1]);
+        assert_eq!(
+            disable_cpi_guard(&spl_token_2022::ID, &account, &owner, &[])
+                .unwrap()
+                .data,
+            DISABLE_CPI_GUARD_INSTRUCTION_DATA
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{
+    cpi::{CpiAuthority, CpiInstruction},
+    entrypoint::NoStdAccountInfo,
+    pubkey::Pubkey,
+};
+
+/// Arguments for the enable required memo transfers instruction on the specified Token program,
+/// which requires memos for transfers into this token account. Only the token account's owner can
+/// invoke this instruction.
+#[derive(Clone, PartialEq, Eq)]
+pub struct EnableRequiredMemoTransfers<'a, 'b: 'a> {
+    pub token_program_id: &'a Pubkey,
+    pub account: &'b NoStdAccountInfo,
+    pub owner: CpiAuthority<'a, 'b>,
+}
+
+impl<'a, 'b: 'a> EnableRequiredMemoTransfers<'a, 'b> {
+    /// Consume arguments to perform CPI call.
+    #[inline(always)]
+    pub fn into_invoke(self) {
+        let Self {
+            token_program_id,
+            account,
+            owner,
+        } = self;
+
+        // Enable selector == 0.
+        _invoke_memo_transfer(token_program_id, account, &owner, 0);
+    }
+}
+
+/// Arguments for the disable required memo transfers instruction on the specified Token program,
+/// which stops requiring memos for transfers into this token account. Only the token account's
+/// owner can invoke this instruction.
+#[derive(Clone, PartialEq, Eq)]
+pub struct DisableRequiredMemoTransfers<'a, 'b: 'a> {
+    pub token_program_id: &'a Pubkey,
+    pub account: &'b NoStdAccountInfo,
+    pub owner: CpiAuthority<'a, 'b>,
+}
+
+impl<'a, 'b: 'a> DisableRequiredMemoTransfers<'a, 'b> {
+    /// Consume arguments to perform CPI call.
+    #[inline(always)]
+    pub fn into_invoke(self) {
+        let Self {
+            token_program_id,
+            account,
+            owner,
+        } = self;
+
+        // Disable selector == 1.
+        _invoke_memo_transfer(token_program_id, account, &owner, 1);
+    }
+}
+
+#[inline(always)]
+fn _invoke_memo_transfer(
+    token_program_id: &Pubkey,
+    account: &NoStdAccountInfo,
+    owner: &CpiAuthority,
+    selector: u8,
+) {
+    CpiInstruction {
+        program_id: token_program_id,
+        accounts: &[account.to_meta_c(), owner.to_meta_c_signer()],
+        // Memo transfer extension selector == 30.
+        data: &[30, selector],
+    }
+    .invoke_possibly_signed(
+        &[account.to_info_c(), owner.to_info_c()],
+        &[owner.signer_seeds],
+    );
+}
@@ -8,6 +8,7 @@
 //!
 //! [invoke_signed]: crate::cpi::invoke_signed
 
+mod cpi_guard;
 mod initialize_close_authority;
 // mod initialize_confidential_mint_burn;
 mod initialize_confidential_transfer;
@@ -20,7 +21,10 @@ mod initialize_non_transferable;
 mod initialize_permanent_delegate;
 mod initialize_transfer_fee_config;
 mod initialize_transfer_hook;
+mod memo_transfer;
+mod reallocate;
 
+pub use cpi_guard::*;
 pub use initialize_close_authority::*;
 // pub use initialize_confidential_mint_burn::*;
 pub use initialize_confidential_transfer::*;
@@ -33,6 +37,8 @@ pub use initialize_non_transferable::*;
 pub use initialize_permanent_delegate::*;
 pub use initialize_transfer_fee_config::*;
 pub use initialize_transfer_hook::*;
+pub use memo_transfer::*;
+pub use reallocate::*;
 
 use core::mem::size_of;
 
@@ -0,0 +1,219 @@
+use core::mem::size_of;
+
+use crate::{
+    cpi::{CpiAuthority, CpiInstruction},
+    entrypoint::{NoStdAccountInfo, ProgramResult},
+    error::SealevelToolsError,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    spl_token_2022::extension::ExtensionType,
+};
+
+/// If "alloc" feature is disabled, only this maximum number of extension types can be passed into
+/// [Reallocate] (returns [Err]).
+pub const MAX_REALLOCATE_EXTENSION_TYPES_NOALLOC: usize = 16;
+
+/// Arguments for the reallocate instruction on the Token Extensions program, which grows a token
+/// account to fit the specified extensions. This instruction is required before enabling
+/// extensions like required memo transfers or CPI guard on an existing token account (if it was
+/// not created with enough space). Only the token account's owner can invoke this instruction.
+///
+/// ### Notes
+///
+/// The Token Extensions program computes the new account length (including extensions that already
+/// exist) and transfers lamports from the payer to keep the account rent-exempt. Because the payer
+/// transfer is a System program CPI performed by the Token Extensions program, the System program
+/// account must be passed in.
+///
+/// If the "alloc" feature is disabled, this method will error out if the number of extension types
+/// exceeds [MAX_REALLOCATE_EXTENSION_TYPES_NOALLOC].
+#[derive(Clone, PartialEq)]
+pub struct Reallocate<'a, 'b: 'a> {
+    pub token_program_id: &'a Pubkey,
+    pub account: &'b NoStdAccountInfo,
+    pub payer: CpiAuthority<'a, 'b>,
+    pub system_program: &'b NoStdAccountInfo,
+    pub owner: CpiAuthority<'a, 'b>,
+    pub extension_types: &'a [ExtensionType],
+}
+
+impl<'a, 'b: 'a> Reallocate<'a, 'b> {
+    /// Try to consume arguments to perform CPI call.
+    #[inline(always)]
+    pub fn try_into_invoke(self) -> ProgramResult {
+        let Self {
+            token_program_id,
+            account,
+            payer,
+            system_program,
+            owner,
+            extension_types,
+        } = self;
+
+        #[cfg(feature = "alloc")]
+        let mut buf = alloc::vec![0; _instruction_data_len(extension_types.len())];
+
+        #[cfg(not(feature = "alloc"))]
+        let mut buf = [0; IX_DATA_LEN_NOALLOC];
+
+        let instruction_data = _serialize_instruction_data(extension_types, &mut buf)?;
+
+        _invoke_reallocate(
+            token_program_id,
+            account,
+            &payer,
+            system_program,
+            &owner,
+            instruction_data,
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "alloc"))]
+const IX_DATA_LEN_NOALLOC: usize = _instruction_data_len(MAX_REALLOCATE_EXTENSION_TYPES_NOALLOC);
+
+#[inline(always)]
+const fn _instruction_data_len(num_extension_types: usize) -> usize {
+    size_of::<u8>() // selector
+    + size_of::<u16>() * num_extension_types // extension types
+}
+
+/// Serialize the reallocate instruction data into the buffer, which must be large enough to fit
+/// all of the extension types.
+#[inline(always)]
+fn _serialize_instruction_data<'c>(
+    extension_types: &[ExtensionType],
+    buf: &'c mut [u8],
+) -> Result<&'c [u8], ProgramError> {
+    let data_len = _instruction_data_len(extension_types.len());
+
+    if data_len > buf.len() {
+        return Err(SealevelToolsError::Cpi(&["Extension types exceed max allowed"]).into());
+    }
+
+    let instruction_data = &mut buf[..data_len];
+
+    // Reallocate selector == 29.
+    instruction_data[0] = 29;
+    instruction_data[1..]
+        .chunks_exact_mut(size_of::<u16>())
+        .zip(extension_types)
+        .for_each(|(buf, extension_type)| buf.copy_from_slice(&<[u8; 2]>::from(*extension_type)));
+
+    Ok(instruction_data)
+}
+
+#[inline(always)]
+fn _invoke_reallocate(
+    token_program_id: &Pubkey,
+    account: &NoStdAccountInfo,
+    payer: &CpiAuthority,
+    system_program: &NoStdAccountInfo,
+    owner: &CpiAuthority,
+    data: &[u8],
+) {
+    CpiInstruction {
+        program_id: token_program_id,
+        accounts: &[
+            account.to_meta_c(),
+            payer.to_meta_c_signer(),
+            system_program.to_meta_c(),
+            owner.to_meta_c_signer(),
+        ],
+        data,
+    }
+    .invoke_possibly_signed(
+        &[
+            account.to_info_c(),
+            payer.to_info_c(),
+            system_program.to_info_c(),
+            owner.to_info_c(),
+        ],
+        &[payer.signer_seeds, owner.signer_seeds],
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_serialize_instruction_data() {
+        let extension_types = [
+            ExtensionType::MemoTransfer,
+            ExtensionType::CpiGuard,
+            ExtensionType::ImmutableOwner,
+        ];
+
+        let mut buf = [0; 16];
+        assert_eq!(
+            _serialize_instruction_data(&extension_types, &mut buf),
+            Ok(&spl_token_2022::instruction::reallocate(
+                &spl_token_2022::ID,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &[],
+                &extension_types,
+            )
+            .unwrap()
+            .data[..])
+        );
+        assert_eq!(_serialize_instruction_data(&[], &mut buf), Ok(&[29][..]));
+    }
+
+    #[test]
+    fn test_serialize_instruction_data_exceeds_buffer() {
+        let extension_types = [ExtensionType::MemoTransfer; MAX_REALLOCATE_EXTENSION_TYPES_NOALLOC];
+        let mut buf = [0; _instruction_data_len(MAX_REALLOCATE_EXTENSION_TYPES_NOALLOC)];
+        assert!(_serialize_instruction_data(&extension_types, &mut buf).is_ok());
+
+        let extension_types =
+            [ExtensionType::MemoTransfer; MAX_REALLOCATE_EXTENSION_TYPES_NOALLOC + 1];
+        assert_eq!(
+            _serialize_instruction_data(&extension_types, &mut buf),
+            Err(ProgramError::Custom(SealevelToolsError::CPI))
+        );
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    #[test]
+    fn test_reallocate_exceeds_max_extension_types_noalloc() {
+        use crate::test_fixture::{TestAccount, TestAccountInfos};
+
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(Pubkey::new_unique(), spl_token_2022::ID).writable(),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::default())
+                .signer()
+                .writable(),
+            TestAccount::new(Pubkey::default(), Pubkey::new_unique()).executable(),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::default()).signer(),
+        ]);
+        let [account, payer, system_program, owner] = &infos[..] else {
+            unreachable!()
+        };
+
+        let reallocate = |extension_types| {
+            Reallocate {
+                token_program_id: &spl_token_2022::ID,
+                account,
+                payer: CpiAuthority::from_signer(payer),
+                system_program,
+                owner: CpiAuthority::from_signer(owner),
+                extension_types,
+            }
+            .try_into_invoke()
+        };
+
+        assert_eq!(
+            reallocate(&[ExtensionType::MemoTransfer; MAX_REALLOCATE_EXTENSION_TYPES_NOALLOC]),
+            Ok(())
+        );
+        assert_eq!(
+            reallocate(&[ExtensionType::MemoTransfer; MAX_REALLOCATE_EXTENSION_TYPES_NOALLOC + 1]),
+            Err(ProgramError::Custom(SealevelToolsError::CPI))
+        );
+    }
+}
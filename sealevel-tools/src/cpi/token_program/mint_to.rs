@@ -1,9 +1,12 @@
 use crate::{
     cpi::{CpiAuthority, CpiInstruction},
-    entrypoint::NoStdAccountInfo,
+    entrypoint::{NoStdAccountInfo, ProgramResult},
+    program_error::ProgramError,
     pubkey::Pubkey,
 };
 
+use super::MultisigSigners;
+
 /// Arguments for the mint to instruction on the specified Token program, which mints a specified
 /// amount to a token account. Only the mint's authority can invoke this instruction.
 ///
@@ -112,4 +115,184 @@ impl<'a, 'b: 'a> MintTo<'a, 'b> {
             &[mint_authority.signer_seeds],
         );
     }
+
+    /// Try to consume arguments to perform CPI call, where [Self::mint_authority] is a multisig
+    /// account (its signer seeds are disregarded) and the given multisig signers sign on its behalf.
+    /// See [MultisigSigners] for more information.
+    #[inline(always)]
+    pub fn try_into_invoke_multisig(self, signers: MultisigSigners) -> ProgramResult {
+        let Self {
+            token_program_id,
+            mint,
+            destination,
+            mint_authority,
+            amount,
+        } = self;
+
+        // Mint to selector == 7.
+        let instruction_data = super::serialize_amount_instruction_data(7, amount);
+
+        super::_invoke_multisig_signed_without_additional_accounts(
+            token_program_id,
+            &[mint, destination],
+            mint_authority.account,
+            &signers,
+            &instruction_data,
+        )
+    }
+}
+
+/// Arguments for invoking the mint to instruction on the specified Token program for multiple
+/// destination token accounts. Like [MintTo], only the mint's authority can invoke this
+/// instruction.
+///
+/// ### Notes
+///
+/// One CPI call is made per recipient. The instruction data buffer and the mint authority (with its
+/// signer seeds) are set up once and reused for every call.
+///
+/// The total amount is checked for overflow before any CPI call is made. No heap memory is
+/// allocated, so there is no limit on the number of recipients other than the compute budget.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account_info::{
+///         try_next_enumerated_account, AccountInfoConstraints, ReadonlyAccount,
+///         WritableTokenProgramAccount,
+///     },
+///     cpi::token_program as token_program_cpi,
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+///     pubkey::Pubkey,
+/// };
+///
+/// sealevel_tools::declare_id!("Examp1eTokenManagement1111111111111111111111");
+///
+/// pub fn airdrop(accounts: &[NoStdAccountInfo], amount_per_recipient: u64) -> ProgramResult {
+///     let mut accounts_iter = accounts.iter().enumerate();
+///
+///     let (_, mint_account) = try_next_enumerated_account::<WritableTokenProgramAccount>(
+///         &mut accounts_iter,
+///         Default::default(),
+///     )?;
+///
+///     let (mint_authority_addr, mint_authority_bump) =
+///         Pubkey::find_program_address(&[b"authority"], &ID);
+///
+///     let (_, mint_authority) = try_next_enumerated_account::<ReadonlyAccount>(
+///         &mut accounts_iter,
+///         AccountInfoConstraints {
+///             key: Some(&mint_authority_addr),
+///             ..Default::default()
+///         },
+///     )?;
+///
+///     // Remaining accounts are the destination token accounts.
+///     let recipients = [
+///         (&accounts[2], amount_per_recipient),
+///         (&accounts[3], amount_per_recipient),
+///     ];
+///
+///     let _total_minted = token_program_cpi::MintToMany {
+///         token_program_id: mint_account.owner(),
+///         mint: &mint_account,
+///         mint_authority: mint_authority
+///             .as_cpi_authority(Some(&[b"authority", &[mint_authority_bump]])),
+///         recipients: &recipients,
+///     }
+///     .try_into_invoke()?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct MintToMany<'a, 'b: 'a> {
+    pub token_program_id: &'a Pubkey,
+    pub mint: &'b NoStdAccountInfo,
+    pub mint_authority: CpiAuthority<'a, 'b>,
+
+    /// Destination token accounts with their respective amounts to mint.
+    pub recipients: &'a [(&'b NoStdAccountInfo, u64)],
+}
+
+impl<'a, 'b: 'a> MintToMany<'a, 'b> {
+    /// Consume arguments to perform CPI calls. Returns the total amount minted.
+    #[inline(always)]
+    pub fn try_into_invoke(self) -> Result<u64, ProgramError> {
+        let Self {
+            token_program_id,
+            mint,
+            mint_authority,
+            recipients,
+        } = self;
+
+        let total_amount = recipients
+            .iter()
+            .try_fold(0_u64, |total, (_, amount)| total.checked_add(*amount))
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        // Mint to selector == 7. Only the amount is updated for each recipient.
+        let mut instruction_data = super::serialize_amount_instruction_data(7, 0);
+
+        for (destination, amount) in recipients {
+            instruction_data[1..9].copy_from_slice(&amount.to_le_bytes());
+
+            CpiInstruction {
+                program_id: token_program_id,
+                accounts: &[
+                    mint.to_meta_c(),
+                    destination.to_meta_c(),
+                    mint_authority.to_meta_c_signer(),
+                ],
+                data: &instruction_data,
+            }
+            .invoke_possibly_signed(
+                &[
+                    mint.to_info_c(),
+                    destination.to_info_c(),
+                    mint_authority.to_info_c(),
+                ],
+                &[mint_authority.signer_seeds],
+            );
+        }
+
+        Ok(total_amount)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_fixture::{TestAccount, TestAccountInfos};
+
+    use super::*;
+
+    #[test]
+    fn test_mint_to_many_total_amount() {
+        let token_program_id = Pubkey::new_unique();
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(Pubkey::new_unique(), token_program_id).writable(),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique()).signer(),
+            TestAccount::new(Pubkey::new_unique(), token_program_id).writable(),
+            TestAccount::new(Pubkey::new_unique(), token_program_id).writable(),
+        ]);
+
+        let mint_to_many = |recipients| MintToMany {
+            token_program_id: &token_program_id,
+            mint: &infos[0],
+            mint_authority: CpiAuthority::from_signer(&infos[1]),
+            recipients,
+        };
+
+        // Number of recipients is not limited.
+        let recipients = [(&infos[2], 1), (&infos[3], 2)].repeat(9);
+        assert_eq!(mint_to_many(&recipients).try_into_invoke(), Ok(27));
+
+        assert_eq!(mint_to_many(&[]).try_into_invoke(), Ok(0));
+
+        assert_eq!(
+            mint_to_many(&[(&infos[2], u64::MAX), (&infos[3], 1)]).try_into_invoke(),
+            Err(ProgramError::ArithmeticOverflow)
+        );
+    }
 }
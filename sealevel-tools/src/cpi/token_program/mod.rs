@@ -9,9 +9,22 @@
 //! See detailed examples of how to perform CPI with [CreateMint], [CreateTokenAccount], [MintTo]
 //! and [Transfer].
 //!
+//! ### Signers
+//!
+//! Token program instructions are signed by a single authority (the mint, freeze, owner or
+//! delegate authority). If this authority is a multisig account, its signers sign on its behalf
+//! instead. Builders that take an authority have a `try_into_invoke_multisig` method, which takes
+//! up to two signers with [MultisigSigners] (where both may be PDAs of the executing program).
+//!
+//! The builders that create accounts via the System program take [CpiAuthority] for both `payer`
+//! and the new account (e.g. [CreateMint] and [CreateTokenAccount]), mirroring [CreateAccount].
+//!
+//! [CpiAuthority]: crate::cpi::CpiAuthority
+//! [CreateAccount]: crate::cpi::system_program::CreateAccount
 //! [invoke_signed]: crate::cpi::invoke_signed
 
 mod approve;
+mod bootstrap_mint;
 mod burn;
 mod close_account;
 mod create_mint;
@@ -21,13 +34,16 @@ mod freeze_account;
 #[cfg(feature = "alloc")]
 mod get_account_data_size;
 mod mint_to;
+mod multisig;
 mod revoke;
 mod set_authority;
 mod sync_native;
 mod thaw_account;
 mod transfer;
+mod transfer_hook;
 
 pub use approve::*;
+pub use bootstrap_mint::*;
 pub use burn::*;
 pub use close_account::*;
 pub use create_mint::*;
@@ -36,11 +52,13 @@ pub use freeze_account::*;
 #[cfg(feature = "alloc")]
 pub use get_account_data_size::*;
 pub use mint_to::*;
+pub use multisig::*;
 pub use revoke::*;
 pub use set_authority::*;
 pub use sync_native::*;
 pub use thaw_account::*;
 pub use transfer::*;
+pub use transfer_hook::*;
 
 pub use spl_token_2022::{extension::ExtensionType, instruction::AuthorityType};
 
@@ -0,0 +1,237 @@
+use crate::{
+    cpi::{CpiAccounts, CpiAuthority, CpiInstruction},
+    entrypoint::{NoStdAccountInfo, ProgramResult},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Maximum number of accounts for a Token program instruction invoked with [MultisigSigners] (up
+/// to two instruction accounts, the multisig authority and two signers).
+const MULTISIG_CPI_ACCOUNTS: usize = 5;
+
+/// Signers of a multisig authority (see [Multisig]), which sign on behalf of the multisig account
+/// for a Token program instruction. Either signer may be a PDA of the executing program (e.g. a
+/// vault authority and a fee authority), so up to two sets of signer seeds are passed into the CPI
+/// call.
+///
+/// The builders that take an authority (e.g. [Transfer], [Burn] and [MintTo]) have a
+/// `try_into_invoke_multisig` method, where the builder's authority is the multisig account and
+/// these signers follow it in the instruction's accounts.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     cpi::{
+///         token_program::{MultisigSigners, Transfer},
+///         CpiAuthority,
+///     },
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+/// };
+///
+/// fn transfer_from_multisig_owner(
+///     source: &NoStdAccountInfo,
+///     destination: &NoStdAccountInfo,
+///     multisig: &NoStdAccountInfo,
+///     vault_authority: &NoStdAccountInfo,
+///     fee_authority: &NoStdAccountInfo,
+///     vault_bump: u8,
+///     fee_bump: u8,
+///     amount: u64,
+/// ) -> ProgramResult {
+///     Transfer {
+///         token_program_id: source.owner(),
+///         source,
+///         destination,
+///         authority: CpiAuthority::from_signer(multisig),
+///         amount,
+///         checked: None,
+///     }
+///     .try_into_invoke_multisig(MultisigSigners {
+///         signer: CpiAuthority::from_pda(vault_authority, &[b"vault", &[vault_bump]]),
+///         second_signer: Some(CpiAuthority::from_pda(fee_authority, &[b"fee", &[fee_bump]])),
+///     })
+/// }
+/// ```
+///
+/// [Burn]: super::Burn
+/// [MintTo]: super::MintTo
+/// [Multisig]: spl_token_2022::state::Multisig
+/// [Transfer]: super::Transfer
+#[derive(Clone, PartialEq, Eq)]
+pub struct MultisigSigners<'a, 'b: 'a> {
+    pub signer: CpiAuthority<'a, 'b>,
+    pub second_signer: Option<CpiAuthority<'a, 'b>>,
+}
+
+/// Invoke a Token program instruction whose authority is a multisig account. The instruction's
+/// accounts are followed by the multisig account, its signers and any additional accounts (e.g.
+/// accounts needed for transfer hook CPI).
+#[inline(always)]
+pub(super) fn _invoke_multisig_signed<const CPI_ACCOUNTS_CAP: usize>(
+    token_program_id: &Pubkey,
+    accounts: &[&NoStdAccountInfo],
+    multisig: &NoStdAccountInfo,
+    signers: &MultisigSigners,
+    additional_accounts: &[NoStdAccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let cpi_accounts = _multisig_cpi_accounts::<CPI_ACCOUNTS_CAP>(
+        accounts,
+        multisig,
+        signers,
+        additional_accounts,
+    )?;
+
+    CpiInstruction {
+        program_id: token_program_id,
+        accounts: cpi_accounts.metas(),
+        data,
+    }
+    .invoke_possibly_signed(
+        cpi_accounts.infos(),
+        &[
+            signers.signer.signer_seeds,
+            signers
+                .second_signer
+                .as_ref()
+                .and_then(|signer| signer.signer_seeds),
+        ],
+    );
+
+    Ok(())
+}
+
+/// Like [_invoke_multisig_signed] for instructions that have at most two accounts before the
+/// multisig authority.
+#[inline(always)]
+pub(super) fn _invoke_multisig_signed_without_additional_accounts(
+    token_program_id: &Pubkey,
+    accounts: &[&NoStdAccountInfo],
+    multisig: &NoStdAccountInfo,
+    signers: &MultisigSigners,
+    data: &[u8],
+) -> ProgramResult {
+    _invoke_multisig_signed::<MULTISIG_CPI_ACCOUNTS>(
+        token_program_id,
+        accounts,
+        multisig,
+        signers,
+        &[],
+        data,
+    )
+}
+
+#[inline(always)]
+fn _multisig_cpi_accounts<const CPI_ACCOUNTS_CAP: usize>(
+    accounts: &[&NoStdAccountInfo],
+    multisig: &NoStdAccountInfo,
+    MultisigSigners {
+        signer,
+        second_signer,
+    }: &MultisigSigners,
+    additional_accounts: &[NoStdAccountInfo],
+) -> Result<CpiAccounts<CPI_ACCOUNTS_CAP>, ProgramError> {
+    let mut cpi_accounts = CpiAccounts::new();
+
+    accounts
+        .iter()
+        .try_for_each(|account| cpi_accounts.try_push_account(account))?;
+
+    // The multisig account itself does not sign.
+    cpi_accounts.try_push(multisig.to_meta_c(), multisig.to_info_c())?;
+    cpi_accounts.try_push_signer(signer)?;
+
+    if let Some(second_signer) = second_signer {
+        cpi_accounts.try_push_signer(second_signer)?;
+    }
+
+    cpi_accounts.try_extend(additional_accounts)?;
+
+    Ok(cpi_accounts)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error::SealevelToolsError,
+        test_fixture::{TestAccount, TestAccountInfos},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_multisig_cpi_accounts() {
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique()).writable(),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique()).writable(),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique()),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique()),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique()),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique()),
+        ]);
+        let [source, destination, multisig, vault_authority, fee_authority, extra] = &infos[..]
+        else {
+            unreachable!()
+        };
+
+        let signers = MultisigSigners {
+            signer: CpiAuthority::from_pda(vault_authority, &[b"vault"]),
+            second_signer: Some(CpiAuthority::from_pda(fee_authority, &[b"fee"])),
+        };
+
+        let cpi_accounts = _multisig_cpi_accounts::<MULTISIG_CPI_ACCOUNTS>(
+            &[source, destination],
+            multisig,
+            &signers,
+            &[],
+        )
+        .unwrap();
+
+        let expected = [
+            (source, false, true),
+            (destination, false, true),
+            (multisig, false, false),
+            (vault_authority, true, false),
+            (fee_authority, true, false),
+        ];
+        assert_eq!(cpi_accounts.len(), expected.len());
+
+        for (meta, (account, is_signer, is_writable)) in cpi_accounts.metas().iter().zip(expected) {
+            assert_eq!(unsafe { &*meta.pubkey }, account.key());
+            assert_eq!(meta.is_signer, is_signer);
+            assert_eq!(meta.is_writable, is_writable);
+        }
+
+        // Single signer with additional accounts.
+        let cpi_accounts = _multisig_cpi_accounts::<MULTISIG_CPI_ACCOUNTS>(
+            &[source, destination],
+            multisig,
+            &MultisigSigners {
+                second_signer: None,
+                ..signers.clone()
+            },
+            core::slice::from_ref(extra),
+        )
+        .unwrap();
+        assert_eq!(cpi_accounts.len(), 5);
+        assert_eq!(
+            unsafe { &*cpi_accounts.metas()[3].pubkey },
+            vault_authority.key()
+        );
+        assert_eq!(unsafe { &*cpi_accounts.metas()[4].pubkey }, extra.key());
+        assert!(!cpi_accounts.metas()[4].is_signer);
+
+        // Additional accounts exceed capacity.
+        assert_eq!(
+            _multisig_cpi_accounts::<MULTISIG_CPI_ACCOUNTS>(
+                &[source, destination],
+                multisig,
+                &signers,
+                core::slice::from_ref(extra),
+            )
+            .err(),
+            Some(ProgramError::Custom(SealevelToolsError::CPI))
+        );
+    }
+}
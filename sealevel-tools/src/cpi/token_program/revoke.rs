@@ -1,9 +1,11 @@
 use crate::{
     cpi::{CpiAuthority, CpiInstruction},
-    entrypoint::NoStdAccountInfo,
+    entrypoint::{NoStdAccountInfo, ProgramResult},
     pubkey::Pubkey,
 };
 
+use super::MultisigSigners;
+
 /// Arguments for the revoke instruction on the specified Token program, which revokes the delegated
 /// amount on a token account. Only the token account's owner can invoke this instruction.
 #[derive(Clone, PartialEq, Eq)]
@@ -34,4 +36,25 @@ impl<'a, 'b: 'a> Revoke<'a, 'b> {
             &[authority.signer_seeds],
         );
     }
+
+    /// Try to consume arguments to perform CPI call, where [Self::authority] is a multisig account
+    /// (its signer seeds are disregarded) and the given multisig signers sign on its behalf. See
+    /// [MultisigSigners] for more information.
+    #[inline(always)]
+    pub fn try_into_invoke_multisig(self, signers: MultisigSigners) -> ProgramResult {
+        let Self {
+            token_program_id,
+            source,
+            authority,
+        } = self;
+
+        super::_invoke_multisig_signed_without_additional_accounts(
+            token_program_id,
+            &[source],
+            authority.account,
+            &signers,
+            // Revoke selector == 5.
+            &[5],
+        )
+    }
 }
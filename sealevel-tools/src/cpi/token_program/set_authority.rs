@@ -2,11 +2,11 @@ use core::mem::size_of;
 
 use crate::{
     cpi::{CpiAuthority, CpiInstruction},
-    entrypoint::NoStdAccountInfo,
+    entrypoint::{NoStdAccountInfo, ProgramResult},
     pubkey::Pubkey,
 };
 
-use super::AuthorityType;
+use super::{AuthorityType, MultisigSigners};
 
 /// Arguments for the set authority instruction on the specified Token program, which sets a new
 /// authority for either mint or token account (depending on the [AuthorityType]). Only the current
@@ -32,22 +32,8 @@ impl<'a, 'b: 'a> SetAuthority<'a, 'b> {
             new_authority,
         } = self;
 
-        const IX_DATA_LEN: usize = {
-            size_of::<u8>() // selector
-            + size_of::<u8>() // authority_type
-            + size_of::<u8>() + size_of::<Pubkey>() // new_authority
-        };
-
-        let mut instruction_data = [0; IX_DATA_LEN];
-
-        // Set authority selector == 6.
-        instruction_data[0] = 6;
-        instruction_data[1] = authority_type as u8;
-
-        if let Some(new_authority) = new_authority {
-            instruction_data[2] = 1;
-            instruction_data[3..35].copy_from_slice(&new_authority.to_bytes());
-        }
+        let instruction_data =
+            _serialize_set_authority_instruction_data(authority_type, new_authority);
 
         CpiInstruction {
             program_id: token_program_id,
@@ -59,4 +45,90 @@ impl<'a, 'b: 'a> SetAuthority<'a, 'b> {
             &[authority.signer_seeds],
         );
     }
+
+    /// Try to consume arguments to perform CPI call, where [Self::authority] is a multisig account
+    /// (its signer seeds are disregarded) and the given multisig signers sign on its behalf. See
+    /// [MultisigSigners] for more information.
+    #[inline(always)]
+    pub fn try_into_invoke_multisig(self, signers: MultisigSigners) -> ProgramResult {
+        let Self {
+            token_program_id,
+            account,
+            authority,
+            authority_type,
+            new_authority,
+        } = self;
+
+        let instruction_data =
+            _serialize_set_authority_instruction_data(authority_type, new_authority);
+
+        super::_invoke_multisig_signed_without_additional_accounts(
+            token_program_id,
+            &[account],
+            authority.account,
+            &signers,
+            &instruction_data,
+        )
+    }
+}
+
+/// Arguments for the set authority instruction on the specified Token program, which permanently
+/// disables minting by removing the mint authority (i.e. fixing the mint's supply). Only the
+/// current mint authority can invoke this instruction.
+///
+/// ### Notes
+///
+/// This is equivalent to [SetAuthority] with [AuthorityType::MintTokens] and no new authority.
+/// This action cannot be undone.
+#[derive(Clone, PartialEq)]
+pub struct DisableMintAuthority<'a, 'b: 'a> {
+    pub token_program_id: &'a Pubkey,
+    pub mint: &'b NoStdAccountInfo,
+    pub current_authority: CpiAuthority<'a, 'b>,
+}
+
+impl<'a, 'b: 'a> DisableMintAuthority<'a, 'b> {
+    /// Consume arguments to perform CPI call.
+    #[inline(always)]
+    pub fn into_invoke(self) {
+        let Self {
+            token_program_id,
+            mint,
+            current_authority,
+        } = self;
+
+        SetAuthority {
+            token_program_id,
+            account: mint,
+            authority: current_authority,
+            authority_type: AuthorityType::MintTokens,
+            new_authority: None,
+        }
+        .into_invoke()
+    }
+}
+
+const IX_SET_AUTHORITY_DATA_LEN: usize = {
+    size_of::<u8>() // selector
+    + size_of::<u8>() // authority_type
+    + size_of::<u8>() + size_of::<Pubkey>() // new_authority
+};
+
+#[inline(always)]
+fn _serialize_set_authority_instruction_data(
+    authority_type: AuthorityType,
+    new_authority: Option<&Pubkey>,
+) -> [u8; IX_SET_AUTHORITY_DATA_LEN] {
+    let mut instruction_data = [0; IX_SET_AUTHORITY_DATA_LEN];
+
+    // Set authority selector == 6.
+    instruction_data[0] = 6;
+    instruction_data[1] = authority_type as u8;
+
+    if let Some(new_authority) = new_authority {
+        instruction_data[2] = 1;
+        instruction_data[3..35].copy_from_slice(&new_authority.to_bytes());
+    }
+
+    instruction_data
 }
@@ -1,9 +1,11 @@
 use crate::{
     cpi::{CpiAuthority, CpiInstruction},
-    entrypoint::NoStdAccountInfo,
+    entrypoint::{NoStdAccountInfo, ProgramResult},
     pubkey::Pubkey,
 };
 
+use super::MultisigSigners;
+
 /// Arguments for the thaw account instruction on the specified Token program, which unfreezes a
 /// token account. Only the mint's freeze authority can invoke this instruction.
 #[derive(Clone, PartialEq, Eq)]
@@ -44,4 +46,26 @@ impl<'a, 'b: 'a> ThawAccount<'a, 'b> {
             &[freeze_authority.signer_seeds],
         );
     }
+
+    /// Try to consume arguments to perform CPI call, where [Self::freeze_authority] is a multisig account
+    /// (its signer seeds are disregarded) and the given multisig signers sign on its behalf. See
+    /// [MultisigSigners] for more information.
+    #[inline(always)]
+    pub fn try_into_invoke_multisig(self, signers: MultisigSigners) -> ProgramResult {
+        let Self {
+            token_program_id,
+            account,
+            mint,
+            freeze_authority,
+        } = self;
+
+        super::_invoke_multisig_signed_without_additional_accounts(
+            token_program_id,
+            &[account, mint],
+            freeze_authority.account,
+            &signers,
+            // Thaw account selector == 11.
+            &[11],
+        )
+    }
 }
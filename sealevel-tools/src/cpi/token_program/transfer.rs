@@ -4,13 +4,21 @@ use alloc::vec;
 use crate::{
     cpi::{CpiAuthority, CpiInstruction},
     entrypoint::{NoStdAccountInfo, ProgramResult},
+    pda::append_bump_seed,
     pubkey::Pubkey,
 };
 
+use super::MultisigSigners;
+
 /// If "alloc" feature is disabled, only this maximum number of additional accounts can be passed
 /// into [Transfer] (panics) and [TransferChecked] (returns [Err]).
 pub const MAX_ADDITIONAL_ACCOUNTS_NOALLOC: usize = 12;
 
+/// Capacity of the stack array of CPI accounts used by [Transfer::try_into_invoke_multisig], which
+/// includes the source, mint, destination, multisig authority and its two signers followed by
+/// [MAX_ADDITIONAL_ACCOUNTS_NOALLOC] additional accounts.
+const TRANSFER_CHECKED_MULTISIG_CPI_ACCOUNTS: usize = 6 + MAX_ADDITIONAL_ACCOUNTS_NOALLOC;
+
 /// Arguments for the transfer instruction on the specified Token program, which moves a specified
 /// amount of tokens from the source to destination token account.
 ///
@@ -197,7 +205,7 @@ impl<'a, 'b: 'a> Transfer<'a, 'b> {
         } = self;
 
         match checked {
-            Some(checked) => _invoke_transfer_checked(
+            Some(checked) => _invoke_transfer_checked::<MAX_ADDITIONAL_ACCOUNTS_NOALLOC>(
                 token_program_id,
                 source,
                 destination,
@@ -208,6 +216,65 @@ impl<'a, 'b: 'a> Transfer<'a, 'b> {
             None => _invoke_transfer(token_program_id, source, destination, &authority, amount),
         }
     }
+
+    /// Try to consume arguments to perform CPI call, where [Self::authority] is a multisig account
+    /// (its signer seeds are disregarded) and the given multisig signers sign on its behalf. See
+    /// [MultisigSigners] for more information.
+    ///
+    /// ### Notes
+    ///
+    /// Regardless of whether the "alloc" feature is enabled, this method will error out if the
+    /// number of additional accounts exceeds [MAX_ADDITIONAL_ACCOUNTS_NOALLOC].
+    #[inline(always)]
+    pub fn try_into_invoke_multisig(self, signers: MultisigSigners) -> ProgramResult {
+        let Self {
+            token_program_id,
+            source,
+            destination,
+            authority,
+            amount,
+            checked,
+        } = self;
+
+        match checked {
+            Some(UseTransferChecked {
+                mint,
+                decimals,
+                additional_accounts,
+            }) => {
+                // Transfer checked selector == 12.
+                let instruction_data =
+                    super::serialize_checked_amount_instruction_data(12, amount, decimals);
+
+                // Like the transfer checked instruction without a multisig authority, additional
+                // accounts only apply to the Token Extensions program.
+                let additional_accounts = additional_accounts
+                    .filter(|_| token_program_id == &spl_token_2022::ID)
+                    .unwrap_or_default();
+
+                super::_invoke_multisig_signed::<TRANSFER_CHECKED_MULTISIG_CPI_ACCOUNTS>(
+                    token_program_id,
+                    &[source, mint, destination],
+                    authority.account,
+                    &signers,
+                    additional_accounts,
+                    &instruction_data,
+                )
+            }
+            None => {
+                // Transfer selector == 3.
+                let instruction_data = super::serialize_amount_instruction_data(3, amount);
+
+                super::_invoke_multisig_signed_without_additional_accounts(
+                    token_program_id,
+                    &[source, destination],
+                    authority.account,
+                    &signers,
+                    &instruction_data,
+                )
+            }
+        }
+    }
 }
 
 /// Arguments for the transfer checked instruction on the specified Token program, which moves
@@ -216,9 +283,47 @@ impl<'a, 'b: 'a> Transfer<'a, 'b> {
 /// delegated authority can invoke this instruction.
 ///
 /// If the "alloc" feature is disabled, this method will error out if the number of additional
-/// accounts exceeds [MAX_ADDITIONAL_ACCOUNTS_NOALLOC]. Otherwise this method should be infallible.
+/// accounts exceeds `MAX_EXTRA` (by default [MAX_ADDITIONAL_ACCOUNTS_NOALLOC]). Otherwise this
+/// method should be infallible.
+///
+/// ### Notes
+///
+/// Without "alloc", the CPI accounts are collected in a stack array with the source, mint,
+/// destination and authority accounts followed by space for `MAX_EXTRA` additional accounts. If a
+/// transfer hook needs more additional accounts, raise `MAX_EXTRA` at compile time (trading stack
+/// space for more accounts):
+/// ```
+/// use sealevel_tools::{
+///     cpi::{token_program::TransferChecked, CpiAuthority},
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+/// };
+///
+/// fn transfer_with_hook(
+///     source: &NoStdAccountInfo,
+///     mint: &NoStdAccountInfo,
+///     destination: &NoStdAccountInfo,
+///     authority: CpiAuthority,
+///     amount: u64,
+///     decimals: u8,
+///     additional_accounts: &[NoStdAccountInfo],
+/// ) -> ProgramResult {
+///     // Allow up to 20 additional accounts.
+///     TransferChecked::<20> {
+///         token_program_id: mint.owner(),
+///         source,
+///         mint,
+///         destination,
+///         authority,
+///         amount,
+///         decimals,
+///         additional_accounts: Some(additional_accounts),
+///     }
+///     .try_into_invoke()
+/// }
+/// ```
+/// `MAX_EXTRA` has no effect if the "alloc" feature is enabled.
 #[derive(Clone, PartialEq, Eq)]
-pub struct TransferChecked<'a, 'b: 'a> {
+pub struct TransferChecked<'a, 'b: 'a, const MAX_EXTRA: usize = MAX_ADDITIONAL_ACCOUNTS_NOALLOC> {
     pub token_program_id: &'a Pubkey,
     pub source: &'b NoStdAccountInfo,
     pub mint: &'b NoStdAccountInfo,
@@ -235,7 +340,7 @@ pub struct TransferChecked<'a, 'b: 'a> {
     pub additional_accounts: Option<&'a [NoStdAccountInfo]>,
 }
 
-impl<'a, 'b: 'a> TransferChecked<'a, 'b> {
+impl<'a, 'b: 'a, const MAX_EXTRA: usize> TransferChecked<'a, 'b, MAX_EXTRA> {
     /// Tries to consume arguments to perform CPI call.
     #[inline(always)]
     pub fn try_into_invoke(self) -> ProgramResult {
@@ -252,7 +357,7 @@ impl<'a, 'b: 'a> TransferChecked<'a, 'b> {
 
         #[cfg(feature = "alloc")]
         {
-            _invoke_transfer_checked(
+            _invoke_transfer_checked::<MAX_EXTRA>(
                 token_program_id,
                 source,
                 destination,
@@ -269,15 +374,13 @@ impl<'a, 'b: 'a> TransferChecked<'a, 'b> {
         }
 
         #[cfg(not(feature = "alloc"))]
-        if additional_accounts
-            .is_some_and(|accounts| accounts.len() > MAX_ADDITIONAL_ACCOUNTS_NOALLOC)
-        {
+        if additional_accounts.is_some_and(|accounts| accounts.len() > MAX_EXTRA) {
             return Err(crate::error::SealevelToolsError::Cpi(&[
                 "Additional accounts exceed max allowed",
             ])
             .into());
         } else {
-            _invoke_transfer_checked(
+            _invoke_transfer_checked::<MAX_EXTRA>(
                 token_program_id,
                 source,
                 destination,
@@ -295,6 +398,108 @@ impl<'a, 'b: 'a> TransferChecked<'a, 'b> {
     }
 }
 
+/// Arguments for a transfer (or transfer checked) instruction on the specified Token program where
+/// the authority of the source token account is a PDA of the executing program (e.g. a vault
+/// authority). The signer seeds are built from the authority's seeds and its known bump, so no
+/// address is derived with [Pubkey::find_program_address].
+///
+/// ### Notes
+///
+/// The bump is not verified by this method. If the seeds and bump do not derive the authority's
+/// key, the Token program will fail because the authority did not sign.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     account_info::{
+///         try_next_enumerated_account, ReadonlyAccount, WritableAccount,
+///         WritableTokenProgramAccount,
+///     },
+///     cpi::token_program::TransferFromPda,
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+/// };
+///
+/// pub fn withdraw(accounts: &[NoStdAccountInfo], amount: u64, vault_bump: u8) -> ProgramResult {
+///     let mut accounts_iter = accounts.iter().enumerate();
+///
+///     let (_, vault_token_account) = try_next_enumerated_account::<WritableTokenProgramAccount>(
+///         &mut accounts_iter,
+///         Default::default(),
+///     )?;
+///     let (_, destination_account) =
+///         try_next_enumerated_account::<WritableAccount>(&mut accounts_iter, Default::default())?;
+///     let (_, vault_authority) =
+///         try_next_enumerated_account::<ReadonlyAccount>(&mut accounts_iter, Default::default())?;
+///
+///     TransferFromPda {
+///         token_program_id: vault_token_account.owner(),
+///         source: &vault_token_account,
+///         destination: &destination_account,
+///         authority: &vault_authority,
+///         authority_seeds: &[b"vault"],
+///         authority_bump: vault_bump,
+///         amount,
+///         checked: None,
+///     }
+///     .try_into_invoke()
+/// }
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct TransferFromPda<'a, 'b: 'a> {
+    pub token_program_id: &'a Pubkey,
+    pub source: &'b NoStdAccountInfo,
+    pub destination: &'b NoStdAccountInfo,
+
+    /// PDA that is either the owner or delegated authority of the source token account.
+    pub authority: &'b NoStdAccountInfo,
+
+    /// Seeds used to derive the authority's address (excluding the bump seed).
+    pub authority_seeds: &'a [&'b [u8]],
+    pub authority_bump: u8,
+    pub amount: u64,
+
+    /// If [Some], the transfer checked instruction will be used instead of the deprecated transfer
+    /// instruction. See [UseTransferChecked] for more information about its usage.
+    pub checked: Option<UseTransferChecked<'b>>,
+}
+
+impl<'a, 'b: 'a> TransferFromPda<'a, 'b> {
+    /// Try to consume arguments to perform CPI call. This method fails with
+    /// [ProgramError::MaxSeedLengthExceeded] if there are too many authority seeds to append the
+    /// bump seed (see [append_bump_seed]).
+    ///
+    /// [ProgramError::MaxSeedLengthExceeded]: crate::program_error::ProgramError::MaxSeedLengthExceeded
+    #[inline(always)]
+    pub fn try_into_invoke(self) -> ProgramResult {
+        let Self {
+            token_program_id,
+            source,
+            destination,
+            authority,
+            authority_seeds,
+            authority_bump,
+            amount,
+            checked,
+        } = self;
+
+        let bump_seed = [authority_bump];
+        let (signer_seeds, len) = append_bump_seed(authority_seeds, &bump_seed)?;
+
+        Transfer {
+            token_program_id,
+            source,
+            destination,
+            authority: CpiAuthority::from_pda(authority, &signer_seeds[..len]),
+            amount,
+            checked,
+        }
+        .into_invoke();
+
+        Ok(())
+    }
+}
+
 #[inline(always)]
 fn _invoke_transfer(
     token_program_id: &Pubkey,
@@ -326,7 +531,7 @@ fn _invoke_transfer(
 }
 
 #[inline(always)]
-fn _invoke_transfer_checked(
+fn _invoke_transfer_checked<const MAX_EXTRA: usize>(
     token_program_id: &Pubkey,
     source: &NoStdAccountInfo,
     destination: &NoStdAccountInfo,
@@ -345,7 +550,7 @@ fn _invoke_transfer_checked(
         Some(account_infos)
             if token_program_id == &spl_token_2022::ID && !account_infos.is_empty() =>
         {
-            _invoke_transfer_checked_with_additional_accounts(
+            _invoke_transfer_checked_with_additional_accounts::<MAX_EXTRA>(
                 token_program_id,
                 source,
                 mint,
@@ -398,7 +603,7 @@ fn _invoke_transfer_checked_without_additional_accounts(
 
 #[cfg(feature = "alloc")]
 #[inline(always)]
-fn _invoke_transfer_checked_with_additional_accounts(
+fn _invoke_transfer_checked_with_additional_accounts<const MAX_EXTRA: usize>(
     token_program_id: &Pubkey,
     source: &NoStdAccountInfo,
     mint: &NoStdAccountInfo,
@@ -435,662 +640,172 @@ fn _invoke_transfer_checked_with_additional_accounts(
 }
 
 #[cfg(not(feature = "alloc"))]
-use __noalloc::_invoke_transfer_checked_with_additional_accounts;
+#[inline(always)]
+fn _invoke_transfer_checked_with_additional_accounts<const MAX_EXTRA: usize>(
+    token_program_id: &Pubkey,
+    source: &NoStdAccountInfo,
+    mint: &NoStdAccountInfo,
+    destination: &NoStdAccountInfo,
+    authority: &CpiAuthority,
+    data: &[u8],
+    additional_accounts: &[NoStdAccountInfo],
+) {
+    if additional_accounts.len() > MAX_EXTRA {
+        panic!("Too many additional accounts passed in for transfer checked CPI");
+    }
 
-#[cfg(not(feature = "alloc"))]
-mod __noalloc {
-    use super::*;
+    let mut metas = FixedThenExtra::<_, 4, MAX_EXTRA>::new([
+        source.to_meta_c(),
+        mint.to_meta_c(),
+        destination.to_meta_c(),
+        authority.to_meta_c_signer(),
+    ]);
+    let mut infos = FixedThenExtra::<_, 4, MAX_EXTRA>::new([
+        source.to_info_c(),
+        mint.to_info_c(),
+        destination.to_info_c(),
+        authority.to_info_c(),
+    ]);
 
-    #[inline(always)]
-    pub fn _invoke_transfer_checked_with_additional_accounts(
-        token_program_id: &Pubkey,
-        source: &NoStdAccountInfo,
-        mint: &NoStdAccountInfo,
-        destination: &NoStdAccountInfo,
-        authority: &CpiAuthority,
-        data: &[u8],
-        additional_accounts: &[NoStdAccountInfo],
-    ) {
-        match additional_accounts.len() {
-            1 => _invoke_transfer_checked_with_additional_accounts_1(
-                token_program_id,
-                source,
-                mint,
-                destination,
-                authority,
-                data,
-                additional_accounts,
-            ),
-            2 => _invoke_transfer_checked_with_additional_accounts_2(
-                token_program_id,
-                source,
-                mint,
-                destination,
-                authority,
-                data,
-                additional_accounts,
-            ),
-            3 => _invoke_transfer_checked_with_additional_accounts_3(
-                token_program_id,
-                source,
-                mint,
-                destination,
-                authority,
-                data,
-                additional_accounts,
-            ),
-            4 => _invoke_transfer_checked_with_additional_accounts_4(
-                token_program_id,
-                source,
-                mint,
-                destination,
-                authority,
-                data,
-                additional_accounts,
-            ),
-            5 => _invoke_transfer_checked_with_additional_accounts_5(
-                token_program_id,
-                source,
-                mint,
-                destination,
-                authority,
-                data,
-                additional_accounts,
-            ),
-            6 => _invoke_transfer_checked_with_additional_accounts_6(
-                token_program_id,
-                source,
-                mint,
-                destination,
-                authority,
-                data,
-                additional_accounts,
-            ),
-            7 => _invoke_transfer_checked_with_additional_accounts_7(
-                token_program_id,
-                source,
-                mint,
-                destination,
-                authority,
-                data,
-                additional_accounts,
-            ),
-            8 => _invoke_transfer_checked_with_additional_accounts_8(
-                token_program_id,
-                source,
-                mint,
-                destination,
-                authority,
-                data,
-                additional_accounts,
-            ),
-            9 => _invoke_transfer_checked_with_additional_accounts_9(
-                token_program_id,
-                source,
-                mint,
-                destination,
-                authority,
-                data,
-                additional_accounts,
-            ),
-            10 => _invoke_transfer_checked_with_additional_accounts_10(
-                token_program_id,
-                source,
-                mint,
-                destination,
-                authority,
-                data,
-                additional_accounts,
-            ),
-            11 => _invoke_transfer_checked_with_additional_accounts_11(
-                token_program_id,
-                source,
-                mint,
-                destination,
-                authority,
-                data,
-                additional_accounts,
-            ),
-            MAX_ADDITIONAL_ACCOUNTS_NOALLOC => {
-                _invoke_transfer_checked_with_additional_accounts_12(
-                    token_program_id,
-                    source,
-                    mint,
-                    destination,
-                    authority,
-                    data,
-                    additional_accounts,
-                )
-            }
-            _ => panic!("Too many additional accounts passed in for transfer checked CPI"),
-        }
+    for account in additional_accounts {
+        metas.push(account.to_meta_c());
+        infos.push(account.to_info_c());
     }
 
-    #[inline(always)]
-    fn _invoke_transfer_checked_with_additional_accounts_1(
-        token_program_id: &Pubkey,
-        source: &NoStdAccountInfo,
-        mint: &NoStdAccountInfo,
-        destination: &NoStdAccountInfo,
-        authority: &CpiAuthority,
-        data: &[u8],
-        additional_accounts: &[NoStdAccountInfo],
-    ) {
-        CpiInstruction {
-            program_id: token_program_id,
-            accounts: &[
-                source.to_meta_c(),
-                mint.to_meta_c(),
-                destination.to_meta_c(),
-                authority.to_meta_c_signer(),
-                additional_accounts[0].to_meta_c(),
-            ],
-            data,
-        }
-        .invoke_possibly_signed(
-            &[
-                source.to_info_c(),
-                mint.to_info_c(),
-                destination.to_info_c(),
-                authority.to_info_c(),
-                additional_accounts[0].to_info_c(),
-            ],
-            &[authority.signer_seeds],
-        );
+    CpiInstruction {
+        program_id: token_program_id,
+        accounts: metas.as_slice(),
+        data,
     }
+    .invoke_possibly_signed(infos.as_slice(), &[authority.signer_seeds]);
+}
 
-    #[inline(always)]
-    fn _invoke_transfer_checked_with_additional_accounts_2(
-        token_program_id: &Pubkey,
-        source: &NoStdAccountInfo,
-        mint: &NoStdAccountInfo,
-        destination: &NoStdAccountInfo,
-        authority: &CpiAuthority,
-        data: &[u8],
-        additional_accounts: &[NoStdAccountInfo],
-    ) {
-        CpiInstruction {
-            program_id: token_program_id,
-            accounts: &[
-                source.to_meta_c(),
-                mint.to_meta_c(),
-                destination.to_meta_c(),
-                authority.to_meta_c_signer(),
-                additional_accounts[0].to_meta_c(),
-                additional_accounts[1].to_meta_c(),
-            ],
-            data,
-        }
-        .invoke_possibly_signed(
-            &[
-                source.to_info_c(),
-                mint.to_info_c(),
-                destination.to_info_c(),
-                authority.to_info_c(),
-                additional_accounts[0].to_info_c(),
-                additional_accounts[1].to_info_c(),
-            ],
-            &[authority.signer_seeds],
-        );
-    }
+/// Stack array with `N` initialized elements followed by space for up to `MAX_EXTRA` elements,
+/// which is read as one slice. Because `N + MAX_EXTRA` cannot be used as the length of an array
+/// for a generic `MAX_EXTRA`, the two arrays are laid out next to each other with `repr(C)`.
+#[cfg(not(feature = "alloc"))]
+#[repr(C)]
+struct FixedThenExtra<T, const N: usize, const MAX_EXTRA: usize> {
+    fixed: [T; N],
+    extra: [core::mem::MaybeUninit<T>; MAX_EXTRA],
+    extra_len: usize,
+}
 
+#[cfg(not(feature = "alloc"))]
+impl<T, const N: usize, const MAX_EXTRA: usize> FixedThenExtra<T, N, MAX_EXTRA> {
     #[inline(always)]
-    fn _invoke_transfer_checked_with_additional_accounts_3(
-        token_program_id: &Pubkey,
-        source: &NoStdAccountInfo,
-        mint: &NoStdAccountInfo,
-        destination: &NoStdAccountInfo,
-        authority: &CpiAuthority,
-        data: &[u8],
-        additional_accounts: &[NoStdAccountInfo],
-    ) {
-        CpiInstruction {
-            program_id: token_program_id,
-            accounts: &[
-                source.to_meta_c(),
-                mint.to_meta_c(),
-                destination.to_meta_c(),
-                authority.to_meta_c_signer(),
-                additional_accounts[0].to_meta_c(),
-                additional_accounts[1].to_meta_c(),
-                additional_accounts[2].to_meta_c(),
-            ],
-            data,
+    fn new(fixed: [T; N]) -> Self {
+        Self {
+            fixed,
+            // Safety: An array of uninitialized elements does not require initialization.
+            extra: unsafe { core::mem::MaybeUninit::uninit().assume_init() },
+            extra_len: 0,
         }
-        .invoke_possibly_signed(
-            &[
-                source.to_info_c(),
-                mint.to_info_c(),
-                destination.to_info_c(),
-                authority.to_info_c(),
-                additional_accounts[0].to_info_c(),
-                additional_accounts[1].to_info_c(),
-                additional_accounts[2].to_info_c(),
-            ],
-            &[authority.signer_seeds],
-        );
     }
 
+    /// Panics if `MAX_EXTRA` elements have already been pushed.
     #[inline(always)]
-    fn _invoke_transfer_checked_with_additional_accounts_4(
-        token_program_id: &Pubkey,
-        source: &NoStdAccountInfo,
-        mint: &NoStdAccountInfo,
-        destination: &NoStdAccountInfo,
-        authority: &CpiAuthority,
-        data: &[u8],
-        additional_accounts: &[NoStdAccountInfo],
-    ) {
-        CpiInstruction {
-            program_id: token_program_id,
-            accounts: &[
-                source.to_meta_c(),
-                mint.to_meta_c(),
-                destination.to_meta_c(),
-                authority.to_meta_c_signer(),
-                additional_accounts[0].to_meta_c(),
-                additional_accounts[1].to_meta_c(),
-                additional_accounts[2].to_meta_c(),
-                additional_accounts[3].to_meta_c(),
-            ],
-            data,
-        }
-        .invoke_possibly_signed(
-            &[
-                source.to_info_c(),
-                mint.to_info_c(),
-                destination.to_info_c(),
-                authority.to_info_c(),
-                additional_accounts[0].to_info_c(),
-                additional_accounts[1].to_info_c(),
-                additional_accounts[2].to_info_c(),
-                additional_accounts[3].to_info_c(),
-            ],
-            &[authority.signer_seeds],
-        );
+    fn push(&mut self, item: T) {
+        self.extra[self.extra_len].write(item);
+        self.extra_len += 1;
     }
 
     #[inline(always)]
-    fn _invoke_transfer_checked_with_additional_accounts_5(
-        token_program_id: &Pubkey,
-        source: &NoStdAccountInfo,
-        mint: &NoStdAccountInfo,
-        destination: &NoStdAccountInfo,
-        authority: &CpiAuthority,
-        data: &[u8],
-        additional_accounts: &[NoStdAccountInfo],
-    ) {
-        CpiInstruction {
-            program_id: token_program_id,
-            accounts: &[
-                source.to_meta_c(),
-                mint.to_meta_c(),
-                destination.to_meta_c(),
-                authority.to_meta_c_signer(),
-                additional_accounts[0].to_meta_c(),
-                additional_accounts[1].to_meta_c(),
-                additional_accounts[2].to_meta_c(),
-                additional_accounts[3].to_meta_c(),
-                additional_accounts[4].to_meta_c(),
-            ],
-            data,
+    fn as_slice(&self) -> &[T] {
+        // Safety: With `repr(C)`, the fixed elements start at the beginning of this struct and the
+        // extra elements start right after them because both arrays have the same element layout
+        // (so there is no padding between them). The first `extra_len` extra elements have been
+        // initialized.
+        unsafe {
+            core::slice::from_raw_parts((self as *const Self).cast::<T>(), N + self.extra_len)
         }
-        .invoke_possibly_signed(
-            &[
-                source.to_info_c(),
-                mint.to_info_c(),
-                destination.to_info_c(),
-                authority.to_info_c(),
-                additional_accounts[0].to_info_c(),
-                additional_accounts[1].to_info_c(),
-                additional_accounts[2].to_info_c(),
-                additional_accounts[3].to_info_c(),
-                additional_accounts[4].to_info_c(),
-            ],
-            &[authority.signer_seeds],
-        );
     }
+}
 
-    #[inline(always)]
-    fn _invoke_transfer_checked_with_additional_accounts_6(
-        token_program_id: &Pubkey,
-        source: &NoStdAccountInfo,
-        mint: &NoStdAccountInfo,
-        destination: &NoStdAccountInfo,
-        authority: &CpiAuthority,
-        data: &[u8],
-        additional_accounts: &[NoStdAccountInfo],
-    ) {
-        CpiInstruction {
-            program_id: token_program_id,
-            accounts: &[
-                source.to_meta_c(),
-                mint.to_meta_c(),
-                destination.to_meta_c(),
-                authority.to_meta_c_signer(),
-                additional_accounts[0].to_meta_c(),
-                additional_accounts[1].to_meta_c(),
-                additional_accounts[2].to_meta_c(),
-                additional_accounts[3].to_meta_c(),
-                additional_accounts[4].to_meta_c(),
-                additional_accounts[5].to_meta_c(),
-            ],
-            data,
-        }
-        .invoke_possibly_signed(
-            &[
-                source.to_info_c(),
-                mint.to_info_c(),
-                destination.to_info_c(),
-                authority.to_info_c(),
-                additional_accounts[0].to_info_c(),
-                additional_accounts[1].to_info_c(),
-                additional_accounts[2].to_info_c(),
-                additional_accounts[3].to_info_c(),
-                additional_accounts[4].to_info_c(),
-                additional_accounts[5].to_info_c(),
-            ],
-            &[authority.signer_seeds],
-        );
-    }
+#[cfg(test)]
+mod test {
+    use crate::test_fixture::{TestAccount, TestAccountInfos};
 
-    #[inline(always)]
-    fn _invoke_transfer_checked_with_additional_accounts_7(
-        token_program_id: &Pubkey,
-        source: &NoStdAccountInfo,
-        mint: &NoStdAccountInfo,
-        destination: &NoStdAccountInfo,
-        authority: &CpiAuthority,
-        data: &[u8],
-        additional_accounts: &[NoStdAccountInfo],
-    ) {
-        CpiInstruction {
-            program_id: token_program_id,
-            accounts: &[
-                source.to_meta_c(),
-                mint.to_meta_c(),
-                destination.to_meta_c(),
-                authority.to_meta_c_signer(),
-                additional_accounts[0].to_meta_c(),
-                additional_accounts[1].to_meta_c(),
-                additional_accounts[2].to_meta_c(),
-                additional_accounts[3].to_meta_c(),
-                additional_accounts[4].to_meta_c(),
-                additional_accounts[5].to_meta_c(),
-                additional_accounts[6].to_meta_c(),
-            ],
-            data,
-        }
-        .invoke_possibly_signed(
-            &[
-                source.to_info_c(),
-                mint.to_info_c(),
-                destination.to_info_c(),
-                authority.to_info_c(),
-                additional_accounts[0].to_info_c(),
-                additional_accounts[1].to_info_c(),
-                additional_accounts[2].to_info_c(),
-                additional_accounts[3].to_info_c(),
-                additional_accounts[4].to_info_c(),
-                additional_accounts[5].to_info_c(),
-                additional_accounts[6].to_info_c(),
-            ],
-            &[authority.signer_seeds],
-        );
-    }
+    use super::*;
 
-    #[inline(always)]
-    fn _invoke_transfer_checked_with_additional_accounts_8(
-        token_program_id: &Pubkey,
-        source: &NoStdAccountInfo,
-        mint: &NoStdAccountInfo,
-        destination: &NoStdAccountInfo,
-        authority: &CpiAuthority,
-        data: &[u8],
-        additional_accounts: &[NoStdAccountInfo],
-    ) {
-        CpiInstruction {
-            program_id: token_program_id,
-            accounts: &[
-                source.to_meta_c(),
-                mint.to_meta_c(),
-                destination.to_meta_c(),
-                authority.to_meta_c_signer(),
-                additional_accounts[0].to_meta_c(),
-                additional_accounts[1].to_meta_c(),
-                additional_accounts[2].to_meta_c(),
-                additional_accounts[3].to_meta_c(),
-                additional_accounts[4].to_meta_c(),
-                additional_accounts[5].to_meta_c(),
-                additional_accounts[6].to_meta_c(),
-                additional_accounts[7].to_meta_c(),
-            ],
-            data,
-        }
-        .invoke_possibly_signed(
-            &[
-                source.to_info_c(),
-                mint.to_info_c(),
-                destination.to_info_c(),
-                authority.to_info_c(),
-                additional_accounts[0].to_info_c(),
-                additional_accounts[1].to_info_c(),
-                additional_accounts[2].to_info_c(),
-                additional_accounts[3].to_info_c(),
-                additional_accounts[4].to_info_c(),
-                additional_accounts[5].to_info_c(),
-                additional_accounts[6].to_info_c(),
-                additional_accounts[7].to_info_c(),
-            ],
-            &[authority.signer_seeds],
+    fn test_account_infos(num_additional_accounts: usize) -> TestAccountInfos {
+        let mut accounts = std::vec![
+            TestAccount::new(Pubkey::new_unique(), spl_token_2022::ID).writable(),
+            TestAccount::new(Pubkey::new_unique(), spl_token_2022::ID),
+            TestAccount::new(Pubkey::new_unique(), spl_token_2022::ID).writable(),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique()).signer(),
+        ];
+        accounts.extend(
+            (0..num_additional_accounts)
+                .map(|_| TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique())),
         );
+
+        TestAccountInfos::new(&accounts)
     }
 
-    #[inline(always)]
-    fn _invoke_transfer_checked_with_additional_accounts_9(
-        token_program_id: &Pubkey,
-        source: &NoStdAccountInfo,
-        mint: &NoStdAccountInfo,
-        destination: &NoStdAccountInfo,
-        authority: &CpiAuthority,
-        data: &[u8],
-        additional_accounts: &[NoStdAccountInfo],
-    ) {
-        CpiInstruction {
-            program_id: token_program_id,
-            accounts: &[
-                source.to_meta_c(),
-                mint.to_meta_c(),
-                destination.to_meta_c(),
-                authority.to_meta_c_signer(),
-                additional_accounts[0].to_meta_c(),
-                additional_accounts[1].to_meta_c(),
-                additional_accounts[2].to_meta_c(),
-                additional_accounts[3].to_meta_c(),
-                additional_accounts[4].to_meta_c(),
-                additional_accounts[5].to_meta_c(),
-                additional_accounts[6].to_meta_c(),
-                additional_accounts[7].to_meta_c(),
-                additional_accounts[8].to_meta_c(),
-            ],
-            data,
+    fn transfer_checked<const MAX_EXTRA: usize>(
+        infos: &[NoStdAccountInfo],
+    ) -> TransferChecked<'_, '_, MAX_EXTRA> {
+        TransferChecked {
+            token_program_id: &spl_token_2022::ID,
+            source: &infos[0],
+            mint: &infos[1],
+            destination: &infos[2],
+            authority: CpiAuthority::from_signer(&infos[3]),
+            amount: 69,
+            decimals: 9,
+            additional_accounts: Some(&infos[4..]),
         }
-        .invoke_possibly_signed(
-            &[
-                source.to_info_c(),
-                mint.to_info_c(),
-                destination.to_info_c(),
-                authority.to_info_c(),
-                additional_accounts[0].to_info_c(),
-                additional_accounts[1].to_info_c(),
-                additional_accounts[2].to_info_c(),
-                additional_accounts[3].to_info_c(),
-                additional_accounts[4].to_info_c(),
-                additional_accounts[5].to_info_c(),
-                additional_accounts[6].to_info_c(),
-                additional_accounts[7].to_info_c(),
-                additional_accounts[8].to_info_c(),
-            ],
-            &[authority.signer_seeds],
-        );
     }
 
-    #[inline(always)]
-    fn _invoke_transfer_checked_with_additional_accounts_10(
-        token_program_id: &Pubkey,
-        source: &NoStdAccountInfo,
-        mint: &NoStdAccountInfo,
-        destination: &NoStdAccountInfo,
-        authority: &CpiAuthority,
-        data: &[u8],
-        additional_accounts: &[NoStdAccountInfo],
-    ) {
-        CpiInstruction {
-            program_id: token_program_id,
-            accounts: &[
-                source.to_meta_c(),
-                mint.to_meta_c(),
-                destination.to_meta_c(),
-                authority.to_meta_c_signer(),
-                additional_accounts[0].to_meta_c(),
-                additional_accounts[1].to_meta_c(),
-                additional_accounts[2].to_meta_c(),
-                additional_accounts[3].to_meta_c(),
-                additional_accounts[4].to_meta_c(),
-                additional_accounts[5].to_meta_c(),
-                additional_accounts[6].to_meta_c(),
-                additional_accounts[7].to_meta_c(),
-                additional_accounts[8].to_meta_c(),
-                additional_accounts[9].to_meta_c(),
-            ],
-            data,
-        }
-        .invoke_possibly_signed(
-            &[
-                source.to_info_c(),
-                mint.to_info_c(),
-                destination.to_info_c(),
-                authority.to_info_c(),
-                additional_accounts[0].to_info_c(),
-                additional_accounts[1].to_info_c(),
-                additional_accounts[2].to_info_c(),
-                additional_accounts[3].to_info_c(),
-                additional_accounts[4].to_info_c(),
-                additional_accounts[5].to_info_c(),
-                additional_accounts[6].to_info_c(),
-                additional_accounts[7].to_info_c(),
-                additional_accounts[8].to_info_c(),
-                additional_accounts[9].to_info_c(),
-            ],
-            &[authority.signer_seeds],
+    #[test]
+    fn test_transfer_checked_max_extra() {
+        let infos = test_account_infos(0);
+        assert_eq!(transfer_checked::<0>(&infos).try_into_invoke(), Ok(()));
+
+        // Default is MAX_ADDITIONAL_ACCOUNTS_NOALLOC additional accounts.
+        let infos = test_account_infos(MAX_ADDITIONAL_ACCOUNTS_NOALLOC);
+        let default_transfer_checked: TransferChecked = transfer_checked(&infos);
+        assert_eq!(default_transfer_checked.try_into_invoke(), Ok(()));
+
+        // Generic parameter is the number of additional accounts.
+        let infos = test_account_infos(20);
+        assert_eq!(transfer_checked::<20>(&infos).try_into_invoke(), Ok(()));
+
+        #[cfg(not(feature = "alloc"))]
+        assert_eq!(
+            transfer_checked::<19>(&infos).try_into_invoke(),
+            Err(crate::program_error::ProgramError::Custom(
+                crate::error::SealevelToolsError::CPI
+            ))
         );
+        #[cfg(feature = "alloc")]
+        assert_eq!(transfer_checked::<19>(&infos).try_into_invoke(), Ok(()));
     }
 
-    #[inline(always)]
-    fn _invoke_transfer_checked_with_additional_accounts_11(
-        token_program_id: &Pubkey,
-        source: &NoStdAccountInfo,
-        mint: &NoStdAccountInfo,
-        destination: &NoStdAccountInfo,
-        authority: &CpiAuthority,
-        data: &[u8],
-        additional_accounts: &[NoStdAccountInfo],
-    ) {
-        CpiInstruction {
-            program_id: token_program_id,
-            accounts: &[
-                source.to_meta_c(),
-                mint.to_meta_c(),
-                destination.to_meta_c(),
-                authority.to_meta_c_signer(),
-                additional_accounts[0].to_meta_c(),
-                additional_accounts[1].to_meta_c(),
-                additional_accounts[2].to_meta_c(),
-                additional_accounts[3].to_meta_c(),
-                additional_accounts[4].to_meta_c(),
-                additional_accounts[5].to_meta_c(),
-                additional_accounts[6].to_meta_c(),
-                additional_accounts[7].to_meta_c(),
-                additional_accounts[8].to_meta_c(),
-                additional_accounts[9].to_meta_c(),
-                additional_accounts[10].to_meta_c(),
-            ],
-            data,
-        }
-        .invoke_possibly_signed(
-            &[
-                source.to_info_c(),
-                mint.to_info_c(),
-                destination.to_info_c(),
-                authority.to_info_c(),
-                additional_accounts[0].to_info_c(),
-                additional_accounts[1].to_info_c(),
-                additional_accounts[2].to_info_c(),
-                additional_accounts[3].to_info_c(),
-                additional_accounts[4].to_info_c(),
-                additional_accounts[5].to_info_c(),
-                additional_accounts[6].to_info_c(),
-                additional_accounts[7].to_info_c(),
-                additional_accounts[8].to_info_c(),
-                additional_accounts[9].to_info_c(),
-                additional_accounts[10].to_info_c(),
-            ],
-            &[authority.signer_seeds],
-        );
+    #[cfg(not(feature = "alloc"))]
+    #[test]
+    fn test_fixed_then_extra() {
+        let mut array = FixedThenExtra::<u64, 2, 3>::new([1, 2]);
+        assert_eq!(array.as_slice(), &[1, 2]);
+
+        array.push(3);
+        array.push(4);
+        assert_eq!(array.as_slice(), &[1, 2, 3, 4]);
+
+        array.push(5);
+        assert_eq!(array.as_slice(), &[1, 2, 3, 4, 5]);
+
+        let array = FixedThenExtra::<u8, 4, 0>::new([1, 2, 3, 4]);
+        assert_eq!(array.as_slice(), &[1, 2, 3, 4]);
     }
 
-    #[inline(always)]
-    fn _invoke_transfer_checked_with_additional_accounts_12(
-        token_program_id: &Pubkey,
-        source: &NoStdAccountInfo,
-        mint: &NoStdAccountInfo,
-        destination: &NoStdAccountInfo,
-        authority: &CpiAuthority,
-        data: &[u8],
-        additional_accounts: &[NoStdAccountInfo],
-    ) {
-        CpiInstruction {
-            program_id: token_program_id,
-            accounts: &[
-                source.to_meta_c(),
-                mint.to_meta_c(),
-                destination.to_meta_c(),
-                authority.to_meta_c_signer(),
-                additional_accounts[0].to_meta_c(),
-                additional_accounts[1].to_meta_c(),
-                additional_accounts[2].to_meta_c(),
-                additional_accounts[3].to_meta_c(),
-                additional_accounts[4].to_meta_c(),
-                additional_accounts[5].to_meta_c(),
-                additional_accounts[6].to_meta_c(),
-                additional_accounts[7].to_meta_c(),
-                additional_accounts[8].to_meta_c(),
-                additional_accounts[9].to_meta_c(),
-                additional_accounts[10].to_meta_c(),
-                additional_accounts[11].to_meta_c(),
-            ],
-            data,
-        }
-        .invoke_possibly_signed(
-            &[
-                source.to_info_c(),
-                mint.to_info_c(),
-                destination.to_info_c(),
-                authority.to_info_c(),
-                additional_accounts[0].to_info_c(),
-                additional_accounts[1].to_info_c(),
-                additional_accounts[2].to_info_c(),
-                additional_accounts[3].to_info_c(),
-                additional_accounts[4].to_info_c(),
-                additional_accounts[5].to_info_c(),
-                additional_accounts[6].to_info_c(),
-                additional_accounts[7].to_info_c(),
-                additional_accounts[8].to_info_c(),
-                additional_accounts[9].to_info_c(),
-                additional_accounts[10].to_info_c(),
-                additional_accounts[11].to_info_c(),
-            ],
-            &[authority.signer_seeds],
-        );
+    #[cfg(not(feature = "alloc"))]
+    #[test]
+    #[should_panic]
+    fn test_fixed_then_extra_exceeds_capacity() {
+        let mut array = FixedThenExtra::<u64, 2, 1>::new([1, 2]);
+        array.push(3);
+        array.push(4);
     }
 }
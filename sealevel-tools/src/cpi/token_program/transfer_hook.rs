@@ -0,0 +1,793 @@
+#[cfg(feature = "alloc")]
+use alloc::format;
+
+use crate::{
+    account::token_extensions,
+    discriminator::Discriminator,
+    entrypoint::NoStdAccountInfo,
+    error::SealevelToolsError,
+    program_error::ProgramError,
+    pubkey::{Pubkey, MAX_SEEDS, MAX_SEED_LEN},
+    spl_token_2022::{
+        extension::{transfer_hook::TransferHook, BaseStateWithExtensions, PodStateWithExtensions},
+        pod::PodMint,
+    },
+};
+
+/// Seed (along with the mint's key) used to derive the transfer hook program's validation account,
+/// which stores the extra account metas required by the execute instruction.
+pub const EXTRA_ACCOUNT_METAS_SEED: &[u8] = b"extra-account-metas";
+
+const EXECUTE_DISCRIMINATOR: [u8; 8] =
+    Discriminator::Sha2(b"spl-transfer-hook-interface:execute").to_bytes();
+
+const EXECUTE_IX_DATA_LEN: usize = {
+    8 // discriminator
+    + 8 // amount
+};
+
+const TLV_HEADER_LEN: usize = {
+    8 // discriminator
+    + 4 // length
+};
+
+const EXTRA_ACCOUNT_META_LEN: usize = {
+    1 // discriminator
+    + 32 // address config
+    + 1 // is_signer
+    + 1 // is_writable
+};
+
+/// Number of execute instruction accounts preceding the extra accounts (source, mint, destination,
+/// authority and validation account).
+const NUM_EXECUTE_ACCOUNTS: usize = 5;
+
+/// Resolve the additional accounts required by the transfer hook program of the given mint (if
+/// any) and validate them against the remaining accounts passed into your program. The returned
+/// slice can be passed into [TransferChecked::additional_accounts] (or
+/// [UseTransferChecked::additional_accounts]).
+///
+/// If the mint is not a Token Extensions mint or does not have a transfer hook program configured,
+/// an empty slice is returned.
+///
+/// ### Notes
+///
+/// The remaining accounts must be ordered the same way the off-chain helpers in
+/// [spl_transfer_hook_interface] append them to a transfer checked instruction:
+///
+/// 0. Each extra account resolved from the validation account, in order.
+/// 1. Transfer hook program.
+/// 2. Validation account (PDA of the transfer hook program with seeds
+///    `[EXTRA_ACCOUNT_METAS_SEED, mint]`).
+///
+/// Resolving the extra accounts does not require any accounts beyond these and the transfer's own
+/// source, mint, destination and authority accounts. But every extra account is derived on-chain,
+/// so each PDA costs a [Pubkey::try_find_program_address] call (as well as one for the validation
+/// account).
+///
+/// Account data used as seeds (e.g. the source token account's owner) is borrowed during
+/// resolution, so none of these accounts can be mutably borrowed when calling this method.
+///
+/// This method does not require the "alloc" feature. Seeds are copied into stack buffers and the
+/// returned accounts are a subslice of the remaining accounts.
+///
+/// [spl_transfer_hook_interface]: https://docs.rs/spl-transfer-hook-interface/latest/spl_transfer_hook_interface/offchain/index.html
+/// [TransferChecked::additional_accounts]: super::TransferChecked::additional_accounts
+/// [UseTransferChecked::additional_accounts]: super::UseTransferChecked::additional_accounts
+pub fn resolve_transfer_hook_accounts<'a>(
+    source: &NoStdAccountInfo,
+    mint: &NoStdAccountInfo,
+    destination: &NoStdAccountInfo,
+    authority: &NoStdAccountInfo,
+    amount: u64,
+    remaining_accounts: &'a [NoStdAccountInfo],
+) -> Result<&'a [NoStdAccountInfo], ProgramError> {
+    if mint.owner() != &token_extensions::ID {
+        return Ok(&[]);
+    }
+
+    let hook_program_id = {
+        let mint_data = mint.try_borrow_data()?;
+        let mint_state = PodStateWithExtensions::<PodMint>::unpack(&mint_data)?;
+
+        match mint_state.get_extension::<TransferHook>() {
+            Ok(transfer_hook) => Option::<Pubkey>::from(transfer_hook.program_id),
+            Err(_) => None,
+        }
+    };
+
+    let hook_program_id = match hook_program_id {
+        Some(hook_program_id) => hook_program_id,
+        None => return Ok(&[]),
+    };
+
+    let (validation_address, _) = Pubkey::find_program_address(
+        &[EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
+        &hook_program_id,
+    );
+
+    let (validation_index, validation_account) = remaining_accounts
+        .iter()
+        .enumerate()
+        .find(|(_, account)| account.key() == &validation_address)
+        .ok_or(SealevelToolsError::AccountInfo(&[
+            "Transfer hook validation account not found",
+        ]))?;
+
+    let validation_data = validation_account.try_borrow_data()?;
+    let extra_metas = _find_execute_extra_account_metas(&validation_data)?;
+    let num_extra_accounts = extra_metas.len() / EXTRA_ACCOUNT_META_LEN;
+
+    if validation_index != num_extra_accounts + 1
+        || remaining_accounts[num_extra_accounts].key() != &hook_program_id
+    {
+        return Err(SealevelToolsError::AccountInfo(&[
+            "Expected extra accounts, transfer hook program and validation account",
+        ])
+        .into());
+    }
+
+    let mut instruction_data = [0; EXECUTE_IX_DATA_LEN];
+    instruction_data[..8].copy_from_slice(&EXECUTE_DISCRIMINATOR);
+    instruction_data[8..].copy_from_slice(&amount.to_le_bytes());
+
+    let execute_accounts = [source, mint, destination, authority, validation_account];
+
+    for (i, meta) in extra_metas.chunks_exact(EXTRA_ACCOUNT_META_LEN).enumerate() {
+        // Extra accounts can only refer to accounts resolved before them.
+        let resolved_accounts = &remaining_accounts[..i];
+
+        let config = &meta[1..33];
+        let expected_key = match meta[0] {
+            0 => _read_pubkey(config, 0)?,
+            1 => _find_extra_account_pda(
+                config,
+                &instruction_data,
+                &execute_accounts,
+                resolved_accounts,
+                &hook_program_id,
+            )?,
+            2 => _resolve_pubkey_data(
+                config,
+                &instruction_data,
+                &execute_accounts,
+                resolved_accounts,
+            )?,
+            discriminator if discriminator >= 128 => {
+                let program_account = _execute_account(
+                    usize::from(discriminator - 128),
+                    &execute_accounts,
+                    resolved_accounts,
+                )?;
+
+                _find_extra_account_pda(
+                    config,
+                    &instruction_data,
+                    &execute_accounts,
+                    resolved_accounts,
+                    program_account.key(),
+                )?
+            }
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+
+        let account = &remaining_accounts[i];
+        let is_signer = meta[33] != 0;
+        let is_writable = meta[34] != 0;
+
+        if account.key() != &expected_key
+            || (is_signer && !account.is_signer())
+            || (is_writable && !account.is_writable())
+        {
+            #[cfg(feature = "alloc")]
+            return Err(SealevelToolsError::AccountInfo(&[
+                format!("Transfer hook extra account {}: Mismatch...", i).as_str(),
+                format!(
+                    "  Found: {}, signer: {}, writable: {}",
+                    account.key(),
+                    account.is_signer(),
+                    account.is_writable()
+                )
+                .as_str(),
+                format!(
+                    "  Expected: {}, signer: {}, writable: {}",
+                    expected_key, is_signer, is_writable
+                )
+                .as_str(),
+            ])
+            .into());
+            #[cfg(not(feature = "alloc"))]
+            return Err(SealevelToolsError::AccountInfo(&[
+                "Transfer hook extra account does not match validation account",
+            ])
+            .into());
+        }
+    }
+
+    Ok(&remaining_accounts[..(num_extra_accounts + 2)])
+}
+
+enum SeedConfig<'a> {
+    Literal(&'a [u8]),
+    InstructionData {
+        index: usize,
+        length: usize,
+    },
+    AccountKey {
+        index: usize,
+    },
+    AccountData {
+        account_index: usize,
+        data_index: usize,
+        length: usize,
+    },
+}
+
+/// Find the execute instruction's extra account metas in the validation account's TLV data. Each
+/// meta is [EXTRA_ACCOUNT_META_LEN] bytes. If the validation account is uninitialized, there are no
+/// extra accounts.
+#[inline(always)]
+fn _find_execute_extra_account_metas(data: &[u8]) -> Result<&[u8], ProgramError> {
+    let mut remaining = data;
+
+    while remaining.len() >= TLV_HEADER_LEN {
+        let (header, rest) = remaining.split_at(TLV_HEADER_LEN);
+
+        // Uninitialized TLV entries mark the end of the data.
+        if header[..8] == [0; 8] {
+            break;
+        }
+
+        let mut length = [0; 4];
+        length.copy_from_slice(&header[8..]);
+        let length = u32::from_le_bytes(length) as usize;
+
+        let value = rest.get(..length).ok_or(ProgramError::InvalidAccountData)?;
+
+        if header[..8] == EXECUTE_DISCRIMINATOR {
+            // Value is a slice of metas prefixed with its u32 length.
+            let mut count = [0; 4];
+            count.copy_from_slice(value.get(..4).ok_or(ProgramError::InvalidAccountData)?);
+            let count = u32::from_le_bytes(count) as usize;
+
+            return count
+                .checked_mul(EXTRA_ACCOUNT_META_LEN)
+                .and_then(|len| value.get(4..(4 + len)))
+                .ok_or(ProgramError::InvalidAccountData);
+        }
+
+        remaining = &rest[length..];
+    }
+
+    Ok(&[])
+}
+
+/// Unpack the next seed config from a PDA's address config. Returns [None] when the remaining
+/// config is empty or the next seed is uninitialized.
+#[inline(always)]
+fn _next_seed_config<'a>(config: &mut &'a [u8]) -> Result<Option<SeedConfig<'a>>, ProgramError> {
+    let (seed, rest) = match *config {
+        [] | [0, ..] => return Ok(None),
+        [1, length, rest @ ..] => {
+            let length = usize::from(*length);
+            if rest.len() < length {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            let (literal, rest) = rest.split_at(length);
+            (SeedConfig::Literal(literal), rest)
+        }
+        [2, index, length, rest @ ..] => (
+            SeedConfig::InstructionData {
+                index: usize::from(*index),
+                length: usize::from(*length),
+            },
+            rest,
+        ),
+        [3, index, rest @ ..] => (
+            SeedConfig::AccountKey {
+                index: usize::from(*index),
+            },
+            rest,
+        ),
+        [4, account_index, data_index, length, rest @ ..] => (
+            SeedConfig::AccountData {
+                account_index: usize::from(*account_index),
+                data_index: usize::from(*data_index),
+                length: usize::from(*length),
+            },
+            rest,
+        ),
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+
+    *config = rest;
+
+    Ok(Some(seed))
+}
+
+#[inline(always)]
+fn _execute_account<'a>(
+    index: usize,
+    execute_accounts: &[&'a NoStdAccountInfo; NUM_EXECUTE_ACCOUNTS],
+    resolved_accounts: &'a [NoStdAccountInfo],
+) -> Result<&'a NoStdAccountInfo, ProgramError> {
+    match index.checked_sub(NUM_EXECUTE_ACCOUNTS) {
+        None => Ok(execute_accounts[index]),
+        Some(index) => resolved_accounts
+            .get(index)
+            .ok_or(ProgramError::InvalidAccountData),
+    }
+}
+
+#[inline(always)]
+fn _slice_at(data: &[u8], index: usize, length: usize) -> Result<&[u8], ProgramError> {
+    data.get(index..(index + length))
+        .ok_or(ProgramError::InvalidAccountData)
+}
+
+#[inline(always)]
+fn _read_pubkey(data: &[u8], index: usize) -> Result<Pubkey, ProgramError> {
+    _slice_at(data, index, 32)
+        .and_then(|bytes| Pubkey::try_from(bytes).map_err(|_| ProgramError::InvalidAccountData))
+}
+
+#[inline(always)]
+fn _copy_seed(buf: &mut [u8; MAX_SEED_LEN], seed: &[u8]) -> Result<usize, ProgramError> {
+    buf.get_mut(..seed.len())
+        .ok_or(ProgramError::MaxSeedLengthExceeded)?
+        .copy_from_slice(seed);
+
+    Ok(seed.len())
+}
+
+#[inline(always)]
+fn _find_extra_account_pda(
+    mut config: &[u8],
+    instruction_data: &[u8],
+    execute_accounts: &[&NoStdAccountInfo; NUM_EXECUTE_ACCOUNTS],
+    resolved_accounts: &[NoStdAccountInfo],
+    program_id: &Pubkey,
+) -> Result<Pubkey, ProgramError> {
+    let mut seed_bufs = [[0; MAX_SEED_LEN]; MAX_SEEDS];
+    let mut seed_lens = [0; MAX_SEEDS];
+    let mut num_seeds = 0;
+
+    while let Some(seed) = _next_seed_config(&mut config)? {
+        let buf = seed_bufs
+            .get_mut(num_seeds)
+            .ok_or(ProgramError::MaxSeedLengthExceeded)?;
+
+        seed_lens[num_seeds] = match seed {
+            SeedConfig::Literal(literal) => _copy_seed(buf, literal)?,
+            SeedConfig::InstructionData { index, length } => {
+                _copy_seed(buf, _slice_at(instruction_data, index, length)?)?
+            }
+            SeedConfig::AccountKey { index } => _copy_seed(
+                buf,
+                _execute_account(index, execute_accounts, resolved_accounts)?
+                    .key()
+                    .as_ref(),
+            )?,
+            SeedConfig::AccountData {
+                account_index,
+                data_index,
+                length,
+            } => _copy_seed(
+                buf,
+                _slice_at(
+                    &_execute_account(account_index, execute_accounts, resolved_accounts)?
+                        .try_borrow_data()?,
+                    data_index,
+                    length,
+                )?,
+            )?,
+        };
+
+        num_seeds += 1;
+    }
+
+    let mut seeds: [&[u8]; MAX_SEEDS] = [&[]; MAX_SEEDS];
+    for (seed, (buf, len)) in seeds.iter_mut().zip(seed_bufs.iter().zip(seed_lens)) {
+        *seed = &buf[..len];
+    }
+
+    Pubkey::try_find_program_address(&seeds[..num_seeds], program_id)
+        .map(|(key, _)| key)
+        .ok_or(ProgramError::InvalidSeeds)
+}
+
+#[inline(always)]
+fn _resolve_pubkey_data(
+    config: &[u8],
+    instruction_data: &[u8],
+    execute_accounts: &[&NoStdAccountInfo; NUM_EXECUTE_ACCOUNTS],
+    resolved_accounts: &[NoStdAccountInfo],
+) -> Result<Pubkey, ProgramError> {
+    match config {
+        [1, index, ..] => _read_pubkey(instruction_data, usize::from(*index)),
+        [2, account_index, data_index, ..] => {
+            let account_data = _execute_account(
+                usize::from(*account_index),
+                execute_accounts,
+                resolved_accounts,
+            )?
+            .try_borrow_data()?;
+            let key = _read_pubkey(&account_data, usize::from(*data_index))?;
+
+            Ok(key)
+        }
+        _ => Err(ProgramError::InvalidAccountData),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{collections::HashMap, vec::Vec};
+
+    use solana_sdk::instruction::{AccountMeta, Instruction};
+    use spl_tlv_account_resolution::{
+        account::ExtraAccountMeta, pubkey_data::PubkeyData, seeds::Seed,
+        state::ExtraAccountMetaList,
+    };
+    use spl_transfer_hook_interface::{
+        instruction::ExecuteInstruction, offchain::add_extra_account_metas_for_execute,
+    };
+
+    use crate::test_fixture::{TestAccount, TestAccountInfos};
+
+    use super::*;
+
+    const AMOUNT: u64 = 69;
+
+    struct TransferHookFixture {
+        mint: Pubkey,
+        hook_program_id: Pubkey,
+        instruction: Instruction,
+        account_datas: HashMap<Pubkey, Vec<u8>>,
+    }
+
+    impl TransferHookFixture {
+        /// Build a transfer checked instruction with extra accounts resolved by the off-chain
+        /// helper in spl-transfer-hook-interface.
+        fn new() -> Self {
+            use crate::spl_token_2022::{
+                extension::{BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut},
+                state::Mint,
+            };
+
+            let hook_program_id = Pubkey::new_unique();
+            let external_program_id = Pubkey::new_unique();
+            let [source, mint, destination, authority] =
+                core::array::from_fn(|_| Pubkey::new_unique());
+
+            let mut mint_data = std::vec![
+                0;
+                ExtensionType::try_calculate_account_len::<Mint>(&[
+                    ExtensionType::TransferHook
+                ])
+                .unwrap()
+            ];
+            let mut state =
+                StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut mint_data).unwrap();
+            state
+                .init_extension::<TransferHook>(true)
+                .unwrap()
+                .program_id = Some(hook_program_id).try_into().unwrap();
+            state.base = Mint {
+                is_initialized: true,
+                ..Default::default()
+            };
+            state.pack_base();
+            state.init_account_type().unwrap();
+
+            // Source token account data with its owner at index 32 and another pubkey at index 72.
+            let mut source_data = std::vec![0; 165];
+            source_data[..32].copy_from_slice(mint.as_ref());
+            source_data[32..64].copy_from_slice(Pubkey::new_unique().as_ref());
+            source_data[72..104].copy_from_slice(Pubkey::new_unique().as_ref());
+
+            let extra_metas = [
+                ExtraAccountMeta::new_with_pubkey(&external_program_id, false, false).unwrap(),
+                ExtraAccountMeta::new_with_seeds(
+                    &[
+                        Seed::Literal {
+                            bytes: b"hook".to_vec(),
+                        },
+                        Seed::AccountKey { index: 0 },
+                        Seed::InstructionData {
+                            index: 8,
+                            length: 8,
+                        },
+                    ],
+                    false,
+                    true,
+                )
+                .unwrap(),
+                ExtraAccountMeta::new_with_seeds(
+                    &[Seed::AccountData {
+                        account_index: 0,
+                        data_index: 32,
+                        length: 32,
+                    }],
+                    false,
+                    false,
+                )
+                .unwrap(),
+                ExtraAccountMeta::new_with_pubkey_data(
+                    &PubkeyData::AccountData {
+                        account_index: 0,
+                        data_index: 72,
+                    },
+                    false,
+                    true,
+                )
+                .unwrap(),
+                // PDA of the first extra account, which refers to the second extra account.
+                ExtraAccountMeta::new_external_pda_with_seeds(
+                    5,
+                    &[
+                        Seed::Literal {
+                            bytes: b"external".to_vec(),
+                        },
+                        Seed::AccountKey { index: 2 },
+                        Seed::AccountKey { index: 6 },
+                    ],
+                    false,
+                    false,
+                )
+                .unwrap(),
+            ];
+
+            let mut validation_data =
+                std::vec![0; ExtraAccountMetaList::size_of(extra_metas.len()).unwrap()];
+            ExtraAccountMetaList::init::<ExecuteInstruction>(&mut validation_data, &extra_metas)
+                .unwrap();
+
+            let (validation_address, _) = Pubkey::find_program_address(
+                &[EXTRA_ACCOUNT_METAS_SEED, mint.as_ref()],
+                &hook_program_id,
+            );
+
+            let account_datas = HashMap::from([
+                (source, source_data),
+                (mint, mint_data),
+                (validation_address, validation_data),
+            ]);
+
+            let mut instruction = crate::spl_token_2022::instruction::transfer_checked(
+                &token_extensions::ID,
+                &source,
+                &mint,
+                &destination,
+                &authority,
+                &[],
+                AMOUNT,
+                9,
+            )
+            .unwrap();
+
+            futures::executor::block_on(add_extra_account_metas_for_execute(
+                &mut instruction,
+                &hook_program_id,
+                &source,
+                &mint,
+                &destination,
+                &authority,
+                AMOUNT,
+                |key| {
+                    let data = account_datas.get(&key).cloned();
+                    async move { Ok(data) }
+                },
+            ))
+            .unwrap();
+
+            Self {
+                mint,
+                hook_program_id,
+                instruction,
+                account_datas,
+            }
+        }
+
+        fn account_infos(&self, metas: &[AccountMeta]) -> TestAccountInfos {
+            let accounts = metas
+                .iter()
+                .map(|meta| {
+                    let owner = if meta.pubkey == self.mint {
+                        token_extensions::ID
+                    } else {
+                        Pubkey::default()
+                    };
+
+                    let mut account = TestAccount::new(meta.pubkey, owner).data(
+                        self.account_datas
+                            .get(&meta.pubkey)
+                            .map(Vec::as_slice)
+                            .unwrap_or_default(),
+                    );
+                    if meta.is_signer {
+                        account = account.signer();
+                    }
+                    if meta.is_writable {
+                        account = account.writable();
+                    }
+
+                    account
+                })
+                .collect::<Vec<_>>();
+
+            TestAccountInfos::new(&accounts)
+        }
+    }
+
+    fn resolve(infos: &[NoStdAccountInfo]) -> Result<&[NoStdAccountInfo], ProgramError> {
+        resolve_transfer_hook_accounts(
+            &infos[0],
+            &infos[1],
+            &infos[2],
+            &infos[3],
+            AMOUNT,
+            &infos[4..],
+        )
+    }
+
+    #[test]
+    fn test_resolve_transfer_hook_accounts() {
+        let fixture = TransferHookFixture::new();
+        let metas = &fixture.instruction.accounts;
+
+        // Five extra accounts followed by the transfer hook program and validation account.
+        assert_eq!(metas.len(), 4 + 5 + 2);
+        assert_eq!(metas[9].pubkey, fixture.hook_program_id);
+
+        let infos = fixture.account_infos(metas);
+        let resolved = resolve(&infos).unwrap();
+
+        assert_eq!(resolved.len(), metas.len() - 4);
+        for (account, meta) in resolved.iter().zip(&metas[4..]) {
+            assert_eq!(account.key(), &meta.pubkey);
+            assert_eq!(account.is_signer(), meta.is_signer);
+            assert_eq!(account.is_writable(), meta.is_writable);
+        }
+
+        // Extra accounts out of order.
+        let mut swapped_metas = metas.clone();
+        swapped_metas.swap(5, 6);
+        let infos = fixture.account_infos(&swapped_metas);
+        assert!(matches!(
+            resolve(&infos),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        ));
+
+        // Writable extra account passed in as read-only.
+        let mut readonly_metas = metas.clone();
+        readonly_metas[5].is_writable = false;
+        let infos = fixture.account_infos(&readonly_metas);
+        assert!(matches!(
+            resolve(&infos),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        ));
+
+        // Transfer hook program not directly before the validation account.
+        let mut moved_program_metas = metas.clone();
+        moved_program_metas.swap(8, 9);
+        let infos = fixture.account_infos(&moved_program_metas);
+        assert!(matches!(
+            resolve(&infos),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        ));
+
+        // Validation account missing.
+        let infos = fixture.account_infos(&metas[..10]);
+        assert!(matches!(
+            resolve(&infos),
+            Err(ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO))
+        ));
+    }
+
+    #[test]
+    fn test_resolve_transfer_hook_accounts_without_hook() {
+        let fixture = TransferHookFixture::new();
+        let metas = &fixture.instruction.accounts[..4];
+
+        // Mint is not owned by the Token Extensions program.
+        let infos = TestAccountInfos::new(
+            &metas
+                .iter()
+                .map(|meta| TestAccount::new(meta.pubkey, Pubkey::default()))
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(resolve(&infos).map(<[_]>::len), Ok(0));
+    }
+
+    #[test]
+    fn test_execute_discriminator() {
+        use spl_discriminator::SplDiscriminate;
+
+        #[derive(SplDiscriminate)]
+        #[discriminator_hash_input("spl-transfer-hook-interface:execute")]
+        struct Execute;
+
+        assert_eq!(Execute::SPL_DISCRIMINATOR.as_slice(), &EXECUTE_DISCRIMINATOR);
+    }
+
+    #[test]
+    fn test_find_execute_extra_account_metas() {
+        let mut data = [0; 128];
+
+        // Unrelated TLV entry with 3 bytes.
+        data[..8].copy_from_slice(&[1; 8]);
+        data[8..12].copy_from_slice(&3_u32.to_le_bytes());
+
+        // Execute TLV entry with 2 metas.
+        data[15..23].copy_from_slice(&EXECUTE_DISCRIMINATOR);
+        data[23..27].copy_from_slice(&74_u32.to_le_bytes());
+        data[27..31].copy_from_slice(&2_u32.to_le_bytes());
+        data[31] = 69;
+
+        let metas = _find_execute_extra_account_metas(&data).unwrap();
+        assert_eq!(metas.len(), 2 * EXTRA_ACCOUNT_META_LEN);
+        assert_eq!(metas[0], 69);
+
+        // No execute entry.
+        assert_eq!(_find_execute_extra_account_metas(&data[..15]), Ok(&[][..]));
+        assert_eq!(_find_execute_extra_account_metas(&[]), Ok(&[][..]));
+
+        // Truncated execute entry.
+        assert_eq!(
+            _find_execute_extra_account_metas(&data[..80]),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_next_seed_config() {
+        let mut config = [0; 32];
+        config[..4].copy_from_slice(&[1, 2, b'h', b'i']);
+        config[4..7].copy_from_slice(&[2, 8, 8]);
+        config[7..9].copy_from_slice(&[3, 0]);
+        config[9..13].copy_from_slice(&[4, 5, 32, 32]);
+
+        let mut remaining = &config[..];
+
+        assert!(matches!(
+            _next_seed_config(&mut remaining),
+            Ok(Some(SeedConfig::Literal(b"hi")))
+        ));
+        assert!(matches!(
+            _next_seed_config(&mut remaining),
+            Ok(Some(SeedConfig::InstructionData {
+                index: 8,
+                length: 8
+            }))
+        ));
+        assert!(matches!(
+            _next_seed_config(&mut remaining),
+            Ok(Some(SeedConfig::AccountKey { index: 0 }))
+        ));
+        assert!(matches!(
+            _next_seed_config(&mut remaining),
+            Ok(Some(SeedConfig::AccountData {
+                account_index: 5,
+                data_index: 32,
+                length: 32
+            }))
+        ));
+        assert!(matches!(_next_seed_config(&mut remaining), Ok(None)));
+
+        // Literal exceeding the config.
+        let mut remaining = &[1, 4, b'h', b'i'][..];
+        assert!(matches!(
+            _next_seed_config(&mut remaining),
+            Err(ProgramError::InvalidAccountData)
+        ));
+
+        // Unknown seed type.
+        let mut remaining = &[5, 0][..];
+        assert!(matches!(
+            _next_seed_config(&mut remaining),
+            Err(ProgramError::InvalidAccountData)
+        ));
+    }
+}
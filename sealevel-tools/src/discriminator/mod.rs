@@ -1,5 +1,41 @@
 //! Discriminator generation for program accounts, events, and instructions.
 
+#[cfg(feature = "borsh")]
+use crate::borsh::{io, BorshDeserialize, BorshSerialize};
+use crate::{entrypoint::ProgramResult, program_error::ProgramError};
+
+/// Derive [Discriminate] from the type's name (requires the "derive" feature). By default, the
+/// discriminator is the first 8 bytes of the Sha2 hash of `"account:{TypeName}"`, which matches
+/// [anchor-lang]'s account discriminators. The prefix, length and hashing function can be
+/// overridden with the `discriminator` attribute.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::discriminator::{Discriminate, Discriminator};
+///
+/// #[derive(Discriminate)]
+/// pub struct Thing {
+///     pub value: u64,
+/// }
+///
+/// #[derive(Discriminate)]
+/// #[discriminator(prefix = "event:", len = 4, hash = "keccak")]
+/// pub struct ThingCreated {
+///     pub value: u64,
+/// }
+///
+/// assert_eq!(Thing::DISCRIMINATOR, Discriminator::Sha2(b"account:Thing").to_bytes());
+/// assert_eq!(
+///     ThingCreated::DISCRIMINATOR,
+///     Discriminator::Keccak(b"event:ThingCreated").to_bytes()
+/// );
+/// ```
+///
+/// [anchor-lang]: https://docs.rs/anchor-lang
+#[cfg(feature = "derive")]
+pub use sealevel_tools_derive::Discriminate;
+
 /// Discriminator generated either by user-defined or by specific hashing function (where total hash
 /// output is 256 bits). These discriminators can be used for discriminating against serialized
 /// program accounts, serialized events, and instructions (as selectors for specific program
@@ -65,6 +101,117 @@ impl<'a, const LEN: usize> Discriminator<'a, LEN> {
     }
 }
 
+/// Instruction selector of a fixed length. Unlike using `[u8; N]` directly, selectors of different
+/// lengths are different types, so a 4-byte selector cannot be compared to an 8-byte one by
+/// accident.
+///
+/// ### Notes
+///
+/// Comparing a selector with a slice (e.g. `selector == data`) checks for exact equality. Use
+/// [Selector::matches] to check whether instruction data starts with the selector.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::discriminator::{Discriminator, Selector};
+///
+/// const DO_SOMETHING: Selector<4> =
+///     Selector::from_discriminator(Discriminator::Sha2(b"ix::do_something"));
+///
+/// let instruction_data = [DO_SOMETHING.to_bytes().as_slice(), &69_u64.to_le_bytes()].concat();
+/// assert!(DO_SOMETHING.matches(&instruction_data));
+/// assert_eq!(
+///     DO_SOMETHING.strip_from(&instruction_data),
+///     Some(69_u64.to_le_bytes().as_slice())
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Selector<const N: usize>(pub [u8; N]);
+
+impl<const N: usize> Selector<N> {
+    /// Create a selector from bytes.
+    #[inline(always)]
+    pub const fn new(bytes: [u8; N]) -> Self {
+        Self(bytes)
+    }
+
+    /// Create a selector from a [Discriminator] (see [Discriminator::to_bytes]).
+    #[inline(always)]
+    pub const fn from_discriminator(discriminator: Discriminator<'_, N>) -> Self {
+        Self(discriminator.to_bytes())
+    }
+
+    /// Selector bytes.
+    #[inline(always)]
+    pub const fn to_bytes(self) -> [u8; N] {
+        self.0
+    }
+
+    /// Reference to the selector bytes.
+    #[inline(always)]
+    pub const fn as_bytes(&self) -> &[u8; N] {
+        &self.0
+    }
+
+    /// Whether the data starts with this selector.
+    #[inline(always)]
+    pub fn matches(&self, data: &[u8]) -> bool {
+        data.starts_with(&self.0)
+    }
+
+    /// If the data starts with this selector, return the remaining data.
+    #[inline(always)]
+    pub fn strip_from<'a>(&self, data: &'a [u8]) -> Option<&'a [u8]> {
+        data.strip_prefix(&self.0)
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for Selector<N> {
+    fn from(bytes: [u8; N]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl<const N: usize> From<Selector<N>> for [u8; N] {
+    fn from(selector: Selector<N>) -> Self {
+        selector.0
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for Selector<N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const N: usize> PartialEq<[u8]> for Selector<N> {
+    fn eq(&self, other: &[u8]) -> bool {
+        self.0 == other
+    }
+}
+
+impl<const N: usize> PartialEq<&[u8]> for Selector<N> {
+    fn eq(&self, other: &&[u8]) -> bool {
+        self.0 == *other
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<const N: usize> BorshDeserialize for Selector<N> {
+    #[inline(always)]
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        <[u8; N]>::deserialize_reader(reader).map(Self)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<const N: usize> BorshSerialize for Selector<N> {
+    #[inline(always)]
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&self.0)
+    }
+}
+
 /// Simple trait to enforce a discriminator for a type. This type is used for various account
 /// handling in this crate (specifically serialization/deserialization). Defining
 /// [Discriminate::DISCRIMINATOR] can be used in conjunction with [Discriminator] to generate a
@@ -115,6 +262,194 @@ where
     const DISCRIMINATOR: [u8; LEN] = T::DISCRIMINATOR;
 }
 
+/// Assert that all instruction data has been consumed after reading the selector and deserializing
+/// its arguments. Trailing bytes indicate a malformed instruction, so
+/// [ProgramError::InvalidInstructionData] is returned if `remaining` is not empty.
+///
+/// ### Notes
+///
+/// Deserializing with `try_from_slice` in [borsh] already performs this check. This method is
+/// useful for hand-written readers that advance a slice.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     discriminator::{assert_fully_consumed, Discriminator},
+///     program_error::ProgramError,
+/// };
+///
+/// const DO_SOMETHING: [u8; 4] = Discriminator::Sha2(b"ix::do_something").to_bytes();
+///
+/// fn read_do_something(instruction_data: &[u8]) -> Result<u64, ProgramError> {
+///     let (selector, remaining) = instruction_data
+///         .split_first_chunk::<4>()
+///         .ok_or(ProgramError::InvalidInstructionData)?;
+///
+///     if selector != &DO_SOMETHING {
+///         return Err(ProgramError::InvalidInstructionData);
+///     }
+///
+///     let (value, remaining) = remaining
+///         .split_first_chunk::<8>()
+///         .ok_or(ProgramError::InvalidInstructionData)?;
+///
+///     assert_fully_consumed(remaining)?;
+///
+///     Ok(u64::from_le_bytes(*value))
+/// }
+/// ```
+///
+/// [borsh]: https://docs.rs/borsh/latest/borsh/
+#[inline(always)]
+pub fn assert_fully_consumed(remaining: &[u8]) -> ProgramResult {
+    if remaining.is_empty() {
+        Ok(())
+    } else {
+        Err(ProgramError::InvalidInstructionData)
+    }
+}
+
+/// Build instruction data by concatenating the selector and the [borsh]-serialized arguments. This
+/// method mirrors how a program decodes its instruction data (see [Selector]), which is useful for
+/// building instructions in client code (e.g. tests or SDKs).
+///
+/// ### Panics
+///
+/// Serializing to a [Vec] cannot fail unless the arguments' [BorshSerialize] implementation
+/// returns an error, in which case this method panics.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::discriminator::{build_instruction_data, Discriminator, Selector};
+///
+/// const DO_SOMETHING: Selector<4> =
+///     Selector::from_discriminator(Discriminator::Sha2(b"ix::do_something"));
+///
+/// let instruction_data = build_instruction_data(DO_SOMETHING.as_bytes(), &69_u64);
+/// assert_eq!(
+///     DO_SOMETHING.strip_from(&instruction_data),
+///     Some(69_u64.to_le_bytes().as_slice())
+/// );
+/// ```
+///
+/// [borsh]: https://docs.rs/borsh/latest/borsh/
+/// [Vec]: alloc::vec::Vec
+#[cfg(all(feature = "alloc", feature = "borsh"))]
+pub fn build_instruction_data<const N: usize, A: BorshSerialize>(
+    selector: &[u8; N],
+    args: &A,
+) -> alloc::vec::Vec<u8> {
+    let mut instruction_data = alloc::vec::Vec::from(selector.as_slice());
+    args.serialize(&mut instruction_data)
+        .expect("Failed to serialize instruction arguments");
+
+    instruction_data
+}
+
+/// Parse instruction data by verifying its leading selector and [borsh]-deserializing the remaining
+/// bytes as the instruction's arguments. This method is the inverse of [build_instruction_data].
+///
+/// ### Notes
+///
+/// If the data does not start with the selector or the arguments cannot be deserialized (including
+/// when there are trailing bytes), [ProgramError::InvalidInstructionData] is returned.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     borsh::BorshDeserialize,
+///     discriminator::{parse_instruction, Discriminator},
+///     pubkey::Pubkey,
+/// };
+///
+/// const INIT_MINT_SELECTOR: [u8; 8] = Discriminator::Sha2(b"global:init_mint").to_bytes();
+///
+/// #[derive(BorshDeserialize)]
+/// struct InitMintArgs {
+///     decimals: u8,
+///     mint_authority: Pubkey,
+/// }
+///
+/// let mut instruction_data = INIT_MINT_SELECTOR.to_vec();
+/// instruction_data.push(9);
+/// instruction_data.extend_from_slice(&Pubkey::new_unique().to_bytes());
+///
+/// let args = parse_instruction::<8, InitMintArgs>(&instruction_data, &INIT_MINT_SELECTOR).unwrap();
+/// assert_eq!(args.decimals, 9);
+/// ```
+///
+/// [borsh]: https://docs.rs/borsh/latest/borsh/
+#[cfg(feature = "borsh")]
+#[inline(always)]
+pub fn parse_instruction<const N: usize, A: BorshDeserialize>(
+    data: &[u8],
+    selector: &[u8; N],
+) -> Result<A, ProgramError> {
+    data.strip_prefix(selector.as_slice())
+        .and_then(|args| A::try_from_slice(args).ok())
+        .ok_or(ProgramError::InvalidInstructionData)
+}
+
+/// Compare two byte slices in constant time (with respect to their contents), which avoids leaking
+/// where the first mismatched byte is through timing. Slices of different lengths are never equal
+/// (and lengths are not treated as secret).
+///
+/// ### Notes
+///
+/// This comparison is only needed when comparing secret values (e.g. a stored commitment or
+/// preimage). Discriminators and selectors are public, so comparing them with `==` is fine (and
+/// cheaper, since the comparison can stop at the first mismatched byte).
+#[inline(always)]
+pub fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    // Pass the accumulator through black_box on each iteration to prevent the compiler from
+    // short-circuiting the fold once a difference is found.
+    let diff = a
+        .iter()
+        .zip(b)
+        .fold(0, |acc, (x, y)| core::hint::black_box(acc | (x ^ y)));
+
+    diff == 0
+}
+
+/// Build a static table of `(name, discriminator)` pairs, where each discriminator is the first 8
+/// bytes of the sha2 hash of its name (see [Discriminator::Sha2]). The table is evaluated at compile
+/// time, which makes it useful for cross-checking instruction selectors and event discriminators
+/// against an IDL or client expectations (e.g. in a unit test).
+///
+/// ### Notes
+///
+/// Names are hashed as-is, so any namespace used by anchor-lang must be included (e.g.
+/// `b"global:ix_a"` for instructions and `b"event:Thing"` for events).
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{discriminator::Discriminator, discriminator_table};
+///
+/// const TABLE: &[(&[u8], [u8; 8])] = discriminator_table!([b"global:ix_a", b"global:ix_b"]);
+///
+/// assert_eq!(TABLE.len(), 2);
+/// assert_eq!(TABLE[0].0, b"global:ix_a");
+/// assert_eq!(TABLE[1].1, Discriminator::Sha2(b"global:ix_b").to_bytes());
+/// ```
+#[macro_export]
+macro_rules! discriminator_table {
+    ([$($name:expr),* $(,)?]) => {{
+        const TABLE: &[(&[u8], [u8; 8])] = &[$((
+            $name,
+            $crate::discriminator::Discriminator::<8>::Sha2($name).to_bytes(),
+        )),*];
+        TABLE
+    }};
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -165,6 +500,116 @@ mod test {
         assert_eq!(&bytes[..8], &SHA3_DISCRIMINATOR);
     }
 
+    #[test]
+    fn test_selector() {
+        const SELECTOR: Selector<8> = Selector::from_discriminator(Discriminator::Sha2(b"a thing"));
+        assert_eq!(SELECTOR.to_bytes(), SHA2_DISCRIMINATOR);
+        assert_eq!(Selector::from(SHA2_DISCRIMINATOR), SELECTOR);
+        assert_eq!(<[u8; 8]>::from(SELECTOR), SHA2_DISCRIMINATOR);
+
+        // Exact equality with slices.
+        assert!(SELECTOR == SHA2_DISCRIMINATOR.as_slice());
+        assert!(SELECTOR != SHA2_DISCRIMINATOR[..4]);
+
+        // Prefix matching.
+        let mut data = [1; 11];
+        data[..8].copy_from_slice(&SHA2_DISCRIMINATOR);
+        data[8..].copy_from_slice(&[1, 2, 3]);
+        assert!(SELECTOR.matches(&data));
+        assert!(!SELECTOR.matches(&data[1..]));
+        assert!(!SELECTOR.matches(&SHA2_DISCRIMINATOR[..7]));
+        assert_eq!(SELECTOR.strip_from(&data), Some([1, 2, 3].as_slice()));
+        assert_eq!(SELECTOR.strip_from(&data[1..]), None);
+    }
+
+    #[test]
+    fn test_assert_fully_consumed() {
+        assert_eq!(assert_fully_consumed(&[]), Ok(()));
+        assert_eq!(
+            assert_fully_consumed(&[69]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+
+    #[cfg(all(feature = "alloc", feature = "borsh"))]
+    #[test]
+    fn test_build_instruction_data() {
+        let instruction_data = build_instruction_data(&SHA2_DISCRIMINATOR, &(69_u64, [1_u8, 2, 3]));
+
+        assert_eq!(instruction_data[..8], SHA2_DISCRIMINATOR);
+        assert_eq!(instruction_data[8..16], 69_u64.to_le_bytes());
+        assert_eq!(instruction_data[16..], [1, 2, 3]);
+
+        // Empty arguments.
+        assert_eq!(
+            build_instruction_data(&SHA2_DISCRIMINATOR, &()),
+            SHA2_DISCRIMINATOR
+        );
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn test_parse_instruction() {
+        let mut data = [0; 8 + 8 + 1];
+        data[..8].copy_from_slice(&SHA2_DISCRIMINATOR);
+        data[8..16].copy_from_slice(&69_u64.to_le_bytes());
+
+        assert_eq!(
+            parse_instruction::<8, u64>(&data[..16], &SHA2_DISCRIMINATOR),
+            Ok(69)
+        );
+
+        // Wrong selector.
+        assert_eq!(
+            parse_instruction::<8, u64>(&data[..16], &SHA3_DISCRIMINATOR),
+            Err(ProgramError::InvalidInstructionData)
+        );
+
+        // Data too short for selector.
+        assert_eq!(
+            parse_instruction::<8, u64>(&data[..7], &SHA2_DISCRIMINATOR),
+            Err(ProgramError::InvalidInstructionData)
+        );
+
+        // Data too short for arguments.
+        assert_eq!(
+            parse_instruction::<8, u64>(&data[..15], &SHA2_DISCRIMINATOR),
+            Err(ProgramError::InvalidInstructionData)
+        );
+
+        // Trailing bytes.
+        assert_eq!(
+            parse_instruction::<8, u64>(&data, &SHA2_DISCRIMINATOR),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+
+    #[test]
+    fn test_ct_eq() {
+        assert!(ct_eq(&[], &[]));
+        assert!(ct_eq(&SHA2_DISCRIMINATOR, &SHA2_DISCRIMINATOR));
+        assert!(!ct_eq(&SHA2_DISCRIMINATOR, &SHA3_DISCRIMINATOR));
+        assert!(!ct_eq(&SHA2_DISCRIMINATOR, &SHA2_DISCRIMINATOR[..7]));
+        assert!(!ct_eq(&[0, 0, 0, 1], &[0, 0, 0, 0]));
+        assert!(!ct_eq(&[1, 0, 0, 0], &[0, 0, 0, 0]));
+    }
+
+    #[test]
+    fn test_discriminator_table() {
+        const TABLE: &[(&[u8], [u8; 8])] = crate::discriminator_table!([b"a thing", b"another"]);
+
+        assert_eq!(TABLE.len(), 2);
+        assert_eq!(TABLE[0], (b"a thing".as_slice(), SHA2_DISCRIMINATOR));
+        assert_eq!(
+            TABLE[1],
+            (
+                b"another".as_slice(),
+                Discriminator::Sha2(b"another").to_bytes()
+            )
+        );
+        assert!(crate::discriminator_table!([]).is_empty());
+    }
+
     #[test]
     fn test_spl_discriminator_equivalence() {
         use spl_discriminator::SplDiscriminate;
@@ -28,25 +28,49 @@ impl<'a> SealevelToolsError<'a> {
     pub const CPI: u32 = u32::from_be_bytes(
         crate::discriminator::Discriminator::Sha2(b"sealevel_tools::cpi").to_bytes(),
     );
-}
 
-impl<'a> From<SealevelToolsError<'a>> for ProgramError {
-    fn from(e: SealevelToolsError) -> ProgramError {
-        let (msgs, code) = match e {
-            SealevelToolsError::PassThrough(err) => return err,
-            SealevelToolsError::AccountInfo(err) => {
-                sol_log("Custom error: AccountInfo");
-                (err, SealevelToolsError::ACCOUNT_INFO)
-            }
-            SealevelToolsError::Cpi(err) => {
-                sol_log("Custom error: CPI");
-                (err, SealevelToolsError::CPI)
-            }
+    /// Log the error's label (e.g. "Custom error: AccountInfo") followed by each of its message
+    /// lines. Nothing is logged for [Self::PassThrough].
+    ///
+    /// ### Notes
+    ///
+    /// Logging does not require the "alloc" feature. Without it, the messages are the short
+    /// messages constructed by this crate (without formatted keys or indices).
+    #[inline(always)]
+    pub fn log(&self) {
+        let (label, msgs) = match self {
+            Self::PassThrough(_) => return,
+            Self::AccountInfo(msgs) => ("Custom error: AccountInfo", msgs),
+            Self::Cpi(msgs) => ("Custom error: CPI", msgs),
         };
 
-        msgs.iter().for_each(|err| sol_log(err));
+        sol_log(label);
+        msgs.iter().for_each(|msg| sol_log(msg));
+    }
+
+    /// Convert to [ProgramError] without logging. Use [Self::log_and_into] (or [From]) to log the
+    /// error's messages as well.
+    #[inline(always)]
+    pub fn to_program_error(&self) -> ProgramError {
+        match self {
+            Self::PassThrough(err) => err.clone(),
+            Self::AccountInfo(_) => ProgramError::Custom(Self::ACCOUNT_INFO),
+            Self::Cpi(_) => ProgramError::Custom(Self::CPI),
+        }
+    }
 
-        ProgramError::Custom(code)
+    /// Log the error's messages (see [Self::log]) and convert to [ProgramError]. This method is
+    /// what the [From] implementation uses.
+    #[inline(always)]
+    pub fn log_and_into(self) -> ProgramError {
+        self.log();
+        self.to_program_error()
+    }
+}
+
+impl<'a> From<SealevelToolsError<'a>> for ProgramError {
+    fn from(e: SealevelToolsError) -> ProgramError {
+        e.log_and_into()
     }
 }
 
@@ -55,3 +79,28 @@ impl<'a> From<ProgramError> for SealevelToolsError<'a> {
         SealevelToolsError::PassThrough(e)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_to_program_error() {
+        assert_eq!(
+            SealevelToolsError::AccountInfo(&["a thing"]).to_program_error(),
+            ProgramError::Custom(SealevelToolsError::ACCOUNT_INFO)
+        );
+        assert_eq!(
+            SealevelToolsError::Cpi(&["a thing"]).to_program_error(),
+            ProgramError::Custom(SealevelToolsError::CPI)
+        );
+        assert_eq!(
+            SealevelToolsError::PassThrough(ProgramError::InvalidArgument).to_program_error(),
+            ProgramError::InvalidArgument
+        );
+        assert_eq!(
+            SealevelToolsError::Cpi(&["a thing"]).log_and_into(),
+            ProgramError::Custom(SealevelToolsError::CPI)
+        );
+    }
+}
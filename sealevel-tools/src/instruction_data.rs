@@ -0,0 +1,100 @@
+//! Utilities for decoding instruction data without allocating heap memory.
+
+use crate::{program_error::ProgramError, pubkey::Pubkey};
+
+/// Read a [borsh]-style array of pubkeys (a little-endian u32 length followed by the pubkeys) from
+/// the front of the data and advance the data past it. The pubkeys are returned as a slice
+/// borrowing the data, so no heap memory is allocated (unlike deserializing a [Vec]).
+///
+/// If the data is too short for the length prefix or for the number of pubkeys it specifies,
+/// [ProgramError::InvalidInstructionData] is returned and the data is not advanced. Because
+/// [Pubkey] has an alignment of 1, the pubkeys can be read from any offset.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     entrypoint::ProgramResult,
+///     instruction_data::read_pubkeys,
+///     discriminator::assert_fully_consumed,
+/// };
+///
+/// fn process_set_admins(mut instruction_data: &[u8]) -> ProgramResult {
+///     let new_admins = read_pubkeys(&mut instruction_data)?;
+///     assert_fully_consumed(instruction_data)?;
+///
+///     // Store the new admins.
+///     let _ = new_admins;
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [borsh]: https://docs.rs/borsh/latest/borsh/
+/// [Vec]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+#[inline(always)]
+pub fn read_pubkeys<'a>(data: &mut &'a [u8]) -> Result<&'a [Pubkey], ProgramError> {
+    let (len, remaining) = data
+        .split_first_chunk::<4>()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    let pubkeys_len = (u32::from_le_bytes(*len) as usize)
+        .checked_mul(core::mem::size_of::<Pubkey>())
+        .filter(|&pubkeys_len| pubkeys_len <= remaining.len())
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    let (pubkeys, remaining) = remaining.split_at(pubkeys_len);
+    let pubkeys =
+        bytemuck::try_cast_slice(pubkeys).map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    *data = remaining;
+
+    Ok(pubkeys)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_read_pubkeys() {
+        let keys = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+        let mut encoded = [0; 4 + 2 * 32 + 1];
+        encoded[..4].copy_from_slice(&2_u32.to_le_bytes());
+        encoded[4..36].copy_from_slice(keys[0].as_ref());
+        encoded[36..68].copy_from_slice(keys[1].as_ref());
+        encoded[68] = 69;
+
+        let mut data = &encoded[..];
+        assert_eq!(read_pubkeys(&mut data), Ok(keys.as_slice()));
+        assert_eq!(data, [69]);
+
+        // Empty array.
+        let mut data = &[0, 0, 0, 0][..];
+        assert_eq!(read_pubkeys(&mut data), Ok([].as_slice()));
+        assert!(data.is_empty());
+
+        // Truncated pubkeys.
+        let mut data = &encoded[..67];
+        assert_eq!(
+            read_pubkeys(&mut data),
+            Err(ProgramError::InvalidInstructionData)
+        );
+        assert_eq!(data.len(), 67);
+
+        // Truncated length.
+        let mut data = &encoded[..3];
+        assert_eq!(
+            read_pubkeys(&mut data),
+            Err(ProgramError::InvalidInstructionData)
+        );
+
+        // Length exceeds data.
+        let mut data = &[255, 255, 255, 255][..];
+        assert_eq!(
+            read_pubkeys(&mut data),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+}
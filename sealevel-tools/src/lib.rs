@@ -86,7 +86,7 @@
 //! ```
 //! use sealevel_tools::{
 //!     borsh::{io, BorshDeserialize, BorshSerialize},
-//!     discriminator::Discriminator,
+//!     discriminator::{Discriminator, Selector},
 //!     entrypoint::{entrypoint_nostd, NoStdAccountInfo, ProgramResult},
 //!     msg,
 //!     program_error::ProgramError,
@@ -106,19 +106,20 @@
 //!     DoSomethingElse { a: u32, b: [u8; 12] }
 //! }
 //!
-//! pub type Selector = [u8; 4];
-//!
 //! impl ProgramInstruction {
-//!     pub const DO_SOMETHING: Selector = Discriminator::Sha2(b"do_something").to_bytes();
-//!     pub const ADD_THING: Selector = Discriminator::Sha2(b"add_thing").to_bytes();
-//!     pub const REMOVE_THING: Selector = Discriminator::Sha2(b"remove_thing").to_bytes();
-//!     pub const DO_SOMETHING_ELSE: Selector =
-//!         Discriminator::Sha2(b"do_something_else").to_bytes();
+//!     pub const DO_SOMETHING: Selector<4> =
+//!         Selector::from_discriminator(Discriminator::Sha2(b"do_something"));
+//!     pub const ADD_THING: Selector<4> =
+//!         Selector::from_discriminator(Discriminator::Sha2(b"add_thing"));
+//!     pub const REMOVE_THING: Selector<4> =
+//!         Selector::from_discriminator(Discriminator::Sha2(b"remove_thing"));
+//!     pub const DO_SOMETHING_ELSE: Selector<4> =
+//!         Selector::from_discriminator(Discriminator::Sha2(b"do_something_else"));
 //! }
 //!
 //! impl BorshDeserialize for ProgramInstruction {
 //!     fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
-//!         match BorshDeserialize::deserialize_reader(reader)? {
+//!         match Selector::<4>::deserialize_reader(reader)? {
 //!             Self::DO_SOMETHING => Ok(Self::DoSomething(BorshDeserialize::deserialize_reader(
 //!                 reader,
 //!             )?)),
@@ -444,12 +445,21 @@
 
 pub mod account;
 pub mod account_info;
+pub mod amount;
 pub mod cpi;
 pub mod discriminator;
 mod error;
+pub mod instruction_data;
 pub mod log;
+mod panic;
 pub mod pda;
+mod program_id;
 pub mod sysvar;
+#[cfg(test)]
+mod test_fixture;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+pub mod util;
 
 pub use error::SealevelToolsError;
 
@@ -469,11 +479,17 @@ pub mod entrypoint {
         NoStdAccountInfo, NoStdAccountInfoInner, RcRefCellInner, Ref, RefMut,
     };
 
+    pub use crate::{
+        debug_panic_impl, entrypoint_nostd_with_program_id, program_id::assert_program_id,
+    };
+
     pub use crate::program_error::ProgramResult;
 }
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(test)]
+extern crate std;
 
 #[cfg(feature = "borsh")]
 pub use borsh;
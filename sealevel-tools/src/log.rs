@@ -24,16 +24,13 @@
 //! [env_logger]: https://docs.rs/env_logger
 //! [RpcClient::get_transaction]: https://docs.rs/solana-rpc-client/latest/solana_rpc_client/rpc_client/struct.RpcClient.html#method.get_transaction
 //!
-//! While most logging functions are defined in this module, a [Pubkey] can
-//! also be efficiently logged with the [Pubkey::log] function.
+//! A [Pubkey] can be efficiently logged with the [sol_log_pubkey] function.
 //!
-//! [Pubkey]: crate::pubkey::Pubkey
-//! [Pubkey::log]: crate::pubkey::Pubkey::log
 //! [formatted strings]: https://doc.rust-lang.org/std/fmt/
 //! [log]: https://docs.rs/solana-program/latest/solana_program/log/index.html
 //! [solana_program]: https://docs.rs/solana-program/
 
-use crate::account_info::NoStdAccountInfo;
+use crate::{account_info::NoStdAccountInfo, pubkey::Pubkey};
 pub use solana_msg::{msg, sol_log};
 
 /// Print 64-bit values represented as hexadecimal to the log.
@@ -68,6 +65,123 @@ pub fn sol_log_data(data: &[&[u8]]) {
     }
 }
 
+/// Label of the record emitted by [log_account_created].
+pub const ACCOUNT_CREATED_LOG_LABEL: &[u8] = b"account_created";
+
+/// Emit a standardized record for indexers when an account is created. The record is logged via
+/// [sol_log_data] as three slices: [ACCOUNT_CREATED_LOG_LABEL], the account's key bytes and its
+/// data length (as little-endian u64).
+///
+/// Off-chain, this method does nothing.
+#[allow(unexpected_cfgs)]
+#[inline(always)]
+pub fn log_account_created(key: &Pubkey, space: usize) {
+    #[cfg(target_os = "solana")]
+    sol_log_data(&[
+        ACCOUNT_CREATED_LOG_LABEL,
+        key.as_ref(),
+        &(space as u64).to_le_bytes(),
+    ]);
+
+    #[cfg(not(target_os = "solana"))]
+    let _ = (key, space);
+}
+
+/// Print panic info (location and message) to the log. With the `debug-panic` feature disabled,
+/// only "panicked!" is logged. This method is called by [debug_panic_impl].
+///
+/// [debug_panic_impl]: crate::entrypoint::debug_panic_impl
+#[inline(always)]
+pub fn sol_log_panic(info: &core::panic::PanicInfo<'_>) {
+    #[cfg(feature = "debug-panic")]
+    {
+        use core::fmt::Write;
+
+        let mut buf = crate::panic::PanicLogBuffer::new();
+
+        // A truncated message is still worth logging.
+        let _ = write!(buf, "{info}");
+        sol_log(buf.as_str());
+    }
+
+    #[cfg(not(feature = "debug-panic"))]
+    {
+        let _ = info;
+        sol_log("panicked!");
+    }
+}
+
+/// Print a [Pubkey] as base58 using the `sol_log_pubkey` syscall. This method is much cheaper than
+/// logging a formatted pubkey (e.g. `msg!("{}", key)`), which requires base58 encoding on the heap.
+#[allow(unexpected_cfgs)]
+#[inline(always)]
+pub fn sol_log_pubkey(key: &Pubkey) {
+    #[cfg(target_os = "solana")]
+    unsafe {
+        solana_pubkey::syscalls::sol_log_pubkey(key.as_ref().as_ptr())
+    };
+
+    #[cfg(not(target_os = "solana"))]
+    {
+        let _ = key;
+        sol_log("sol_log_pubkey() not available");
+    }
+}
+
+/// Maximum length of the label passed into [sol_log_labeled_u64]. Longer labels are truncated.
+pub const MAX_LOG_LABEL_LEN: usize = 64;
+
+const MAX_U64_DIGITS: usize = 20;
+
+/// Print a label followed by an integer in decimal (e.g. "Account index: 3"). Unlike [msg!], this
+/// method does not use [core::fmt] or allocate to the heap, so it is suitable for programs without
+/// an allocator (and costs much less program size than formatting).
+///
+/// Labels longer than [MAX_LOG_LABEL_LEN] bytes are truncated.
+#[inline(always)]
+pub fn sol_log_labeled_u64(label: &str, value: u64) {
+    let mut buf = [0; MAX_LOG_LABEL_LEN + MAX_U64_DIGITS];
+    let len = _write_labeled_u64(&mut buf, label, value);
+
+    // Only whole UTF-8 characters of the label and ASCII digits are written.
+    if let Ok(message) = core::str::from_utf8(&buf[..len]) {
+        sol_log(message);
+    }
+}
+
+#[inline(always)]
+fn _write_labeled_u64(
+    buf: &mut [u8; MAX_LOG_LABEL_LEN + MAX_U64_DIGITS],
+    label: &str,
+    value: u64,
+) -> usize {
+    let mut label_len = label.len().min(MAX_LOG_LABEL_LEN);
+    while !label.is_char_boundary(label_len) {
+        label_len -= 1;
+    }
+    buf[..label_len].copy_from_slice(&label.as_bytes()[..label_len]);
+
+    // Write digits in reverse, then move them after the label.
+    let mut digits = [0; MAX_U64_DIGITS];
+    let mut num_digits = 0;
+    let mut remaining = value;
+
+    loop {
+        digits[MAX_U64_DIGITS - 1 - num_digits] = b'0' + (remaining % 10) as u8;
+        num_digits += 1;
+        remaining /= 10;
+
+        if remaining == 0 {
+            break;
+        }
+    }
+
+    let len = label_len + num_digits;
+    buf[label_len..len].copy_from_slice(&digits[(MAX_U64_DIGITS - num_digits)..]);
+
+    len
+}
+
 /// Print the hexadecimal representation of a slice.
 #[inline(always)]
 pub fn sol_log_slice(slice: &[u8]) {
@@ -88,14 +202,14 @@ pub fn sol_log_params(accounts: &[NoStdAccountInfo], data: &[u8]) {
         sol_log("- Is signer");
         sol_log_64(0, 0, 0, 0, account.is_signer() as u64);
         sol_log("- Key");
-        account.key().log();
+        sol_log_pubkey(account.key());
         sol_log("- Lamports");
         let lamports = unsafe { *account.unchecked_borrow_lamports() };
         sol_log_64(0, 0, 0, 0, lamports);
         sol_log("- Account data length");
         sol_log_64(0, 0, 0, 0, account.data_len() as u64);
         sol_log("- Owner");
-        account.owner().log();
+        sol_log_pubkey(account.owner());
     }
     sol_log("Instruction data");
     sol_log_slice(data);
@@ -113,3 +227,29 @@ pub fn sol_log_compute_units() {
     #[cfg(not(target_os = "solana"))]
     sol_log("sol_log_compute_units() not available");
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_write_labeled_u64() {
+        let mut buf = [0; MAX_LOG_LABEL_LEN + MAX_U64_DIGITS];
+
+        let len = _write_labeled_u64(&mut buf, "Account index: ", 3);
+        assert_eq!(&buf[..len], b"Account index: 3");
+
+        let len = _write_labeled_u64(&mut buf, "", 0);
+        assert_eq!(&buf[..len], b"0");
+
+        let len = _write_labeled_u64(&mut buf, "Max: ", u64::MAX);
+        assert_eq!(&buf[..len], b"Max: 18446744073709551615");
+
+        // Label is truncated at a character boundary.
+        let mut label = [b'a'; MAX_LOG_LABEL_LEN + 1];
+        label[(MAX_LOG_LABEL_LEN - 1)..].copy_from_slice("é".as_bytes());
+        let len = _write_labeled_u64(&mut buf, core::str::from_utf8(&label).unwrap(), 69);
+        assert_eq!(len, MAX_LOG_LABEL_LEN - 1 + 2);
+        assert_eq!(&buf[(MAX_LOG_LABEL_LEN - 1)..len], b"69");
+    }
+}
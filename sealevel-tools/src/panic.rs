@@ -0,0 +1,119 @@
+/// Define a `custom_panic` hook that logs the panic location and message (e.g.
+/// `panicked at src/processor.rs:42:13: attempt to subtract with overflow`). Use this macro in
+/// place of [basic_panic_impl] (not alongside it, since both define `custom_panic`).
+///
+/// ### Notes
+///
+/// The location and message are only logged with the `debug-panic` feature enabled. Formatting the
+/// panic info pulls in [core::fmt] machinery and the file paths of every panic site, which can add
+/// kilobytes to the program binary. Without this feature, only "panicked!" is logged, which keeps
+/// release builds as small as with [basic_panic_impl].
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::entrypoint::{
+///     debug_panic_impl, entrypoint_nostd, NoStdAccountInfo, ProgramResult,
+/// };
+///
+/// pub fn process_instruction(
+///     program_id: &Pubkey,
+///     accounts: &[NoStdAccountInfo],
+///     instruction_data: &[u8],
+/// ) -> ProgramResult {
+///     Ok(())
+/// }
+///
+/// entrypoint_nostd!(process_instruction, 8);
+/// debug_panic_impl!();
+///
+/// # use sealevel_tools::pubkey::Pubkey;
+/// ```
+///
+/// [basic_panic_impl]: crate::entrypoint::basic_panic_impl
+#[macro_export]
+macro_rules! debug_panic_impl {
+    () => {
+        #[cfg(target_os = "solana")]
+        #[no_mangle]
+        fn custom_panic(info: &core::panic::PanicInfo<'_>) {
+            $crate::log::sol_log_panic(info);
+        }
+    };
+}
+
+#[cfg(feature = "debug-panic")]
+const PANIC_LOG_BUFFER_LEN: usize = 256;
+
+/// Stack buffer used to format panic info without an allocator. Writes past its capacity are
+/// truncated at a UTF-8 character boundary.
+#[cfg(feature = "debug-panic")]
+pub(crate) struct PanicLogBuffer {
+    buf: [u8; PANIC_LOG_BUFFER_LEN],
+    len: usize,
+}
+
+#[cfg(feature = "debug-panic")]
+impl PanicLogBuffer {
+    #[inline(always)]
+    pub(crate) const fn new() -> Self {
+        Self {
+            buf: [0; PANIC_LOG_BUFFER_LEN],
+            len: 0,
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn as_str(&self) -> &str {
+        // Only whole UTF-8 characters are ever written, so this should never fail. But we cannot
+        // panic while handling a panic.
+        core::str::from_utf8(&self.buf[..self.len]).unwrap_or("panicked!")
+    }
+}
+
+#[cfg(feature = "debug-panic")]
+impl core::fmt::Write for PanicLogBuffer {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let remaining = PANIC_LOG_BUFFER_LEN - self.len;
+
+        let mut n = s.len().min(remaining);
+        while !s.is_char_boundary(n) {
+            n -= 1;
+        }
+
+        self.buf[self.len..(self.len + n)].copy_from_slice(&s.as_bytes()[..n]);
+        self.len += n;
+
+        if n < s.len() {
+            Err(core::fmt::Error)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(all(test, feature = "debug-panic"))]
+mod test {
+    use core::fmt::Write;
+
+    use super::*;
+
+    #[test]
+    fn test_panic_log_buffer() {
+        let mut buf = PanicLogBuffer::new();
+        assert!(write!(buf, "panicked at src/lib.rs:{}", 69).is_ok());
+        assert_eq!(buf.as_str(), "panicked at src/lib.rs:69");
+
+        // Fill up to one byte short of capacity, then attempt to write a two-byte character.
+        let mut buf = PanicLogBuffer::new();
+        for _ in 0..(PANIC_LOG_BUFFER_LEN - 1) {
+            assert!(buf.write_str("a").is_ok());
+        }
+        assert!(buf.write_str("é").is_err());
+        assert_eq!(buf.as_str().len(), PANIC_LOG_BUFFER_LEN - 1);
+
+        assert!(buf.write_str("a").is_ok());
+        assert!(buf.write_str("a").is_err());
+        assert_eq!(buf.as_str().len(), PANIC_LOG_BUFFER_LEN);
+    }
+}
@@ -1,6 +1,11 @@
 //! Utilities for PDAs (program-derived accounts) like deriving PDA addresses.
 
-use crate::pubkey::Pubkey;
+use crate::{
+    entrypoint::ProgramResult,
+    error::SealevelToolsError,
+    program_error::ProgramError,
+    pubkey::{Pubkey, MAX_SEEDS},
+};
 
 /// Simple trait to derive a PDA address for a type given some seeds.
 ///
@@ -124,6 +129,249 @@ macro_rules! impl_to_seed {
 
 impl_to_seed!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
 
+/// Single typed component of [PdaSeeds]. Byte slices and pubkeys are borrowed while integers are
+/// encoded as little-endian bytes into a fixed buffer, so the encoding used by a program and its
+/// clients is always the same.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PdaSeed<'a> {
+    Borrowed(&'a [u8]),
+    Encoded { bytes: [u8; 16], len: usize },
+}
+
+impl<'a> PdaSeed<'a> {
+    /// Seed bytes used to derive the address.
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            Self::Borrowed(bytes) => bytes,
+            Self::Encoded { bytes, len } => &bytes[..*len],
+        }
+    }
+}
+
+impl<'a> From<&'a [u8]> for PdaSeed<'a> {
+    #[inline(always)]
+    fn from(bytes: &'a [u8]) -> Self {
+        Self::Borrowed(bytes)
+    }
+}
+
+impl<'a, const N: usize> From<&'a [u8; N]> for PdaSeed<'a> {
+    #[inline(always)]
+    fn from(bytes: &'a [u8; N]) -> Self {
+        Self::Borrowed(bytes)
+    }
+}
+
+impl<'a> From<&'a Pubkey> for PdaSeed<'a> {
+    #[inline(always)]
+    fn from(key: &'a Pubkey) -> Self {
+        Self::Borrowed(key.as_ref())
+    }
+}
+
+macro_rules! impl_pda_seed_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl<'a> From<$t> for PdaSeed<'a> {
+                #[inline(always)]
+                fn from(value: $t) -> Self {
+                    const LEN: usize = core::mem::size_of::<$t>();
+
+                    let mut bytes = [0; 16];
+                    bytes[..LEN].copy_from_slice(&value.to_le_bytes());
+
+                    Self::Encoded { bytes, len: LEN }
+                }
+            }
+        )*
+    };
+}
+
+impl_pda_seed_from_int!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+/// Typed seeds used to derive a PDA. Build these seeds from a tuple of components that can be
+/// converted into [PdaSeed] (byte slices, pubkeys and integers).
+///
+/// ### Notes
+///
+/// Integers are encoded as little-endian bytes (unlike [ToSeed], which encodes big-endian bytes).
+/// Clients deriving the same address must encode integers the same way.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{pda::PdaSeeds, pubkey::Pubkey};
+///
+/// sealevel_tools::declare_id!("ThingProcessor11111111111111111111111111111");
+///
+/// let mint = Pubkey::new_unique();
+/// let index = 69_u64;
+///
+/// let seeds = PdaSeeds::from((b"thing", &mint, index));
+/// let (addr, bump) = seeds.find_program_address(&ID);
+///
+/// assert_eq!(
+///     (addr, bump),
+///     Pubkey::find_program_address(&[b"thing", mint.as_ref(), &index.to_le_bytes()], &ID)
+/// );
+/// assert_eq!(seeds.create_program_address(bump, &ID), Some(addr));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PdaSeeds<'a, const N: usize>(pub [PdaSeed<'a>; N]);
+
+impl<'a, const N: usize> PdaSeeds<'a, N> {
+    /// Seeds as byte slices, which can be used for [Pubkey::find_program_address].
+    #[inline(always)]
+    pub fn to_seeds(&self) -> [&[u8]; N] {
+        let mut seeds: [&[u8]; N] = [&[]; N];
+        seeds
+            .iter_mut()
+            .zip(self.0.iter())
+            .for_each(|(seed, component)| *seed = component.as_bytes());
+
+        seeds
+    }
+
+    /// Seeds with the bump seed appended, which can be used as signer seeds for CPI. Only the
+    /// first `N + 1` elements of the returned array are populated, which is the length returned
+    /// with the array.
+    ///
+    /// ### Notes
+    ///
+    /// `N` must be less than [MAX_SEEDS] to leave room for the bump seed. This requirement is
+    /// checked at compile time, so the following does not compile:
+    ///
+    /// ```compile_fail
+    /// use sealevel_tools::{
+    ///     pda::{PdaSeed, PdaSeeds},
+    ///     pubkey::MAX_SEEDS,
+    /// };
+    ///
+    /// let seeds = PdaSeeds([PdaSeed::Borrowed(b"thing"); MAX_SEEDS]);
+    /// let _ = seeds.to_signer_seeds(&[255]);
+    /// ```
+    #[inline(always)]
+    pub fn to_signer_seeds<'b>(&'b self, bump_seed: &'b [u8; 1]) -> ([&'b [u8]; MAX_SEEDS], usize) {
+        const { assert!(N < MAX_SEEDS, "PDA seeds leave no room for bump seed") };
+
+        match append_bump_seed(&self.to_seeds(), bump_seed) {
+            Ok(signer_seeds) => signer_seeds,
+            // N < MAX_SEEDS is checked at compile time.
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Find the PDA address and its canonical bump for the given program ID.
+    #[inline(always)]
+    pub fn find_program_address(&self, program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&self.to_seeds(), program_id)
+    }
+
+    /// Create the PDA address with the given bump seed. Returns [None] if the seeds with this bump
+    /// do not produce a valid PDA.
+    #[inline(always)]
+    pub fn create_program_address(&self, bump_seed: u8, program_id: &Pubkey) -> Option<Pubkey> {
+        let bump_seed = [bump_seed];
+        let (seeds, len) = self.to_signer_seeds(&bump_seed);
+
+        Pubkey::create_program_address(&seeds[..len], program_id).ok()
+    }
+}
+
+macro_rules! impl_pda_seeds_from_tuple {
+    ($n:literal: $($t:ident),+) => {
+        impl<'a, $($t: Into<PdaSeed<'a>>),+> From<($($t,)+)> for PdaSeeds<'a, $n> {
+            #[inline(always)]
+            #[allow(non_snake_case)]
+            fn from(($($t,)+): ($($t,)+)) -> Self {
+                Self([$($t.into()),+])
+            }
+        }
+    };
+}
+
+impl_pda_seeds_from_tuple!(1: A);
+impl_pda_seeds_from_tuple!(2: A, B);
+impl_pda_seeds_from_tuple!(3: A, B, C);
+impl_pda_seeds_from_tuple!(4: A, B, C, D);
+impl_pda_seeds_from_tuple!(5: A, B, C, D, E);
+impl_pda_seeds_from_tuple!(6: A, B, C, D, E, F);
+
+/// Assert that the provided bump is the canonical bump for the given seeds and program ID (i.e. the
+/// bump found by [Pubkey::find_program_address]). Use this method when the bump comes from an
+/// untrusted source (e.g. instruction data), where a non-canonical bump used with
+/// [Pubkey::create_program_address] may derive a different valid address.
+///
+/// If the bump is not canonical, a custom program error code with
+/// [SealevelToolsError::ACCOUNT_INFO] is returned.
+///
+/// ### Notes
+///
+/// This method calls [Pubkey::find_program_address], which costs 1,500 compute units per bump
+/// attempted (starting from 255). Canonical bumps are usually found within the first few attempts,
+/// but the cost is unbounded in theory. If the bump is stored in an account that your program
+/// created with the canonical bump, this check is unnecessary.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{entrypoint::ProgramResult, pda::assert_canonical_bump, pubkey::Pubkey};
+///
+/// fn check_provided_bump(program_id: &Pubkey, user: &Pubkey, bump: u8) -> ProgramResult {
+///     assert_canonical_bump(&[b"user", user.as_ref()], program_id, bump)
+/// }
+/// ```
+#[inline(always)]
+pub fn assert_canonical_bump(seeds: &[&[u8]], program_id: &Pubkey, bump: u8) -> ProgramResult {
+    let (_, canonical_bump) = Pubkey::find_program_address(seeds, program_id);
+
+    if bump == canonical_bump {
+        Ok(())
+    } else {
+        Err(SealevelToolsError::AccountInfo(&["Bump is not canonical"]).into())
+    }
+}
+
+/// Append the bump seed to the given seeds, which can be used as signer seeds for CPI or to create
+/// a PDA address with [Pubkey::create_program_address]. Only the first `seeds.len() + 1` elements of
+/// the returned array are populated, which is the length returned with the array.
+///
+/// Returns [ProgramError::MaxSeedLengthExceeded] if there is no room for the bump seed (i.e. there
+/// are already [MAX_SEEDS] seeds).
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{pda::append_bump_seed, pubkey::Pubkey};
+///
+/// let program_id = Pubkey::new_unique();
+/// let (addr, bump) = Pubkey::find_program_address(&[b"thing"], &program_id);
+///
+/// let bump_seed = [bump];
+/// let (signer_seeds, len) = append_bump_seed(&[b"thing"], &bump_seed).unwrap();
+///
+/// assert_eq!(
+///     Pubkey::create_program_address(&signer_seeds[..len], &program_id),
+///     Ok(addr)
+/// );
+/// ```
+#[inline(always)]
+pub fn append_bump_seed<'a>(
+    seeds: &[&'a [u8]],
+    bump_seed: &'a [u8; 1],
+) -> Result<([&'a [u8]; MAX_SEEDS], usize), ProgramError> {
+    if seeds.len() >= MAX_SEEDS {
+        return Err(ProgramError::MaxSeedLengthExceeded);
+    }
+
+    let mut signer_seeds: [&[u8]; MAX_SEEDS] = [&[]; MAX_SEEDS];
+    signer_seeds[..seeds.len()].copy_from_slice(seeds);
+    signer_seeds[seeds.len()] = bump_seed;
+
+    Ok((signer_seeds, seeds.len() + 1))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -135,4 +383,145 @@ mod test {
         assert_eq!(x.to_seed(), x.to_be_bytes());
         assert_eq!(x.to_seed(), [0, 0, 4, 0]);
     }
+
+    #[test]
+    fn test_pda_seeds() {
+        let program_id = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let index = 0x0400_u64;
+
+        let seeds = PdaSeeds::from((&mint, index));
+        assert_eq!(
+            seeds.to_seeds(),
+            [mint.as_ref(), &[0, 4, 0, 0, 0, 0, 0, 0][..]]
+        );
+
+        let (addr, bump) = seeds.find_program_address(&program_id);
+        assert_eq!(
+            (addr, bump),
+            Pubkey::find_program_address(&[mint.as_ref(), &index.to_le_bytes()], &program_id)
+        );
+        assert_eq!(seeds.create_program_address(bump, &program_id), Some(addr));
+
+        let bump_seed = [bump];
+        let (signer_seeds, len) = seeds.to_signer_seeds(&bump_seed);
+        assert_eq!(len, 3);
+        assert_eq!(
+            &signer_seeds[..len],
+            &[mint.as_ref(), &index.to_le_bytes()[..], &[bump][..]]
+        );
+    }
+
+    #[test]
+    fn test_pda_seeds_little_endian_integers() {
+        let program_id = Pubkey::new_unique();
+
+        macro_rules! assert_little_endian {
+            ($($x:expr),*) => {
+                $(
+                    let x = $x;
+                    assert_eq!(
+                        PdaSeeds::from((x,)).find_program_address(&program_id),
+                        Pubkey::find_program_address(&[&x.to_le_bytes()], &program_id)
+                    );
+                )*
+            };
+        }
+
+        assert_little_endian!(
+            0x01_u8,
+            0x0102_u16,
+            0x01020304_u32,
+            0x0102030405060708_u64,
+            0x0102030405060708090a0b0c0d0e0f10_u128,
+            -2_i8,
+            -0x0102_i16,
+            -0x01020304_i32,
+            -0x0102030405060708_i64,
+            -0x0102030405060708090a0b0c0d0e0f10_i128
+        );
+    }
+
+    #[test]
+    fn test_pda_seeds_max_components() {
+        let program_id = Pubkey::new_unique();
+
+        // The bump seed takes the last available slot.
+        let seeds = PdaSeeds([PdaSeed::Borrowed(b"thing"); MAX_SEEDS - 1]);
+        let (addr, bump) = seeds.find_program_address(&program_id);
+
+        let bump_seed = [bump];
+        let (signer_seeds, len) = seeds.to_signer_seeds(&bump_seed);
+        assert_eq!(len, MAX_SEEDS);
+        assert_eq!(signer_seeds[MAX_SEEDS - 1], &[bump][..]);
+        assert_eq!(seeds.create_program_address(bump, &program_id), Some(addr));
+    }
+
+    #[test]
+    fn test_pda_seeds_mixed_components() {
+        let program_id = Pubkey::new_unique();
+        let user = Pubkey::new_unique();
+        let suffix: &[u8] = b"suffix";
+
+        let seeds = PdaSeeds::from((b"thing", &user, 1_u8, -1_i16, suffix));
+        assert_eq!(
+            seeds.find_program_address(&program_id),
+            Pubkey::find_program_address(
+                &[b"thing", user.as_ref(), &[1], &[0xff, 0xff], b"suffix"],
+                &program_id
+            )
+        );
+    }
+
+    #[test]
+    fn test_assert_canonical_bump() {
+        let program_id = Pubkey::new_unique();
+        let seeds: &[&[u8]] = &[b"thing"];
+        let (_, bump) = Pubkey::find_program_address(seeds, &program_id);
+
+        assert_eq!(assert_canonical_bump(seeds, &program_id, bump), Ok(()));
+
+        // Find a non-canonical bump that still derives a valid address.
+        let non_canonical_bump = (0..bump)
+            .rev()
+            .find(|&other| {
+                Pubkey::create_program_address(&[b"thing", &[other]], &program_id).is_ok()
+            })
+            .unwrap();
+        assert_eq!(
+            assert_canonical_bump(seeds, &program_id, non_canonical_bump),
+            Err(crate::program_error::ProgramError::Custom(
+                SealevelToolsError::ACCOUNT_INFO
+            ))
+        );
+    }
+
+    #[test]
+    fn test_append_bump_seed() {
+        let bump_seed = [255];
+
+        let (signer_seeds, len) = append_bump_seed(&[b"vault", b"authority"], &bump_seed).unwrap();
+        assert_eq!(len, 3);
+        assert_eq!(
+            signer_seeds[..len],
+            [b"vault".as_slice(), b"authority", &[255]]
+        );
+
+        // No seeds besides the bump.
+        let (signer_seeds, len) = append_bump_seed(&[], &bump_seed).unwrap();
+        assert_eq!(signer_seeds[..len], [&[255]]);
+
+        // Maximum number of seeds including the bump.
+        let seeds = [b"seed".as_slice(); MAX_SEEDS - 1];
+        let (signer_seeds, len) = append_bump_seed(&seeds, &bump_seed).unwrap();
+        assert_eq!(len, MAX_SEEDS);
+        assert_eq!(signer_seeds[MAX_SEEDS - 1], &[255]);
+
+        // No room for the bump.
+        let seeds = [b"seed".as_slice(); MAX_SEEDS];
+        assert_eq!(
+            append_bump_seed(&seeds, &bump_seed),
+            Err(ProgramError::MaxSeedLengthExceeded)
+        );
+    }
 }
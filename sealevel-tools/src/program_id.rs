@@ -0,0 +1,98 @@
+use crate::{entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey};
+
+/// Assert that the program ID passed into the entrypoint equals the expected program ID (usually
+/// the `ID` defined by [declare_id]). Returns [ProgramError::IncorrectProgramId] otherwise.
+///
+/// See [entrypoint_nostd_with_program_id] to perform this check automatically.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     entrypoint::{assert_program_id, NoStdAccountInfo, ProgramResult},
+///     pubkey::Pubkey,
+/// };
+///
+/// sealevel_tools::declare_id!("Examp1eTokenManagement1111111111111111111111");
+///
+/// pub fn process_instruction(
+///     program_id: &Pubkey,
+///     accounts: &[NoStdAccountInfo],
+///     instruction_data: &[u8],
+/// ) -> ProgramResult {
+///     assert_program_id(program_id, &ID)?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [declare_id]: crate::declare_id
+/// [entrypoint_nostd_with_program_id]: crate::entrypoint::entrypoint_nostd_with_program_id
+#[inline(always)]
+pub fn assert_program_id(program_id: &Pubkey, expected: &Pubkey) -> ProgramResult {
+    if program_id == expected {
+        Ok(())
+    } else {
+        Err(ProgramError::IncorrectProgramId)
+    }
+}
+
+/// Like [entrypoint_nostd], but checks the program ID with [assert_program_id] before calling the
+/// instruction processor. This check prevents a class of bugs where a program forgets to verify
+/// that it is being invoked as itself.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     entrypoint::{entrypoint_nostd_with_program_id, NoStdAccountInfo, ProgramResult},
+///     pubkey::Pubkey,
+/// };
+///
+/// sealevel_tools::declare_id!("Examp1eTokenManagement1111111111111111111111");
+///
+/// pub fn process_instruction(
+///     program_id: &Pubkey,
+///     accounts: &[NoStdAccountInfo],
+///     instruction_data: &[u8],
+/// ) -> ProgramResult {
+///     // Program ID was already checked.
+///     Ok(())
+/// }
+///
+/// entrypoint_nostd_with_program_id!(process_instruction, ID, 8);
+/// ```
+///
+/// [entrypoint_nostd]: macro@crate::entrypoint::entrypoint_nostd
+/// [assert_program_id]: crate::entrypoint::assert_program_id
+#[macro_export]
+macro_rules! entrypoint_nostd_with_program_id {
+    ($process_instruction:ident, $program_id:expr, $accounts:literal) => {
+        #[inline(always)]
+        fn __process_instruction_with_program_id(
+            program_id: &$crate::pubkey::Pubkey,
+            accounts: &[$crate::entrypoint::NoStdAccountInfo],
+            instruction_data: &[u8],
+        ) -> $crate::entrypoint::ProgramResult {
+            $crate::entrypoint::assert_program_id(program_id, &$program_id)?;
+            $process_instruction(program_id, accounts, instruction_data)
+        }
+
+        $crate::entrypoint::entrypoint_nostd!(__process_instruction_with_program_id, $accounts);
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_assert_program_id() {
+        let program_id = Pubkey::new_unique();
+        assert_eq!(assert_program_id(&program_id, &program_id), Ok(()));
+        assert_eq!(
+            assert_program_id(&Pubkey::new_unique(), &program_id),
+            Err(ProgramError::IncorrectProgramId)
+        );
+    }
+}
@@ -0,0 +1,330 @@
+//! Utility methods for reading the Instructions sysvar account.
+
+crate::declare_id!("Sysvar1nstructions1111111111111111111111111");
+
+use crate::{
+    entrypoint::{NoStdAccountInfo, ProgramResult},
+    error::SealevelToolsError,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// Read the number of accounts passed into the currently executing top-level instruction from the
+/// Instructions sysvar account. This method is useful for instructions whose account layout varies.
+///
+/// ### Notes
+///
+/// If the account is not the Instructions sysvar, [ProgramError::UnsupportedSysvar] is returned. If
+/// the current instruction cannot be determined from the sysvar data (e.g. its index is out of
+/// bounds), [ProgramError::InvalidAccountData] is returned.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+///     program_error::ProgramError,
+///     sysvar::instructions::current_instruction_accounts_len,
+/// };
+///
+/// fn check_accounts(
+///     accounts: &[NoStdAccountInfo],
+///     instructions_sysvar: &NoStdAccountInfo,
+/// ) -> ProgramResult {
+///     let accounts_len = current_instruction_accounts_len(instructions_sysvar)?;
+///
+///     if usize::from(accounts_len) != accounts.len() {
+///         return Err(ProgramError::NotEnoughAccountKeys);
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[inline(always)]
+pub fn current_instruction_accounts_len(account: &NoStdAccountInfo) -> Result<u16, ProgramError> {
+    if account.key() != &ID {
+        return Err(ProgramError::UnsupportedSysvar);
+    }
+
+    _current_instruction_accounts_len(&account.try_borrow_data()?)
+}
+
+/// Assert that a top-level instruction before the currently executing one in the transaction was
+/// sent to `program_id` and its data starts with `discriminator` (e.g. a setup instruction that
+/// must precede this one). Pass in an empty discriminator to match any instruction sent to
+/// `program_id`.
+///
+/// ### Notes
+///
+/// If the account is not the Instructions sysvar, [ProgramError::UnsupportedSysvar] is returned. If
+/// the sysvar data cannot be read, [ProgramError::InvalidAccountData] is returned. If no matching
+/// instruction precedes the current one, a custom program error code with
+/// [SealevelToolsError::ACCOUNT_INFO] is returned.
+///
+/// Only top-level instructions are recorded in the Instructions sysvar, so instructions invoked via
+/// CPI cannot be matched.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     discriminator::Discriminator,
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+///     pubkey::Pubkey,
+///     sysvar::instructions::assert_instruction_before,
+/// };
+///
+/// const SETUP_SELECTOR: [u8; 8] = Discriminator::Sha2(b"global:setup").to_bytes();
+///
+/// fn check_setup_first(
+///     program_id: &Pubkey,
+///     instructions_sysvar: &NoStdAccountInfo,
+/// ) -> ProgramResult {
+///     assert_instruction_before(instructions_sysvar, program_id, &SETUP_SELECTOR)
+/// }
+/// ```
+#[inline(always)]
+pub fn assert_instruction_before(
+    account: &NoStdAccountInfo,
+    program_id: &Pubkey,
+    discriminator: &[u8],
+) -> ProgramResult {
+    if account.key() != &ID {
+        return Err(ProgramError::UnsupportedSysvar);
+    }
+
+    if _has_instruction_before(&account.try_borrow_data()?, program_id, discriminator)? {
+        Ok(())
+    } else {
+        Err(SealevelToolsError::AccountInfo(&[
+            "No matching instruction before current instruction",
+        ])
+        .into())
+    }
+}
+
+/// The Instructions sysvar is serialized as:
+/// - number of instructions (u16).
+/// - offset of each instruction (u16 each).
+/// - each instruction, starting with its number of accounts (u16).
+/// - index of the currently executing instruction (u16) as the last two bytes.
+#[inline(always)]
+fn _current_instruction_accounts_len(data: &[u8]) -> Result<u16, ProgramError> {
+    let current_index = _current_instruction_index(data)?;
+
+    let instruction_offset = _read_u16(data, 2 + 2 * usize::from(current_index))?;
+    _read_u16(data, usize::from(instruction_offset))
+}
+
+/// Each instruction is serialized as:
+/// - number of accounts (u16).
+/// - each account meta (1 byte for flags followed by its 32-byte pubkey).
+/// - program ID (32 bytes).
+/// - instruction data length (u16) followed by the instruction data.
+#[inline(always)]
+fn _has_instruction_before(
+    data: &[u8],
+    program_id: &Pubkey,
+    discriminator: &[u8],
+) -> Result<bool, ProgramError> {
+    let current_index = _current_instruction_index(data)?;
+
+    for index in 0..usize::from(current_index) {
+        let instruction_offset = usize::from(_read_u16(data, 2 + 2 * index)?);
+        let num_accounts = usize::from(_read_u16(data, instruction_offset)?);
+
+        let program_id_offset = instruction_offset + 2 + 33 * num_accounts;
+        let instruction_program_id = data
+            .get(program_id_offset..(program_id_offset + 32))
+            .ok_or(ProgramError::InvalidAccountData)?;
+
+        if instruction_program_id != &program_id.to_bytes()[..] {
+            continue;
+        }
+
+        let data_len_offset = program_id_offset + 32;
+        let data_len = usize::from(_read_u16(data, data_len_offset)?);
+        let instruction_data = data
+            .get((data_len_offset + 2)..(data_len_offset + 2 + data_len))
+            .ok_or(ProgramError::InvalidAccountData)?;
+
+        if instruction_data.starts_with(discriminator) {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+#[inline(always)]
+fn _current_instruction_index(data: &[u8]) -> Result<u16, ProgramError> {
+    let current_index = data
+        .len()
+        .checked_sub(2)
+        .ok_or(ProgramError::InvalidAccountData)
+        .and_then(|offset| _read_u16(data, offset))?;
+
+    if current_index >= _read_u16(data, 0)? {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(current_index)
+}
+
+#[inline(always)]
+fn _read_u16(data: &[u8], offset: usize) -> Result<u16, ProgramError> {
+    data.get(offset..)
+        .and_then(|data| data.first_chunk::<2>())
+        .map(|value| u16::from_le_bytes(*value))
+        .ok_or(ProgramError::InvalidAccountData)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_id() {
+        assert_eq!(ID, solana_sdk::sysvar::instructions::ID);
+    }
+
+    #[test]
+    fn test_current_instruction_accounts_len() {
+        // Two instructions. First has 1 account and 0 bytes of data. Second has 2 accounts and
+        // 1 byte of data.
+        let mut data = [0; 2 + 2 * 2 + (2 + 33 + 32 + 2) + (2 + 2 * 33 + 32 + 2 + 1) + 2];
+        let first_offset = 6;
+        let second_offset = first_offset + 2 + 33 + 32 + 2;
+        data[..2].copy_from_slice(&2_u16.to_le_bytes());
+        data[2..4].copy_from_slice(&(first_offset as u16).to_le_bytes());
+        data[4..6].copy_from_slice(&(second_offset as u16).to_le_bytes());
+        data[first_offset..(first_offset + 2)].copy_from_slice(&1_u16.to_le_bytes());
+        data[second_offset..(second_offset + 2)].copy_from_slice(&2_u16.to_le_bytes());
+
+        let current_index_offset = data.len() - 2;
+
+        assert_eq!(_current_instruction_accounts_len(&data), Ok(1));
+
+        data[current_index_offset..].copy_from_slice(&1_u16.to_le_bytes());
+        assert_eq!(_current_instruction_accounts_len(&data), Ok(2));
+
+        // Current index out of bounds.
+        data[current_index_offset..].copy_from_slice(&2_u16.to_le_bytes());
+        assert_eq!(
+            _current_instruction_accounts_len(&data),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        // Truncated data.
+        assert_eq!(
+            _current_instruction_accounts_len(&[]),
+            Err(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            _current_instruction_accounts_len(&[1, 0, 0]),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    const MAX_TEST_DATA_LEN: usize = 1_024;
+
+    fn serialize_instructions(
+        instructions: &[(&Pubkey, usize, &[u8])],
+        current_index: u16,
+    ) -> ([u8; MAX_TEST_DATA_LEN], usize) {
+        let mut data = [0; MAX_TEST_DATA_LEN];
+        let mut len = 0;
+
+        let mut write = |bytes: &[u8]| {
+            data[len..(len + bytes.len())].copy_from_slice(bytes);
+            len += bytes.len();
+        };
+
+        write(&(instructions.len() as u16).to_le_bytes());
+
+        // Each instruction's serialized length determines the next instruction's offset.
+        let mut offset = 2 + 2 * instructions.len();
+        for (_, num_accounts, instruction_data) in instructions {
+            write(&(offset as u16).to_le_bytes());
+            offset += 2 + 33 * num_accounts + 32 + 2 + instruction_data.len();
+        }
+
+        for (program_id, num_accounts, instruction_data) in instructions {
+            write(&(*num_accounts as u16).to_le_bytes());
+            for _ in 0..*num_accounts {
+                write(&[0]);
+                write(&Pubkey::new_unique().to_bytes());
+            }
+            write(&program_id.to_bytes());
+            write(&(instruction_data.len() as u16).to_le_bytes());
+            write(instruction_data);
+        }
+
+        write(&current_index.to_le_bytes());
+
+        (data, len)
+    }
+
+    #[test]
+    fn test_has_instruction_before() {
+        let program_id = Pubkey::new_unique();
+        let other_program_id = Pubkey::new_unique();
+
+        let instructions: [(&Pubkey, usize, &[u8]); 4] = [
+            (&other_program_id, 1, &[1, 2]),
+            (&program_id, 2, &[3, 4, 5]),
+            (&program_id, 0, &[]),
+            (&program_id, 3, &[6]),
+        ];
+
+        let (data, len) = serialize_instructions(&instructions, 3);
+        let data = &data[..len];
+        assert_eq!(
+            _has_instruction_before(data, &program_id, &[3, 4]),
+            Ok(true)
+        );
+        assert_eq!(_has_instruction_before(data, &program_id, &[]), Ok(true));
+        assert_eq!(
+            _has_instruction_before(data, &other_program_id, &[1]),
+            Ok(true)
+        );
+
+        // Selector does not match.
+        assert_eq!(
+            _has_instruction_before(data, &program_id, &[1, 2]),
+            Ok(false)
+        );
+
+        // Discriminator longer than instruction data.
+        assert_eq!(
+            _has_instruction_before(data, &program_id, &[3, 4, 5, 6]),
+            Ok(false)
+        );
+
+        // Current instruction and those after it are not considered.
+        assert_eq!(_has_instruction_before(data, &program_id, &[6]), Ok(false));
+
+        let (data, len) = serialize_instructions(&instructions, 1);
+        let data = &data[..len];
+        assert_eq!(_has_instruction_before(data, &program_id, &[3]), Ok(false));
+        assert_eq!(
+            _has_instruction_before(data, &other_program_id, &[1, 2]),
+            Ok(true)
+        );
+
+        let (data, len) = serialize_instructions(&instructions, 0);
+        let data = &data[..len];
+        assert_eq!(
+            _has_instruction_before(data, &other_program_id, &[]),
+            Ok(false)
+        );
+
+        // Current index out of bounds.
+        let (data, len) = serialize_instructions(&instructions, 4);
+        let data = &data[..len];
+        assert_eq!(
+            _has_instruction_before(data, &program_id, &[]),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+}
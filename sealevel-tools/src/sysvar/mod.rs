@@ -1,5 +1,12 @@
 //! Utility methods for fetching Sysvar account data.
 
+pub mod instructions;
+
+/// Namespace for Rent sysvar ID.
+pub mod rent {
+    crate::declare_id!("SysvarRent111111111111111111111111111111111");
+}
+
 use solana_clock::Clock;
 use solana_define_syscall::define_syscall;
 use solana_rent::Rent;
@@ -11,6 +18,24 @@ define_syscall!(fn sol_get_rent_sysvar(addr: *mut u8) -> u64);
 ///
 /// This is the preferred way to load a sysvar. Calling this method does not incur any
 /// deserialization overhead, and does not require the sysvar account to be passed to the program.
+///
+/// ### Notes
+///
+/// Each call to this method (including the field getters below, like [get_clock_slot]) invokes a
+/// syscall. If you need more than one field, load [Clock] once and read its fields.
+///
+/// ### Example
+///
+/// ```no_run
+/// use sealevel_tools::sysvar::get_clock;
+///
+/// // One syscall for all fields.
+/// let clock = get_clock();
+///
+/// let epoch = clock.epoch;
+/// let slot = clock.slot;
+/// let unix_timestamp = clock.unix_timestamp;
+/// ```
 #[allow(unexpected_cfgs)]
 #[inline(always)]
 pub fn get_clock() -> Clock {
@@ -25,24 +50,41 @@ pub fn get_clock() -> Clock {
     panic!("Cannot get sysvar on non-Solana targets")
 }
 
-/// Load [Clock::epoch] directly from Solana runtime.
+/// Load [Clock::epoch] directly from Solana runtime. This method loads the entire [Clock], so
+/// use [get_clock] instead if you need other fields as well.
 #[inline(always)]
 pub fn get_clock_epoch() -> u64 {
     get_clock().epoch
 }
 
-/// Load [Clock::slot] directly from Solana runtime.
+/// Load [Clock::slot] directly from Solana runtime. This method loads the entire [Clock], so
+/// use [get_clock] instead if you need other fields as well.
 #[inline(always)]
 pub fn get_clock_slot() -> u64 {
     get_clock().slot
 }
 
-/// Load [Clock::unix_timestamp] directly from Solana runtime.
+/// Load [Clock::unix_timestamp] directly from Solana runtime. This method loads the entire
+/// [Clock], so use [get_clock] instead if you need other fields as well.
 #[inline(always)]
 pub fn get_clock_unix_timestamp() -> i64 {
     get_clock().unix_timestamp
 }
 
+/// Load [Clock::slot] and [Clock::unix_timestamp] directly from Solana runtime with a single
+/// syscall (instead of calling [get_clock_slot] and [get_clock_unix_timestamp], which would invoke
+/// two syscalls).
+#[inline(always)]
+pub fn get_clock_slot_and_timestamp() -> (u64, i64) {
+    let Clock {
+        slot,
+        unix_timestamp,
+        ..
+    } = get_clock();
+
+    (slot, unix_timestamp)
+}
+
 /// Load [Rent] directly from Solana runtime.
 ///
 /// This is the preferred way to load a sysvar. Calling this method does not incur any
@@ -67,3 +109,76 @@ pub fn get_rent() -> Rent {
 pub fn get_rent_minimum_balance(size: usize) -> u64 {
     get_rent().minimum_balance(size)
 }
+
+/// Calculate the total minimum balance due for rent-exemption of multiple account data sizes by
+/// loading [Rent] directly from Solana runtime once (instead of calling [get_rent_minimum_balance]
+/// for each size, which would invoke a syscall per size). This method is useful for checking that
+/// a payer can fund all accounts created by an instruction before creating any of them.
+///
+/// ### Notes
+///
+/// The total saturates at [u64::MAX] instead of overflowing, so a payer's lamports can never
+/// satisfy an overflowed total.
+///
+/// ### Example
+///
+/// ```no_run
+/// use sealevel_tools::{
+///     entrypoint::{NoStdAccountInfo, ProgramResult},
+///     program_error::ProgramError,
+///     sysvar::total_rent_for,
+/// };
+///
+/// fn assert_payer_can_fund(payer: &NoStdAccountInfo, sizes: &[usize]) -> ProgramResult {
+///     if *payer.try_borrow_lamports()? >= total_rent_for(sizes) {
+///         Ok(())
+///     } else {
+///         Err(ProgramError::InsufficientFunds)
+///     }
+/// }
+/// ```
+#[inline(always)]
+pub fn total_rent_for(sizes: &[usize]) -> u64 {
+    _total_rent_for(&get_rent(), sizes)
+}
+
+#[inline(always)]
+fn _total_rent_for(rent: &Rent, sizes: &[usize]) -> u64 {
+    sizes.iter().fold(0, |total, size| {
+        total.saturating_add(rent.minimum_balance(*size))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rent_id() {
+        assert_eq!(rent::ID, solana_sdk::sysvar::rent::ID);
+    }
+
+    #[test]
+    fn test_total_rent_for() {
+        let rent = Rent::default();
+
+        assert_eq!(_total_rent_for(&rent, &[]), 0);
+        assert_eq!(
+            _total_rent_for(&rent, &[0, 165, 82]),
+            rent.minimum_balance(0) + rent.minimum_balance(165) + rent.minimum_balance(82)
+        );
+    }
+
+    #[test]
+    fn test_total_rent_for_saturates() {
+        // Each minimum balance is u64::MAX, so their sum would overflow.
+        let rent = Rent {
+            lamports_per_byte_year: 1,
+            exemption_threshold: f64::MAX,
+            ..Default::default()
+        };
+        assert_eq!(rent.minimum_balance(0), u64::MAX);
+
+        assert_eq!(_total_rent_for(&rent, &[0, 0]), u64::MAX);
+    }
+}
@@ -0,0 +1,186 @@
+//! Shared fixture for unit tests that need [NoStdAccountInfo] instances. Accounts are serialized
+//! the same way the runtime serializes them for a program's entrypoint and are then deserialized
+//! with [deserialize_nostd], so borrows and reallocs behave like they do on-chain.
+
+use core::{mem::MaybeUninit, ops::Deref};
+use std::vec::Vec;
+
+use crate::{
+    account_info::MAX_PERMITTED_DATA_INCREASE,
+    entrypoint::{deserialize_nostd, NoStdAccountInfo},
+    pubkey::Pubkey,
+};
+
+/// Marker the runtime uses for an account that is not a duplicate of a previous account.
+const NON_DUP_MARKER: u8 = u8::MAX;
+
+/// Fields of an account to serialize into [TestAccountInfos].
+#[derive(Debug, Clone, Copy)]
+pub struct TestAccount<'a> {
+    pub key: Pubkey,
+    pub owner: Pubkey,
+    pub lamports: u64,
+    pub data: &'a [u8],
+    pub is_signer: bool,
+    pub is_writable: bool,
+    pub executable: bool,
+}
+
+impl<'a> TestAccount<'a> {
+    /// Read-only, non-signer account with no lamports and no data.
+    pub fn new(key: Pubkey, owner: Pubkey) -> Self {
+        Self {
+            key,
+            owner,
+            lamports: 0,
+            data: &[],
+            is_signer: false,
+            is_writable: false,
+            executable: false,
+        }
+    }
+
+    pub fn lamports(self, lamports: u64) -> Self {
+        Self { lamports, ..self }
+    }
+
+    pub fn data(self, data: &'a [u8]) -> Self {
+        Self { data, ..self }
+    }
+
+    pub fn signer(self) -> Self {
+        Self {
+            is_signer: true,
+            ..self
+        }
+    }
+
+    pub fn writable(self) -> Self {
+        Self {
+            is_writable: true,
+            ..self
+        }
+    }
+
+    pub fn executable(self) -> Self {
+        Self {
+            executable: true,
+            ..self
+        }
+    }
+}
+
+/// Account infos backed by a serialized input buffer, which dereferences to a slice of
+/// [NoStdAccountInfo] (like the accounts passed into an instruction processor).
+///
+/// Duplicate accounts can be made by cloning an account info, which shares the same serialized
+/// account.
+pub struct TestAccountInfos {
+    // Account infos point into this buffer, which is stored as words so it is 8-byte aligned.
+    _input: Vec<u64>,
+    infos: Vec<NoStdAccountInfo>,
+}
+
+impl TestAccountInfos {
+    pub fn new(accounts: &[TestAccount]) -> Self {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&(accounts.len() as u64).to_le_bytes());
+
+        for account in accounts {
+            bytes.extend_from_slice(&[
+                NON_DUP_MARKER,
+                account.is_signer.into(),
+                account.is_writable.into(),
+                account.executable.into(),
+            ]);
+
+            // Original data length (used as realloc byte counter).
+            bytes.extend_from_slice(&[0; 4]);
+
+            bytes.extend_from_slice(account.key.as_ref());
+            bytes.extend_from_slice(account.owner.as_ref());
+            bytes.extend_from_slice(&account.lamports.to_le_bytes());
+            bytes.extend_from_slice(&(account.data.len() as u64).to_le_bytes());
+            bytes.extend_from_slice(account.data);
+
+            // Space reserved for realloc, padded to 8-byte alignment.
+            bytes.resize(
+                (bytes.len() + MAX_PERMITTED_DATA_INCREASE).next_multiple_of(8),
+                0,
+            );
+
+            // Rent epoch.
+            bytes.extend_from_slice(&[0; 8]);
+        }
+
+        // No instruction data followed by the program ID.
+        bytes.extend_from_slice(&[0; 8]);
+        bytes.extend_from_slice(&[0; 32]);
+
+        let mut input = std::vec![0_u64; bytes.len().div_ceil(8)];
+        bytemuck::cast_slice_mut::<u64, u8>(&mut input)[..bytes.len()].copy_from_slice(&bytes);
+
+        let mut infos = (0..accounts.len())
+            .map(|_| MaybeUninit::uninit())
+            .collect::<Vec<_>>();
+
+        let (_, processed, _) = unsafe {
+            deserialize_nostd::<{ usize::MAX }>(input.as_mut_ptr() as *mut u8, &mut infos)
+        };
+        assert_eq!(processed, accounts.len());
+
+        Self {
+            _input: input,
+            infos: infos
+                .into_iter()
+                .map(|info| unsafe { info.assume_init() })
+                .collect(),
+        }
+    }
+}
+
+impl Deref for TestAccountInfos {
+    type Target = [NoStdAccountInfo];
+
+    fn deref(&self) -> &Self::Target {
+        &self.infos
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_test_account_infos() {
+        let key = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let infos = TestAccountInfos::new(&[
+            TestAccount::new(key, owner)
+                .data(&[1, 2, 3])
+                .signer()
+                .writable(),
+            TestAccount::new(Pubkey::new_unique(), Pubkey::new_unique()).executable(),
+        ]);
+        assert_eq!(infos.len(), 2);
+
+        let info = &infos[0];
+        assert_eq!(info.key(), &key);
+        assert_eq!(info.owner(), &owner);
+        assert!(info.is_signer());
+        assert!(info.is_writable());
+        assert!(!info.executable());
+        assert_eq!(&info.try_borrow_data().unwrap()[..], &[1, 2, 3]);
+
+        // Account can grow into the space reserved for realloc.
+        assert_eq!(info.realloc(3 + MAX_PERMITTED_DATA_INCREASE, false), Ok(()));
+        assert_eq!(info.data_len(), 3 + MAX_PERMITTED_DATA_INCREASE);
+
+        let info = &infos[1];
+        assert!(!info.is_signer());
+        assert!(!info.is_writable());
+        assert!(info.executable());
+        assert_eq!(info.data_len(), 0);
+    }
+}
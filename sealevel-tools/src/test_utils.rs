@@ -0,0 +1,213 @@
+//! Utilities for program tests, which are enabled with the "test-utils" feature. This feature is
+//! meant to be enabled for your program's dev-dependencies only.
+//!
+//! ### Notes
+//!
+//! Deriving a program address costs CU for each bump iteration. Because these costs depend on the
+//! derived keys (which may change when program IDs or seeds change), it is useful to subtract them
+//! from the compute units consumed before comparing against a target CU.
+
+use solana_instruction::Instruction;
+
+/// CU cost per bump iteration when deriving a program address (which is the cost of creating a
+/// program address with a known bump).
+pub const BUMP_ITERATION_CU: u64 = 1_500;
+
+/// Lower CU cost per bump iteration observed for some derivation paths (e.g. finding an address
+/// using a single static seed).
+pub const REDUCED_BUMP_ITERATION_CU: u64 = 1_200;
+
+/// Subtract the cost of bump iterations from the compute units consumed.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::test_utils::{adjust_for_bump_iterations, BUMP_ITERATION_CU};
+///
+/// // Bump of 252 requires 4 iterations.
+/// assert_eq!(adjust_for_bump_iterations(12_000, 4, BUMP_ITERATION_CU), 6_000);
+/// ```
+///
+/// # Panics
+///
+/// This method panics if the bump iteration cost exceeds the compute units consumed, which means
+/// the number of iterations or the cost per iteration is wrong.
+pub const fn adjust_for_bump_iterations(cu: u64, iterations: u64, per_iter: u64) -> u64 {
+    match cu.checked_sub(iterations.saturating_mul(per_iter)) {
+        Some(adjusted) => adjusted,
+        None => panic!("Bump iteration cost exceeds compute units consumed"),
+    }
+}
+
+/// Expected signer and writable flags of an account meta in an [Instruction] (see
+/// [assert_account_roles]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountRole {
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl AccountRole {
+    /// Read-only account that does not sign.
+    pub const READONLY: Self = Self {
+        is_signer: false,
+        is_writable: false,
+    };
+
+    /// Writable account that does not sign.
+    pub const WRITABLE: Self = Self {
+        is_signer: false,
+        is_writable: true,
+    };
+
+    /// Read-only signer (like [Authority]).
+    ///
+    /// [Authority]: crate::account_info::Authority
+    pub const AUTHORITY: Self = Self {
+        is_signer: true,
+        is_writable: false,
+    };
+
+    /// Writable signer (like [Payer]).
+    ///
+    /// [Payer]: crate::account_info::Payer
+    pub const PAYER: Self = Self {
+        is_signer: true,
+        is_writable: true,
+    };
+}
+
+/// Assert that each account meta of a built instruction has the expected role. This check catches
+/// account role mistakes (e.g. using `AccountMeta::new_readonly` for an account that the program
+/// writes to) before sending a transaction.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     pubkey::Pubkey,
+///     test_utils::{assert_account_roles, AccountRole},
+/// };
+/// use solana_instruction::{AccountMeta, Instruction};
+///
+/// let instruction = Instruction::new_with_bytes(
+///     Pubkey::new_unique(),
+///     &[],
+///     vec![
+///         AccountMeta::new(Pubkey::new_unique(), true),
+///         AccountMeta::new(Pubkey::new_unique(), false),
+///         AccountMeta::new_readonly(Pubkey::new_unique(), false),
+///     ],
+/// );
+///
+/// assert_account_roles(
+///     &instruction,
+///     &[
+///         AccountRole::PAYER,
+///         AccountRole::WRITABLE,
+///         AccountRole::READONLY,
+///     ],
+/// );
+/// ```
+///
+/// # Panics
+///
+/// This method panics if the number of account metas differs from the number of expected roles or
+/// if any account meta's role differs from its expected role.
+#[track_caller]
+pub fn assert_account_roles(instruction: &Instruction, expected: &[AccountRole]) {
+    assert_eq!(
+        instruction.accounts.len(),
+        expected.len(),
+        "Number of account metas does not match expected roles"
+    );
+
+    for (index, (meta, expected)) in instruction.accounts.iter().zip(expected).enumerate() {
+        let found = AccountRole {
+            is_signer: meta.is_signer,
+            is_writable: meta.is_writable,
+        };
+
+        assert_eq!(
+            found, *expected,
+            "Account index {} ({}) has unexpected role",
+            index, meta.pubkey
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use solana_instruction::AccountMeta;
+
+    use crate::pubkey::Pubkey;
+
+    use super::*;
+
+    #[test]
+    fn test_adjust_for_bump_iterations() {
+        assert_eq!(adjust_for_bump_iterations(6_350, 0, BUMP_ITERATION_CU), 6_350);
+        assert_eq!(
+            adjust_for_bump_iterations(13_850, 5, BUMP_ITERATION_CU),
+            6_350
+        );
+        assert_eq!(
+            adjust_for_bump_iterations(3_730, 1, REDUCED_BUMP_ITERATION_CU),
+            2_530
+        );
+        assert_eq!(adjust_for_bump_iterations(1_500, 1, BUMP_ITERATION_CU), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Bump iteration cost exceeds compute units consumed")]
+    fn test_adjust_for_bump_iterations_underflow() {
+        adjust_for_bump_iterations(1_499, 1, BUMP_ITERATION_CU);
+    }
+
+    fn test_instruction() -> Instruction {
+        Instruction::new_with_bytes(
+            Pubkey::new_unique(),
+            &[],
+            [
+                AccountMeta::new(Pubkey::new_unique(), true),
+                AccountMeta::new_readonly(Pubkey::new_unique(), true),
+                AccountMeta::new(Pubkey::new_unique(), false),
+                AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            ]
+            .to_vec(),
+        )
+    }
+
+    #[test]
+    fn test_assert_account_roles() {
+        assert_account_roles(
+            &test_instruction(),
+            &[
+                AccountRole::PAYER,
+                AccountRole::AUTHORITY,
+                AccountRole::WRITABLE,
+                AccountRole::READONLY,
+            ],
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Account index 2")]
+    fn test_assert_account_roles_mismatch() {
+        assert_account_roles(
+            &test_instruction(),
+            &[
+                AccountRole::PAYER,
+                AccountRole::AUTHORITY,
+                AccountRole::READONLY,
+                AccountRole::READONLY,
+            ],
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Number of account metas does not match expected roles")]
+    fn test_assert_account_roles_len_mismatch() {
+        assert_account_roles(&test_instruction(), &[AccountRole::PAYER]);
+    }
+}
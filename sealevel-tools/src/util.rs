@@ -0,0 +1,209 @@
+//! Miscellaneous utilities like hashing and verifying Merkle proofs.
+
+use solana_define_syscall::define_syscall;
+
+define_syscall!(fn sol_sha256(vals: *const u8, val_len: u64, hash_result: *mut u8) -> u64);
+define_syscall!(fn sol_keccak256(vals: *const u8, val_len: u64, hash_result: *mut u8) -> u64);
+
+/// Compute the SHA-256 hash of the given data at runtime (e.g. for a commitment provided with
+/// instruction data). On Solana, the hash is computed by the runtime's syscall, which is much
+/// cheaper than a software implementation.
+///
+/// For hashes known at compile time, use [Discriminator::Sha2] instead.
+///
+/// [Discriminator::Sha2]: crate::discriminator::Discriminator::Sha2
+#[inline(always)]
+pub fn sha256(data: &[u8]) -> [u8; 32] {
+    _sha256v(&[data])
+}
+
+/// Compute the Keccak-256 hash of the given data at runtime. On Solana, the hash is computed by the
+/// runtime's syscall, which is much cheaper than a software implementation.
+///
+/// For hashes known at compile time, use [Discriminator::Keccak] instead.
+///
+/// [Discriminator::Keccak]: crate::discriminator::Discriminator::Keccak
+#[inline(always)]
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    _keccak256v(&[data])
+}
+
+/// Verify a Merkle proof for a leaf against a root (e.g. an allowlist root stored in an account).
+/// Each node is the Keccak-256 hash of its two children sorted in ascending order, which matches
+/// common client libraries (like OpenZeppelin's `MerkleProof` and `merkletreejs` with
+/// `sortPairs`).
+///
+/// ### Notes
+///
+/// The leaf is used as is, so it should already be hashed (e.g. the Keccak-256 hash of the
+/// allowlisted user's pubkey). Hashing the leaf differently than internal nodes (or hashing it
+/// twice) protects against second preimage attacks, where an internal node is passed in as a
+/// leaf.
+///
+/// ### Example
+///
+/// ```
+/// use sealevel_tools::{
+///     entrypoint::ProgramResult, program_error::ProgramError, util::verify_merkle_proof,
+/// };
+///
+/// fn assert_allowlisted(
+///     allowlist_root: [u8; 32],
+///     claimant_leaf: [u8; 32],
+///     proof: &[[u8; 32]],
+/// ) -> ProgramResult {
+///     if verify_merkle_proof(claimant_leaf, proof, allowlist_root) {
+///         Ok(())
+///     } else {
+///         Err(ProgramError::InvalidArgument)
+///     }
+/// }
+/// ```
+#[inline(always)]
+pub fn verify_merkle_proof(leaf: [u8; 32], proof: &[[u8; 32]], root: [u8; 32]) -> bool {
+    proof
+        .iter()
+        .fold(leaf, |node, sibling| _hash_sorted_pair(&node, sibling))
+        == root
+}
+
+#[inline(always)]
+fn _hash_sorted_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+
+    _keccak256v(&[left.as_slice(), right.as_slice()])
+}
+
+#[allow(unexpected_cfgs)]
+#[inline(always)]
+fn _sha256v(vals: &[&[u8]]) -> [u8; 32] {
+    #[cfg(target_os = "solana")]
+    {
+        let mut hash_result = [0; 32];
+        unsafe {
+            sol_sha256(
+                vals as *const _ as *const u8,
+                vals.len() as u64,
+                hash_result.as_mut_ptr(),
+            )
+        };
+        hash_result
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    {
+        let mut hasher = const_crypto::sha2::Sha256::new();
+        for val in vals {
+            hasher = hasher.update(val);
+        }
+        hasher.finalize()
+    }
+}
+
+#[allow(unexpected_cfgs)]
+#[inline(always)]
+fn _keccak256v(vals: &[&[u8]]) -> [u8; 32] {
+    #[cfg(target_os = "solana")]
+    {
+        let mut hash_result = [0; 32];
+        unsafe {
+            sol_keccak256(
+                vals as *const _ as *const u8,
+                vals.len() as u64,
+                hash_result.as_mut_ptr(),
+            )
+        };
+        hash_result
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    {
+        let mut hasher = const_crypto::sha3::Keccak256::new();
+        for val in vals {
+            hasher = hasher.update(val);
+        }
+        hasher.finalize()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use solana_sdk::{hash::hashv as sha256_hashv, keccak::hashv};
+
+    use super::*;
+
+    fn hash_sorted_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+        if a <= b {
+            hashv(&[a.as_slice(), b.as_slice()]).to_bytes()
+        } else {
+            hashv(&[b.as_slice(), a.as_slice()]).to_bytes()
+        }
+    }
+
+    #[test]
+    fn test_sha256() {
+        assert_eq!(sha256(b""), sha256_hashv(&[b"".as_slice()]).to_bytes());
+        assert_eq!(
+            sha256(b"a thing"),
+            sha256_hashv(&[b"a thing".as_slice()]).to_bytes()
+        );
+        assert_eq!(
+            _sha256v(&[b"a ".as_slice(), b"thing".as_slice()]),
+            sha256_hashv(&[b"a thing".as_slice()]).to_bytes()
+        );
+    }
+
+    #[test]
+    fn test_keccak256() {
+        assert_eq!(keccak256(b""), hashv(&[b"".as_slice()]).to_bytes());
+        assert_eq!(
+            keccak256(b"a thing"),
+            hashv(&[b"a thing".as_slice()]).to_bytes()
+        );
+        assert_eq!(
+            _keccak256v(&[b"a ".as_slice(), b"thing".as_slice()]),
+            hashv(&[b"a thing".as_slice()]).to_bytes()
+        );
+    }
+
+    #[test]
+    fn test_hash_sorted_pair() {
+        let a = hashv(&[b"a".as_slice()]).to_bytes();
+        let b = hashv(&[b"b".as_slice()]).to_bytes();
+
+        assert_eq!(_hash_sorted_pair(&a, &b), hash_sorted_pair(&a, &b));
+        assert_eq!(_hash_sorted_pair(&a, &b), _hash_sorted_pair(&b, &a));
+    }
+
+    #[test]
+    fn test_verify_merkle_proof() {
+        let leaves = [b"a", b"b", b"c", b"d"].map(|data| hashv(&[data.as_slice()]).to_bytes());
+
+        //         root
+        //       /      \
+        //    ab          cd
+        //   /  \        /  \
+        //  a    b      c    d
+        let ab = hash_sorted_pair(&leaves[0], &leaves[1]);
+        let cd = hash_sorted_pair(&leaves[2], &leaves[3]);
+        let root = hash_sorted_pair(&ab, &cd);
+
+        assert!(verify_merkle_proof(leaves[0], &[leaves[1], cd], root));
+        assert!(verify_merkle_proof(leaves[1], &[leaves[0], cd], root));
+        assert!(verify_merkle_proof(leaves[2], &[leaves[3], ab], root));
+        assert!(verify_merkle_proof(leaves[3], &[leaves[2], ab], root));
+
+        // Wrong sibling.
+        assert!(!verify_merkle_proof(leaves[0], &[leaves[2], cd], root));
+
+        // Leaf not in tree.
+        let e = hashv(&[b"e".as_slice()]).to_bytes();
+        assert!(!verify_merkle_proof(e, &[leaves[1], cd], root));
+
+        // Incomplete proof.
+        assert!(!verify_merkle_proof(leaves[0], &[leaves[1]], root));
+
+        // Single-leaf tree has an empty proof.
+        assert!(verify_merkle_proof(leaves[0], &[], leaves[0]));
+    }
+}